@@ -158,4 +158,14 @@ impl Connection for AnyConnection {
     fn should_flush(&self) -> bool {
         delegate_to!(self.should_flush())
     }
+
+    #[doc(hidden)]
+    fn reset(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+        delegate_to_mut!(self.reset())
+    }
+
+    #[doc(hidden)]
+    fn should_reset(&self) -> bool {
+        delegate_to!(self.should_reset())
+    }
 }
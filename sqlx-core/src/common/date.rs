@@ -0,0 +1,24 @@
+use std::convert::TryFrom;
+use time::Date;
+
+// the date `days` after 1970-01-01, or `None` if it is out of the range of `Date`,
+// where the constructors of `time` would panic
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+pub(crate) fn date_from_unix_days(days: i64) -> Option<Date> {
+    let days = days.checked_add(719_468)?;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_shifted = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_shifted + 2) / 5 + 1;
+    let month = if month_shifted < 10 {
+        month_shifted + 3
+    } else {
+        month_shifted - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    Date::try_from_ymd(i32::try_from(year).ok()?, month as u8, day as u8).ok()
+}
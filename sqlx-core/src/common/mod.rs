@@ -1,3 +1,9 @@
 mod statement_cache;
 
+#[cfg(all(feature = "time", any(feature = "postgres", feature = "sqlite")))]
+pub(crate) mod date;
+
+#[cfg(all(feature = "geo-types", any(feature = "mysql", feature = "postgis")))]
+pub(crate) mod wkb;
+
 pub(crate) use statement_cache::StatementCache;
@@ -0,0 +1,386 @@
+use geo_types::{
+    Coordinate, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+use std::convert::TryFrom;
+
+use crate::error::BoxDynError;
+
+// the well-known binary representation of geometries, as used by MySQL and PostGIS
+
+// https://portal.ogc.org/files/?artifact_id=25355 (section 8.2)
+// https://github.com/postgis/postgis/blob/master/doc/ZMSGeoms.txt
+
+const POINT: u32 = 1;
+const LINE_STRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTI_POINT: u32 = 4;
+const MULTI_LINE_STRING: u32 = 5;
+const MULTI_POLYGON: u32 = 6;
+const GEOMETRY_COLLECTION: u32 = 7;
+
+// flags of the geometry type in the extended WKB of PostGIS
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+// decodes a geometry, returning the SRID of the geometry if it is extended WKB with an SRID
+//
+// coordinates with a Z or M dimension are read, but only their X and Y are kept
+pub(crate) fn decode(buf: &[u8]) -> Result<(Geometry<f64>, Option<u32>), BoxDynError> {
+    let mut reader = Reader {
+        buf,
+        little_endian: true,
+    };
+
+    let (geometry, srid) = reader.geometry()?;
+
+    if !reader.buf.is_empty() {
+        return Err(format!(
+            "unexpected {} bytes after the end of a WKB geometry",
+            reader.buf.len()
+        )
+        .into());
+    }
+
+    Ok((geometry, srid))
+}
+
+// encodes a geometry as little-endian WKB, or extended WKB if there is an SRID
+pub(crate) fn encode<T: Encode>(geometry: &T, srid: Option<u32>, buf: &mut Vec<u8>) {
+    geometry.encode(srid, buf);
+}
+
+// a geometry that is written from a reference, without converting it to a `Geometry`
+pub(crate) trait Encode {
+    fn encode(&self, srid: Option<u32>, buf: &mut Vec<u8>);
+}
+
+impl Encode for Geometry<f64> {
+    fn encode(&self, srid: Option<u32>, buf: &mut Vec<u8>) {
+        match self {
+            Geometry::Point(point) => point.encode(srid, buf),
+
+            Geometry::Line(line) => {
+                put_header(buf, LINE_STRING, srid);
+                buf.extend(&2_u32.to_le_bytes());
+                put_coordinate(buf, line.start);
+                put_coordinate(buf, line.end);
+            }
+
+            Geometry::LineString(line_string) => line_string.encode(srid, buf),
+            Geometry::Polygon(polygon) => polygon.encode(srid, buf),
+            Geometry::Rect(rect) => rect.to_polygon().encode(srid, buf),
+            Geometry::Triangle(triangle) => triangle.to_polygon().encode(srid, buf),
+            Geometry::MultiPoint(multi_point) => multi_point.encode(srid, buf),
+            Geometry::MultiLineString(multi_line_string) => multi_line_string.encode(srid, buf),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.encode(srid, buf),
+            Geometry::GeometryCollection(collection) => collection.encode(srid, buf),
+        }
+    }
+}
+
+impl Encode for Point<f64> {
+    fn encode(&self, srid: Option<u32>, buf: &mut Vec<u8>) {
+        put_header(buf, POINT, srid);
+        put_coordinate(buf, self.0);
+    }
+}
+
+impl Encode for LineString<f64> {
+    fn encode(&self, srid: Option<u32>, buf: &mut Vec<u8>) {
+        put_header(buf, LINE_STRING, srid);
+        put_line_string(buf, self);
+    }
+}
+
+impl Encode for Polygon<f64> {
+    fn encode(&self, srid: Option<u32>, buf: &mut Vec<u8>) {
+        put_header(buf, POLYGON, srid);
+        put_polygon(buf, self);
+    }
+}
+
+impl Encode for MultiPoint<f64> {
+    fn encode(&self, srid: Option<u32>, buf: &mut Vec<u8>) {
+        put_header(buf, MULTI_POINT, srid);
+        put_geometries(buf, &self.0);
+    }
+}
+
+impl Encode for MultiLineString<f64> {
+    fn encode(&self, srid: Option<u32>, buf: &mut Vec<u8>) {
+        put_header(buf, MULTI_LINE_STRING, srid);
+        put_geometries(buf, &self.0);
+    }
+}
+
+impl Encode for MultiPolygon<f64> {
+    fn encode(&self, srid: Option<u32>, buf: &mut Vec<u8>) {
+        put_header(buf, MULTI_POLYGON, srid);
+        put_geometries(buf, &self.0);
+    }
+}
+
+impl Encode for GeometryCollection<f64> {
+    fn encode(&self, srid: Option<u32>, buf: &mut Vec<u8>) {
+        put_header(buf, GEOMETRY_COLLECTION, srid);
+        put_geometries(buf, &self.0);
+    }
+}
+
+// the geometries of a multi-geometry or collection never have an SRID of their own
+fn put_geometries<T: Encode>(buf: &mut Vec<u8>, geometries: &[T]) {
+    buf.extend(&(geometries.len() as u32).to_le_bytes());
+
+    for geometry in geometries {
+        geometry.encode(None, buf);
+    }
+}
+
+fn put_header(buf: &mut Vec<u8>, r#type: u32, srid: Option<u32>) {
+    // little endian
+    buf.push(1);
+
+    match srid {
+        Some(srid) => {
+            buf.extend(&(r#type | EWKB_SRID).to_le_bytes());
+            buf.extend(&srid.to_le_bytes());
+        }
+
+        None => {
+            buf.extend(&r#type.to_le_bytes());
+        }
+    }
+}
+
+fn put_coordinate(buf: &mut Vec<u8>, coordinate: Coordinate<f64>) {
+    buf.extend(&coordinate.x.to_le_bytes());
+    buf.extend(&coordinate.y.to_le_bytes());
+}
+
+fn put_line_string(buf: &mut Vec<u8>, line_string: &LineString<f64>) {
+    buf.extend(&(line_string.0.len() as u32).to_le_bytes());
+
+    for coordinate in &line_string.0 {
+        put_coordinate(buf, *coordinate);
+    }
+}
+
+fn put_polygon(buf: &mut Vec<u8>, polygon: &Polygon<f64>) {
+    // an empty polygon has no rings at all
+    if polygon.exterior().0.is_empty() && polygon.interiors().is_empty() {
+        buf.extend(&0_u32.to_le_bytes());
+        return;
+    }
+
+    buf.extend(&(1 + polygon.interiors().len() as u32).to_le_bytes());
+    put_line_string(buf, polygon.exterior());
+
+    for interior in polygon.interiors() {
+        put_line_string(buf, interior);
+    }
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    little_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], BoxDynError> {
+        if self.buf.len() < len {
+            return Err("unexpected end of a WKB geometry".into());
+        }
+
+        let (bytes, rest) = self.buf.split_at(len);
+        self.buf = rest;
+
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, BoxDynError> {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.bytes(4)?);
+
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self) -> Result<f64, BoxDynError> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.bytes(8)?);
+
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    // reads the byte order and type of a geometry, every geometry in a collection has its own
+    fn geometry(&mut self) -> Result<(Geometry<f64>, Option<u32>), BoxDynError> {
+        self.little_endian = match self.bytes(1)?[0] {
+            0 => false,
+            1 => true,
+            order => return Err(format!("invalid byte order {} of a WKB geometry", order).into()),
+        };
+
+        let r#type = self.u32()?;
+
+        let srid = if r#type & EWKB_SRID != 0 {
+            Some(self.u32()?)
+        } else {
+            None
+        };
+
+        let mut dimensions = 2;
+
+        if r#type & EWKB_Z != 0 {
+            dimensions += 1;
+        }
+
+        if r#type & EWKB_M != 0 {
+            dimensions += 1;
+        }
+
+        // ISO WKB adds 1000 for Z, 2000 for M and 3000 for both to the type instead
+        let r#type = r#type & 0x0fff_ffff;
+
+        dimensions += match r#type / 1000 {
+            0 => 0,
+            1 | 2 => 1,
+            3 => 2,
+            _ => return Err(format!("unknown WKB geometry type {}", r#type).into()),
+        };
+
+        let geometry = match r#type % 1000 {
+            POINT => Geometry::Point(Point(self.coordinate(dimensions)?)),
+            LINE_STRING => Geometry::LineString(self.line_string(dimensions)?),
+            POLYGON => Geometry::Polygon(self.polygon(dimensions)?),
+
+            MULTI_POINT => Geometry::MultiPoint(MultiPoint(self.geometries()?)),
+            MULTI_LINE_STRING => Geometry::MultiLineString(MultiLineString(self.geometries()?)),
+            MULTI_POLYGON => Geometry::MultiPolygon(MultiPolygon(self.geometries()?)),
+
+            GEOMETRY_COLLECTION => {
+                Geometry::GeometryCollection(GeometryCollection(self.geometries()?))
+            }
+
+            _ => return Err(format!("unknown WKB geometry type {}", r#type).into()),
+        };
+
+        Ok((geometry, srid))
+    }
+
+    fn geometries<T>(&mut self) -> Result<Vec<T>, BoxDynError>
+    where
+        T: TryFrom<Geometry<f64>>,
+    {
+        let len = self.u32()?;
+        let mut geometries = Vec::with_capacity(len.min(1024) as usize);
+
+        for _ in 0..len {
+            let (geometry, _) = self.geometry()?;
+
+            geometries.push(
+                T::try_from(geometry)
+                    .map_err(|_| "unexpected type of a geometry in a WKB multi-geometry")?,
+            );
+        }
+
+        Ok(geometries)
+    }
+
+    fn coordinate(&mut self, dimensions: usize) -> Result<Coordinate<f64>, BoxDynError> {
+        let x = self.f64()?;
+        let y = self.f64()?;
+
+        for _ in 2..dimensions {
+            self.f64()?;
+        }
+
+        Ok(Coordinate { x, y })
+    }
+
+    fn line_string(&mut self, dimensions: usize) -> Result<LineString<f64>, BoxDynError> {
+        let len = self.u32()?;
+        let mut coordinates = Vec::with_capacity(len.min(1024) as usize);
+
+        for _ in 0..len {
+            coordinates.push(self.coordinate(dimensions)?);
+        }
+
+        Ok(LineString(coordinates))
+    }
+
+    fn polygon(&mut self, dimensions: usize) -> Result<Polygon<f64>, BoxDynError> {
+        let len = self.u32()?;
+
+        if len == 0 {
+            return Ok(Polygon::new(LineString(Vec::new()), Vec::new()));
+        }
+
+        let exterior = self.line_string(dimensions)?;
+        let mut interiors = Vec::with_capacity((len - 1).min(1024) as usize);
+
+        for _ in 1..len {
+            interiors.push(self.line_string(dimensions)?);
+        }
+
+        Ok(Polygon::new(exterior, interiors))
+    }
+}
+
+#[test]
+fn it_decodes_wkb() -> Result<(), BoxDynError> {
+    // POINT(1 2), big endian
+    let (geometry, srid) = decode(&[
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ])?;
+
+    assert_eq!(geometry, Geometry::Point(Point::new(1.0, 2.0)));
+    assert_eq!(srid, None);
+
+    // SRID=4326;POINT Z(1 2 3), as extended WKB of PostGIS
+    let (geometry, srid) = decode(&[
+        0x01, 0x01, 0x00, 0x00, 0xa0, 0xe6, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xf0, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x40,
+    ])?;
+
+    assert_eq!(geometry, Geometry::Point(Point::new(1.0, 2.0)));
+    assert_eq!(srid, Some(4326));
+
+    assert!(decode(&[0x01, 0x01, 0x00, 0x00]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn it_encodes_wkb() -> Result<(), BoxDynError> {
+    let polygon = Polygon::new(
+        LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 0.0)]),
+        vec![LineString::from(vec![(1.0, 1.0), (2.0, 1.0), (2.0, 2.0)])],
+    );
+
+    let collection = Geometry::GeometryCollection(GeometryCollection(vec![
+        Geometry::Polygon(polygon),
+        Geometry::MultiPoint(MultiPoint(vec![Point::new(1.0, 2.0), Point::new(3.0, 4.0)])),
+    ]));
+
+    let mut buf = Vec::new();
+    encode(&collection, Some(3857), &mut buf);
+
+    assert_eq!(
+        &buf[..9],
+        &[0x01, 0x07, 0x00, 0x00, 0x20, 0x11, 0x0f, 0x00, 0x00]
+    );
+    assert_eq!(decode(&buf)?, (collection, Some(3857)));
+
+    Ok(())
+}
@@ -87,6 +87,16 @@ pub trait Connection: Send {
     #[doc(hidden)]
     fn should_flush(&self) -> bool;
 
+    #[doc(hidden)]
+    fn reset(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    #[doc(hidden)]
+    fn should_reset(&self) -> bool {
+        false
+    }
+
     /// Establish a new database connection.
     ///
     /// A value of `Options` is parsed from the provided connection string. This parsing
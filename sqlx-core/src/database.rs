@@ -160,3 +160,8 @@ pub trait HasStatement<'q> {
 ///
 /// [`Database`]: trait.Database.html
 pub trait HasStatementCache {}
+
+/// A [`Database`] that can fetch the rows of a query in batches of a given size.
+///
+/// [`Database`]: trait.Database.html
+pub trait HasFetchSize {}
@@ -198,6 +198,12 @@ pub trait Execute<'q, DB: Database>: Send + Sized {
 
     /// Returns `true` if the statement should be cached.
     fn persistent(&self) -> bool;
+
+    /// Returns the number of rows to fetch at a time, if the rows should be fetched in batches.
+    #[inline]
+    fn fetch_size(&self) -> Option<u32> {
+        None
+    }
 }
 
 // NOTE: `Execute` is explicitly not implemented for String and &String to make it slightly more
@@ -1,14 +1,22 @@
 use crate::arguments::Arguments;
 use crate::encode::{Encode, IsNull};
+use crate::mysql::protocol::text::ColumnType;
 use crate::mysql::{MySql, MySqlTypeInfo};
 use crate::types::Type;
 
+// strings and bytes larger than this are sent ahead of `COM_STMT_EXECUTE`
+// with `COM_STMT_SEND_LONG_DATA`, instead of in a single packet
+pub(crate) const LONG_DATA_THRESHOLD: usize = 1024 * 1024;
+
 /// Implementation of [`Arguments`] for MySQL.
 #[derive(Debug, Default)]
 pub struct MySqlArguments {
     pub(crate) values: Vec<u8>,
     pub(crate) types: Vec<MySqlTypeInfo>,
     pub(crate) null_bitmap: Vec<u8>,
+
+    // values that are sent separately, by the index of their parameter
+    pub(crate) long_data: Vec<(u16, Vec<u8>)>,
 }
 
 impl MySqlArguments {
@@ -19,15 +27,53 @@ impl MySqlArguments {
         let ty = value.produces().unwrap_or_else(T::type_info);
         let index = self.types.len();
 
+        let is_long_data = is_long_data_type(ty.r#type);
+
         self.types.push(ty);
         self.null_bitmap.resize((index / 8) + 1, 0);
 
+        let offset = self.values.len();
+
         if let IsNull::Yes = value.encode(&mut self.values) {
             self.null_bitmap[index / 8] |= (1 << (index % 8)) as u8;
+        } else if is_long_data && self.values.len() - offset > LONG_DATA_THRESHOLD {
+            // the value is taken from the long data of the parameter
+            // when it is left out of `COM_STMT_EXECUTE`
+            let prefix = lenenc_prefix_len(self.values[offset]);
+            let data = self.values[offset + prefix..].to_vec();
+
+            self.values.truncate(offset);
+            self.long_data.push((index as u16, data));
         }
     }
 }
 
+// types that are encoded as length-encoded bytes
+fn is_long_data_type(ty: ColumnType) -> bool {
+    matches!(
+        ty,
+        ColumnType::VarChar
+            | ColumnType::Json
+            | ColumnType::TinyBlob
+            | ColumnType::MediumBlob
+            | ColumnType::LongBlob
+            | ColumnType::Blob
+            | ColumnType::VarString
+            | ColumnType::String
+            | ColumnType::Geometry
+    )
+}
+
+// https://dev.mysql.com/doc/internals/en/integer.html#length-encoded-integer
+fn lenenc_prefix_len(first: u8) -> usize {
+    match first {
+        0xfc => 3,
+        0xfd => 4,
+        0xfe => 9,
+        _ => 1,
+    }
+}
+
 impl<'q> Arguments<'q> for MySqlArguments {
     type Database = MySql;
 
@@ -43,3 +89,21 @@ impl<'q> Arguments<'q> for MySqlArguments {
         self.add(value)
     }
 }
+
+#[test]
+fn it_separates_long_data() {
+    let mut arguments = MySqlArguments::default();
+
+    let long = vec![0xab_u8; LONG_DATA_THRESHOLD + 1];
+
+    arguments.add(1_i32);
+    arguments.add(&*long);
+    arguments.add("short");
+
+    assert_eq!(arguments.long_data.len(), 1);
+    assert_eq!(arguments.long_data[0].0, 1);
+    assert_eq!(arguments.long_data[0].1, long);
+
+    assert_eq!(arguments.values, b"\x01\x00\x00\x00\x05short");
+    assert_eq!(arguments.null_bitmap, [0]);
+}
@@ -1,11 +1,13 @@
 use bytes::buf::ext::Chain;
 use bytes::Bytes;
+use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
+use curve25519_dalek::scalar::Scalar;
 use digest::{Digest, FixedOutput};
 use generic_array::GenericArray;
 use rand::thread_rng;
 use rsa::{PaddingScheme, PublicKey, RSAPublicKey};
 use sha1::Sha1;
-use sha2::Sha256;
+use sha2::{Sha256, Sha512};
 
 use crate::error::Error;
 use crate::mysql::connection::stream::MySqlStream;
@@ -27,6 +29,23 @@ impl AuthPlugin {
 
             // https://mariadb.com/kb/en/sha256_password-plugin/
             AuthPlugin::Sha256Password => encrypt_rsa(stream, 0x01, password, nonce).await,
+
+            // https://mariadb.com/kb/en/authentication-plugin-ed25519/
+            AuthPlugin::ClientEd25519 => Ok(sign_ed25519(password.as_bytes(), nonce).to_vec()),
+
+            // https://dev.mysql.com/doc/refman/8.0/en/cleartext-pluggable-authentication.html
+            AuthPlugin::MySqlClearPassword => {
+                if !stream.is_tls() {
+                    return Err(Error::Configuration(
+                        "the server requested the mysql_clear_password authentication \
+                         plugin, which is refused without TLS as it sends the password \
+                         in clear text"
+                            .into(),
+                    ));
+                }
+
+                Ok(to_asciz(password))
+            }
         }
     }
 
@@ -123,6 +142,47 @@ fn scramble_sha256(
     pw_hash
 }
 
+fn sign_ed25519(password: &[u8], nonce: &Chain<Bytes, Bytes>) -> [u8; 64] {
+    // an Ed25519 signature of the nonce, with SHA512( password ) as the expanded secret key
+    // https://github.com/MariaDB/server/blob/10.5/plugin/auth_ed25519/ref10/sign.c
+
+    let hash = Sha512::digest(password);
+
+    let mut secret = [0; 32];
+    secret.copy_from_slice(&hash[..32]);
+    secret[0] &= 248;
+    secret[31] &= 63;
+    secret[31] |= 64;
+
+    let secret = Scalar::from_bits(secret);
+    let public_key = (&secret * &ED25519_BASEPOINT_TABLE).compress();
+
+    let r = Scalar::from_hash(
+        Sha512::new()
+            .chain(&hash[32..])
+            .chain(nonce.first_ref())
+            .chain(nonce.last_ref()),
+    );
+
+    let r_point = (&r * &ED25519_BASEPOINT_TABLE).compress();
+
+    let k = Scalar::from_hash(
+        Sha512::new()
+            .chain(r_point.as_bytes())
+            .chain(public_key.as_bytes())
+            .chain(nonce.first_ref())
+            .chain(nonce.last_ref()),
+    );
+
+    let s = k * secret + r;
+
+    let mut signature = [0; 64];
+    signature[..32].copy_from_slice(r_point.as_bytes());
+    signature[32..].copy_from_slice(s.as_bytes());
+
+    signature
+}
+
 async fn encrypt_rsa<'s>(
     stream: &'s mut MySqlStream,
     public_key_request_id: u8,
@@ -199,3 +259,32 @@ fn parse_rsa_pub_key(key: &[u8]) -> Result<RSAPublicKey, Error> {
 
     RSAPublicKey::from_pkcs8(&der).map_err(Error::protocol)
 }
+
+#[test]
+fn it_signs_ed25519() {
+    use bytes::buf::BufExt;
+
+    // MariaDB signs with the password in place of the 32-byte seed of an Ed25519 key, so a
+    // password of 32 bytes signs as that seed does in the test vectors of RFC 8032
+    // https://tools.ietf.org/html/rfc8032#section-7.1 (TEST 1 and TEST 2)
+
+    let signature = sign_ed25519(
+        &hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60").unwrap(),
+        &Bytes::new().chain(Bytes::new()),
+    );
+
+    assert_eq!(
+        hex::encode(&signature[..]),
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+    );
+
+    let signature = sign_ed25519(
+        &hex::decode("4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb").unwrap(),
+        &Bytes::from_static(&[0x72]).chain(Bytes::new()),
+    );
+
+    assert_eq!(
+        hex::encode(&signature[..]),
+        "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00"
+    );
+}
@@ -0,0 +1,156 @@
+use bytes::BytesMut;
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use std::io::{Read, Write};
+
+use crate::error::Error;
+use crate::mysql::MySqlCompression;
+
+// https://dev.mysql.com/doc/internals/en/compression.html
+// https://dev.mysql.com/doc/dev/mysql-server/8.0.18/page_protocol_basic_compression.html
+
+// the largest payload of a compressed packet, before compression
+const MAX_PAYLOAD_SIZE: usize = 0xFF_FF_FF;
+
+// payloads smaller than this are sent without compression, like the MySQL client does
+const MIN_COMPRESS_LENGTH: usize = 50;
+
+// the compression level of zstd, for both directions
+pub(crate) const ZSTD_COMPRESSION_LEVEL: u8 = 3;
+
+// compressed packets wrap the regular packets (with their headers) in a stream of their own
+pub(crate) struct PacketCompression {
+    algorithm: MySqlCompression,
+
+    // the sequence of compressed packets, which is independent of the packets they contain
+    pub(crate) sequence_id: u8,
+
+    // decompressed packets that were not read yet
+    pub(crate) rbuf: BytesMut,
+}
+
+impl PacketCompression {
+    pub(crate) fn new(algorithm: MySqlCompression) -> Self {
+        Self {
+            algorithm,
+            sequence_id: 0,
+            rbuf: BytesMut::new(),
+        }
+    }
+
+    // writes `packets` to `buf` as compressed packets
+    pub(crate) fn compress(&mut self, packets: &[u8], buf: &mut Vec<u8>) -> Result<(), Error> {
+        for payload in packets.chunks(MAX_PAYLOAD_SIZE) {
+            let compressed = if payload.len() < MIN_COMPRESS_LENGTH {
+                None
+            } else {
+                Some(self.compress_payload(payload)?)
+            };
+
+            // the uncompressed length is 0 for a payload that is sent as-is,
+            // which is also done when compression would not make it smaller
+            let (payload, uncompressed_len) = match compressed {
+                Some(ref compressed) if compressed.len() < payload.len() => {
+                    (&compressed[..], payload.len())
+                }
+
+                _ => (payload, 0),
+            };
+
+            buf.extend(&(payload.len() as u32).to_le_bytes()[..3]);
+            buf.push(self.sequence_id);
+            buf.extend(&(uncompressed_len as u32).to_le_bytes()[..3]);
+            buf.extend_from_slice(payload);
+
+            self.sequence_id = self.sequence_id.wrapping_add(1);
+        }
+
+        Ok(())
+    }
+
+    fn compress_payload(&self, payload: &[u8]) -> Result<Vec<u8>, Error> {
+        match self.algorithm {
+            MySqlCompression::Zlib => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(payload)?;
+
+                Ok(encoder.finish()?)
+            }
+
+            #[cfg(feature = "zstd")]
+            MySqlCompression::Zstd => Ok(zstd::stream::encode_all(
+                payload,
+                i32::from(ZSTD_COMPRESSION_LEVEL),
+            )?),
+
+            MySqlCompression::Disabled => Ok(payload.to_vec()),
+        }
+    }
+
+    // appends the payload of a compressed packet to the read buffer
+    pub(crate) fn decompress(&mut self, payload: &[u8], len: usize) -> Result<(), Error> {
+        if len == 0 {
+            // the payload was not compressed
+            self.rbuf.extend_from_slice(payload);
+
+            return Ok(());
+        }
+
+        let mut decompressed = Vec::with_capacity(len);
+
+        match self.algorithm {
+            MySqlCompression::Zlib => {
+                ZlibDecoder::new(payload).read_to_end(&mut decompressed)?;
+            }
+
+            #[cfg(feature = "zstd")]
+            MySqlCompression::Zstd => {
+                zstd::stream::copy_decode(payload, &mut decompressed)?;
+            }
+
+            MySqlCompression::Disabled => {
+                return Err(err_protocol!(
+                    "received a compressed packet without compression"
+                ));
+            }
+        }
+
+        if decompressed.len() != len {
+            return Err(err_protocol!(
+                "expected a compressed packet of {} bytes but found {}",
+                len,
+                decompressed.len()
+            ));
+        }
+
+        self.rbuf.extend_from_slice(&decompressed);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn it_compresses_packets() -> Result<(), Error> {
+    let mut compression = PacketCompression::new(MySqlCompression::Zlib);
+    let packets = [b'x'; 200];
+
+    let mut buf = Vec::new();
+    compression.compress(&[0x01, 0x00, 0x00, 0x00, 0x01], &mut buf)?;
+    compression.compress(&packets, &mut buf)?;
+
+    // a small payload is sent as-is
+    assert_eq!(
+        &buf[..12],
+        b"\x05\x00\x00\x00\x00\x00\x00\x01\x00\x00\x00\x01"
+    );
+
+    let len = u32::from_le_bytes([buf[12], buf[13], buf[14], 0]) as usize;
+    assert_eq!(buf[15], 1);
+    assert_eq!(&buf[16..19], b"\xc8\x00\x00");
+    assert_eq!(buf.len(), 19 + len);
+
+    compression.decompress(&buf[19..], 200)?;
+    assert_eq!(&compression.rbuf[..], &packets[..]);
+
+    Ok(())
+}
@@ -1,8 +1,12 @@
+use bytes::buf::ext::Chain;
 use bytes::Bytes;
 
 use crate::common::StatementCache;
 use crate::error::Error;
+use crate::mysql::connection::compression::{PacketCompression, ZSTD_COMPRESSION_LEVEL};
+use crate::mysql::connection::reset::ResetOnRelease;
 use crate::mysql::connection::{tls, MySqlStream, MAX_PACKET_SIZE};
+use crate::mysql::protocol::auth::AuthPlugin;
 use crate::mysql::protocol::connect::{
     AuthSwitchRequest, AuthSwitchResponse, Handshake, HandshakeResponse,
 };
@@ -75,61 +79,98 @@ impl MySqlConnection {
             database: options.database.as_deref(),
             auth_plugin: plugin,
             auth_response: auth_response.as_deref(),
+            zstd_compression_level: ZSTD_COMPRESSION_LEVEL,
         });
 
         stream.flush().await?;
 
-        loop {
-            let packet = stream.recv_packet().await?;
-            match packet[0] {
-                0x00 => {
-                    let _ok = packet.ok()?;
+        authenticate(
+            &mut stream,
+            options.password.as_deref(),
+            &mut plugin,
+            &mut nonce,
+        )
+        .await?;
+
+        // packets are compressed after the connection phase, if the server agreed to
+        if stream
+            .capabilities
+            .intersects(Capabilities::COMPRESS | Capabilities::ZSTD_COMPRESSION_ALGORITHM)
+        {
+            stream.compression = Some(PacketCompression::new(options.compression));
+        }
 
-                    break;
-                }
+        let reset_on_release = if options.reset_on_release {
+            Some(Box::new(ResetOnRelease::new(
+                &handshake.server_version,
+                options,
+                plugin,
+                nonce,
+            )))
+        } else {
+            None
+        };
 
-                0xfe => {
-                    let switch: AuthSwitchRequest = packet.decode()?;
+        Ok(Self {
+            stream,
+            transaction_depth: 0,
+            cache_statement: StatementCache::new(options.statement_cache_capacity),
+            local_infile: Default::default(),
+            reset_on_release,
+            log_settings: options.log_settings.clone(),
+        })
+    }
+}
 
-                    plugin = Some(switch.plugin);
-                    nonce = switch.data.chain(Bytes::new());
+// completes the authentication of a handshake response or `COM_CHANGE_USER`, following
+// the switches of authentication method requested by the server
+pub(super) async fn authenticate(
+    stream: &mut MySqlStream,
+    password: Option<&str>,
+    plugin: &mut Option<AuthPlugin>,
+    nonce: &mut Chain<Bytes, Bytes>,
+) -> Result<(), Error> {
+    loop {
+        let packet = stream.recv_packet().await?;
+        match packet[0] {
+            0x00 => {
+                let _ok = packet.ok()?;
+
+                break;
+            }
 
-                    let response = switch
-                        .plugin
-                        .scramble(
-                            &mut stream,
-                            options.password.as_deref().unwrap_or_default(),
-                            &nonce,
-                        )
-                        .await?;
+            0xfe => {
+                let switch: AuthSwitchRequest = packet.decode()?;
 
-                    stream.write_packet(AuthSwitchResponse(response));
-                    stream.flush().await?;
-                }
+                *plugin = Some(switch.plugin);
+                *nonce = switch.data.chain(Bytes::new());
+
+                let response = switch
+                    .plugin
+                    .scramble(stream, password.unwrap_or_default(), nonce)
+                    .await?;
+
+                stream.write_packet(AuthSwitchResponse(response));
+                stream.flush().await?;
+            }
 
-                id => {
-                    if let (Some(plugin), Some(password)) = (plugin, &options.password) {
-                        if plugin.handle(&mut stream, packet, password, &nonce).await? {
-                            // plugin signaled authentication is ok
-                            break;
-                        }
-
-                    // plugin signaled to continue authentication
-                    } else {
-                        return Err(err_protocol!(
-                            "unexpected packet 0x{:02x} during authentication",
-                            id
-                        ));
+            id => {
+                if let (Some(plugin), Some(password)) = (*plugin, password) {
+                    if plugin.handle(stream, packet, password, nonce).await? {
+                        // plugin signaled authentication is ok
+                        break;
                     }
+
+                // plugin signaled to continue authentication
+                } else {
+                    return Err(err_protocol!(
+                        "unexpected packet 0x{:02x} during authentication",
+                        id
+                    ));
                 }
             }
         }
-
-        Ok(Self {
-            stream,
-            transaction_depth: 0,
-            cache_statement: StatementCache::new(options.statement_cache_capacity),
-            log_settings: options.log_settings.clone(),
-        })
     }
+
+    Ok(())
 }
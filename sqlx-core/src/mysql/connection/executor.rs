@@ -6,9 +6,9 @@ use crate::ext::ustr::UStr;
 use crate::logger::QueryLogger;
 use crate::mysql::connection::stream::Busy;
 use crate::mysql::io::MySqlBufExt;
-use crate::mysql::protocol::response::Status;
+use crate::mysql::protocol::response::{EofPacket, Status};
 use crate::mysql::protocol::statement::{
-    BinaryRow, Execute as StatementExecute, Prepare, PrepareOk, StmtClose,
+    BinaryRow, Execute as StatementExecute, Fetch, Prepare, PrepareOk, SendLongData, StmtClose,
 };
 use crate::mysql::protocol::text::{ColumnDefinition, ColumnFlags, Query, TextRow};
 use crate::mysql::statement::{MySqlStatement, MySqlStatementMetadata};
@@ -24,6 +24,9 @@ use futures_core::Stream;
 use futures_util::{pin_mut, TryStreamExt};
 use std::{borrow::Cow, sync::Arc};
 
+// the size of the packets that long data is sent in
+const LONG_DATA_CHUNK_SIZE: usize = 1024 * 1024;
+
 impl MySqlConnection {
     async fn get_or_prepare<'c>(
         &mut self,
@@ -60,7 +63,9 @@ impl MySqlConnection {
         let mut columns = Vec::new();
 
         let column_names = if ok.columns > 0 {
-            recv_result_metadata(&mut self.stream, ok.columns as usize, &mut columns).await?
+            recv_result_metadata(&mut self.stream, ok.columns as usize, &mut columns)
+                .await?
+                .0
         } else {
             Default::default()
         };
@@ -88,6 +93,7 @@ impl MySqlConnection {
         sql: &'q str,
         arguments: Option<MySqlArguments>,
         persistent: bool,
+        fetch_size: Option<u32>,
     ) -> Result<impl Stream<Item = Result<Either<MySqlDone, MySqlRow>, Error>> + 'e, Error> {
         let mut logger = QueryLogger::new(sql, self.log_settings.clone());
 
@@ -100,34 +106,59 @@ impl MySqlConnection {
             // to re-use this memory freely between result sets
             let mut columns = Arc::new(Vec::new());
 
-            let (mut column_names, format, mut needs_metadata) = if let Some(arguments) = arguments {
+            // the index of the current result set, of which there are several for
+            // multiple statements or a `CALL` of a stored procedure
+            let mut result_set = 0;
+
+            let (statement, mut column_names, format, mut needs_metadata) = if let Some(arguments) = arguments {
                 let (id, metadata) = self.get_or_prepare(
                     sql,
                     persistent,
                 )
                 .await?;
 
+                // https://dev.mysql.com/doc/internals/en/com-stmt-send-long-data.html
+                for (param, data) in &arguments.long_data {
+                    for chunk in data.chunks(LONG_DATA_CHUNK_SIZE) {
+                        self.stream
+                            .send_packet(SendLongData {
+                                statement: id,
+                                param: *param,
+                                data: chunk,
+                            })
+                            .await?;
+                    }
+                }
+
                 // https://dev.mysql.com/doc/internals/en/com-stmt-execute.html
                 self.stream
                     .send_packet(StatementExecute {
                         statement: id,
                         arguments: &arguments,
+                        cursor: fetch_size.is_some(),
                     })
                     .await?;
 
-                (metadata.column_names, MySqlValueFormat::Binary, false)
+                (id, metadata.column_names, MySqlValueFormat::Binary, false)
             } else {
                 // https://dev.mysql.com/doc/internals/en/com-query.html
                 self.stream.send_packet(Query(sql)).await?;
 
-                (Arc::default(), MySqlValueFormat::Text, true)
+                (0, Arc::default(), MySqlValueFormat::Text, true)
             };
 
             loop {
                 // query response is a meta-packet which may be one of:
-                //  Ok, Err, ResultSet, or (unhandled) LocalInfileRequest
+                //  Ok, Err, ResultSet, or LocalInfileRequest
                 let mut packet = self.stream.recv_packet().await?;
 
+                if packet[0] == 0xfb {
+                    // the server requests a file for `LOAD DATA LOCAL INFILE`,
+                    // followed by the usual response once it is sent
+                    self.send_local_infile(&packet[1..]).await?;
+                    continue;
+                }
+
                 if packet[0] == 0x00 || packet[0] == 0xff {
                     // first packet in a query response is OK or ERR
                     // this indicates either a successful query with no rows at all or a failed query
@@ -136,9 +167,11 @@ impl MySqlConnection {
                     let done = MySqlDone {
                         rows_affected: ok.affected_rows,
                         last_insert_id: ok.last_insert_id,
+                        result_set,
                     };
 
                     r#yield!(Either::Left(done));
+                    result_set += 1;
 
                     if ok.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
                         // more result sets exist, continue to the next one
@@ -154,14 +187,34 @@ impl MySqlConnection {
 
                 let num_columns = packet.get_uint_lenenc() as usize; // column count
 
-                if needs_metadata {
-                    column_names = Arc::new(recv_result_metadata(&mut self.stream, num_columns, Arc::make_mut(&mut columns)).await?);
+                let eof = if needs_metadata {
+                    let (names, eof) = recv_result_metadata(&mut self.stream, num_columns, Arc::make_mut(&mut columns)).await?;
+                    column_names = Arc::new(names);
+
+                    eof
                 } else {
                     // next time we hit here, it'll be a new result set and we'll need the
                     // full metadata
                     needs_metadata = true;
 
-                    recv_result_columns(&mut self.stream, num_columns, Arc::make_mut(&mut columns)).await?;
+                    recv_result_columns(&mut self.stream, num_columns, Arc::make_mut(&mut columns)).await?
+                };
+
+                // the OUT parameters of a procedure called by a prepared statement are sent as
+                // a result set of their own, flagged either after the metadata or at its end
+                let mut out_params = matches!(&eof, Some(eof) if eof.status.contains(Status::SERVER_PS_OUT_PARAMS));
+
+                // so rows of a procedure called by a prepared statement are yielded one row
+                // behind, once it is known whether the next packet ends the result set
+                let buffer_rows = matches!(format, MySqlValueFormat::Binary) && is_call(sql);
+                let mut pending: Option<MySqlRow> = None;
+
+                // https://dev.mysql.com/doc/dev/mysql-server/8.0.12/page_protocol_com_stmt_fetch.html
+                if let (Some(rows), Some(eof)) = (fetch_size, &eof) {
+                    if eof.status.contains(Status::SERVER_STATUS_CURSOR_EXISTS) {
+                        // the rows of a cursor are sent in batches, once they are fetched
+                        self.stream.send_packet(Fetch { statement, rows }).await?;
+                    }
                 }
 
                 // finally, there will be none or many result-rows
@@ -171,11 +224,31 @@ impl MySqlConnection {
                     if packet[0] == 0xfe && packet.len() < 9 {
                         let eof = packet.eof(self.stream.capabilities)?;
 
+                        out_params |= eof.status.contains(Status::SERVER_PS_OUT_PARAMS);
+
+                        if let Some(rows) = fetch_size {
+                            if eof.status.contains(Status::SERVER_STATUS_CURSOR_EXISTS)
+                                && !eof.status.contains(Status::SERVER_STATUS_LAST_ROW_SENT)
+                            {
+                                // fetch the next batch of rows of the cursor
+                                self.stream.send_packet(Fetch { statement, rows }).await?;
+                                continue;
+                            }
+                        }
+
+                        if let Some(mut row) = pending.take() {
+                            row.out_params = out_params;
+                            r#yield!(Either::Right(row));
+                        }
+
                         r#yield!(Either::Left(MySqlDone {
                             rows_affected: 0,
                             last_insert_id: 0,
+                            result_set,
                         }));
 
+                        result_set += 1;
+
                         if eof.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
                             // more result sets exist, continue to the next one
                             self.stream.busy = Busy::Result;
@@ -191,22 +264,38 @@ impl MySqlConnection {
                         MySqlValueFormat::Text => packet.decode_with::<TextRow, _>(&columns)?.0,
                     };
 
-                    let v = Either::Right(MySqlRow {
+                    let row = MySqlRow {
                         row,
                         format,
                         columns: Arc::clone(&columns),
                         column_names: Arc::clone(&column_names),
-                    });
+                        result_set,
+                        out_params,
+                    };
 
                     logger.increment_rows();
 
-                    r#yield!(v);
+                    if buffer_rows {
+                        if let Some(row) = pending.replace(row) {
+                            r#yield!(Either::Right(row));
+                        }
+                    } else {
+                        r#yield!(Either::Right(row));
+                    }
                 }
             }
         }))
     }
 }
 
+// only a `CALL` of a procedure can return OUT parameters
+fn is_call(sql: &str) -> bool {
+    let sql = sql.trim_start();
+
+    matches!(sql.get(..4), Some(keyword) if keyword.eq_ignore_ascii_case("call"))
+        && !sql[4..].starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
 impl<'c> Executor<'c> for &'c mut MySqlConnection {
     type Database = MySql;
 
@@ -221,9 +310,10 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
         let sql = query.sql();
         let arguments = query.take_arguments();
         let persistent = query.persistent();
+        let fetch_size = query.fetch_size();
 
         Box::pin(try_stream! {
-            let s = self.run(sql, arguments, persistent).await?;
+            let s = self.run(sql, arguments, persistent, fetch_size).await?;
             pin_mut!(s);
 
             while let Some(v) = s.try_next().await? {
@@ -309,7 +399,7 @@ async fn recv_result_columns(
     stream: &mut MySqlStream,
     num_columns: usize,
     columns: &mut Vec<MySqlColumn>,
-) -> Result<(), Error> {
+) -> Result<Option<EofPacket>, Error> {
     columns.clear();
     columns.reserve(num_columns);
 
@@ -318,10 +408,10 @@ async fn recv_result_columns(
     }
 
     if num_columns > 0 {
-        stream.maybe_recv_eof().await?;
+        stream.maybe_recv_eof().await
+    } else {
+        Ok(None)
     }
-
-    Ok(())
 }
 
 fn recv_next_result_column(def: &ColumnDefinition, ordinal: usize) -> Result<MySqlColumn, Error> {
@@ -346,7 +436,7 @@ async fn recv_result_metadata(
     stream: &mut MySqlStream,
     num_columns: usize,
     columns: &mut Vec<MySqlColumn>,
-) -> Result<HashMap<UStr, usize>, Error> {
+) -> Result<(HashMap<UStr, usize>, Option<EofPacket>), Error> {
     // the result-set metadata is primarily a listing of each output
     // column in the result-set
 
@@ -364,7 +454,16 @@ async fn recv_result_metadata(
         columns.push(column);
     }
 
-    stream.maybe_recv_eof().await?;
+    let eof = stream.maybe_recv_eof().await?;
+
+    Ok((column_names, eof))
+}
 
-    Ok(column_names)
+#[test]
+fn it_recognizes_call() {
+    assert!(is_call("CALL p(?)"));
+    assert!(is_call("  call `p`()"));
+    assert!(!is_call("SELECT 1"));
+    assert!(!is_call("CALLS"));
+    assert!(!is_call("cal"));
 }
@@ -0,0 +1,171 @@
+use bytes::Bytes;
+use futures_core::stream::BoxStream;
+use futures_core::Stream;
+use futures_util::{stream, StreamExt, TryStreamExt};
+use sqlx_rt::{AsyncRead, AsyncReadExt};
+use std::fmt::{self, Debug, Formatter};
+
+use crate::error::{BoxDynError, Error};
+use crate::mysql::connection::Busy;
+use crate::mysql::protocol::response::Status;
+use crate::mysql::MySqlConnection;
+
+// the size of the chunks we read from an `AsyncRead`
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+// the largest payload of a single packet
+const MAX_PAYLOAD_SIZE: usize = 0xFF_FF_FE;
+
+/// The contents of a file requested by `LOAD DATA LOCAL INFILE`.
+///
+/// A `MySqlLocalInfile` is registered on a connection under the file name used in the
+/// query with [`MySqlConnection::register_local_infile`]. The server may only read files
+/// that have been registered; any other request is refused.
+///
+/// [`MySqlConnection::register_local_infile`]: struct.MySqlConnection.html#method.register_local_infile
+pub struct MySqlLocalInfile {
+    stream: BoxStream<'static, Result<Bytes, Error>>,
+}
+
+impl MySqlLocalInfile {
+    /// Serves the bytes read from `reader`.
+    pub fn from_reader<R>(reader: R) -> Self
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let stream = stream::try_unfold(reader, |mut reader| async move {
+            let mut buf = vec![0; READ_BUFFER_SIZE];
+            let len = reader.read(&mut buf).await?;
+
+            if len == 0 {
+                return Ok(None);
+            }
+
+            buf.truncate(len);
+
+            Ok(Some((Bytes::from(buf), reader)))
+        });
+
+        Self {
+            stream: stream.boxed(),
+        }
+    }
+
+    /// Serves the chunks of bytes produced by `stream`.
+    pub fn from_stream<S, B, E>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<B, E>> + Send + 'static,
+        B: Into<Bytes> + 'static,
+        E: Into<BoxDynError> + 'static,
+    {
+        let stream = stream
+            .map_ok(Into::into)
+            .map_err(|err| Error::Io(std::io::Error::new(std::io::ErrorKind::Other, err.into())));
+
+        Self {
+            stream: stream.boxed(),
+        }
+    }
+}
+
+impl Debug for MySqlLocalInfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MySqlLocalInfile").finish()
+    }
+}
+
+impl MySqlConnection {
+    /// Registers the contents of the file `name` for a following
+    /// `LOAD DATA LOCAL INFILE '<name>'` on this connection.
+    ///
+    /// The contents are served once; a registration is removed when the server requests it.
+    /// Requests for files that were not registered are refused, so a server can not read
+    /// arbitrary files from the client.
+    ///
+    /// The connection must have been opened with
+    /// [`MySqlConnectOptions::local_infile`](super::MySqlConnectOptions::local_infile).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_core::error::Error;
+    /// # use sqlx_core::connection::ConnectOptions;
+    /// # use sqlx_core::executor::Executor;
+    /// # use sqlx_core::mysql::{MySqlConnectOptions, MySqlLocalInfile};
+    /// #
+    /// # fn main() {
+    /// # #[cfg(feature = "_rt-async-std")]
+    /// # sqlx_rt::async_std::task::block_on::<_, Result<(), Error>>(async move {
+    /// let mut conn = MySqlConnectOptions::new()
+    ///     .local_infile(true)
+    ///     .connect()
+    ///     .await?;
+    ///
+    /// let file = sqlx_rt::fs::File::open("accounts.csv").await?;
+    /// conn.register_local_infile("accounts.csv", MySqlLocalInfile::from_reader(file));
+    ///
+    /// conn.execute("LOAD DATA LOCAL INFILE 'accounts.csv' INTO TABLE accounts").await?;
+    /// # Ok(())
+    /// # }).unwrap();
+    /// # }
+    /// ```
+    pub fn register_local_infile(&mut self, name: impl Into<String>, file: MySqlLocalInfile) {
+        self.local_infile.insert(name.into(), file);
+    }
+
+    // https://dev.mysql.com/doc/internals/en/com-query-response.html#packet-Protocol::LOCAL_INFILE_Request
+    pub(super) async fn send_local_infile(&mut self, name: &[u8]) -> Result<(), Error> {
+        let name = String::from_utf8_lossy(name);
+
+        let error = match self.local_infile.remove(&*name) {
+            Some(file) => self.send_local_infile_contents(file).await.err(),
+
+            None => Some(err_protocol!(
+                "server requested the local file {:?} which was not registered",
+                name
+            )),
+        };
+
+        // the file always ends with an empty packet
+        self.stream.write_packet(&[][..]);
+        self.stream.flush().await?;
+
+        if let Some(error) = error {
+            // the server responds with OK or ERR after the (possibly incomplete) file,
+            // which we consume to report our own error instead
+            match self.stream.recv_packet().await {
+                Ok(packet) => {
+                    if !packet
+                        .ok()?
+                        .status
+                        .contains(Status::SERVER_MORE_RESULTS_EXISTS)
+                    {
+                        self.stream.busy = Busy::NotBusy;
+                    }
+                }
+
+                Err(Error::Database(_)) => {}
+                Err(error) => return Err(error),
+            }
+
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    async fn send_local_infile_contents(
+        &mut self,
+        mut file: MySqlLocalInfile,
+    ) -> Result<(), Error> {
+        while let Some(chunk) = file.stream.try_next().await? {
+            for payload in chunk.chunks(MAX_PAYLOAD_SIZE) {
+                self.stream.write_packet(payload);
+            }
+
+            self.stream.flush().await?;
+        }
+
+        Ok(())
+    }
+}
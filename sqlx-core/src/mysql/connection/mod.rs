@@ -6,16 +6,22 @@ use crate::mysql::protocol::text::{Ping, Quit};
 use crate::mysql::statement::MySqlStatementMetadata;
 use crate::mysql::{MySql, MySqlConnectOptions};
 use crate::transaction::Transaction;
+use crate::HashMap;
 use futures_core::future::BoxFuture;
 use futures_util::FutureExt;
 use std::fmt::{self, Debug, Formatter};
 
 mod auth;
+mod compression;
 mod establish;
 mod executor;
+mod local_infile;
+mod reset;
 mod stream;
 mod tls;
 
+pub use local_infile::MySqlLocalInfile;
+use reset::ResetOnRelease;
 pub(crate) use stream::{Busy, MySqlStream};
 
 const MAX_PACKET_SIZE: u32 = 1024;
@@ -33,6 +39,12 @@ pub struct MySqlConnection {
     // cache by query string to the statement id and metadata
     cache_statement: StatementCache<(u32, MySqlStatementMetadata)>,
 
+    // files served to `LOAD DATA LOCAL INFILE`, by name
+    local_infile: HashMap<String, MySqlLocalInfile>,
+
+    // set when the session is reset on release to a pool
+    reset_on_release: Option<Box<ResetOnRelease>>,
+
     log_settings: LogSettings,
 }
 
@@ -94,6 +106,19 @@ impl Connection for MySqlConnection {
         !self.stream.wbuf.is_empty()
     }
 
+    #[doc(hidden)]
+    fn reset(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            self.stream.wait_until_ready().await?;
+            self.reset_session().await
+        })
+    }
+
+    #[doc(hidden)]
+    fn should_reset(&self) -> bool {
+        self.reset_on_release.is_some()
+    }
+
     fn begin(&mut self) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
     where
         Self: Sized,
@@ -0,0 +1,165 @@
+use bytes::buf::ext::Chain;
+use bytes::Bytes;
+
+use crate::error::Error;
+use crate::mysql::connection::establish::authenticate;
+use crate::mysql::protocol::auth::AuthPlugin;
+use crate::mysql::protocol::connect::ChangeUser;
+use crate::mysql::protocol::text::ResetConnection;
+use crate::mysql::{MySqlConnectOptions, MySqlConnection, MySqlDatabaseError};
+
+// https://dev.mysql.com/doc/refman/8.0/en/server-error-reference.html#error_er_unknown_com_error
+const ER_UNKNOWN_COM_ERROR: u16 = 1047;
+
+// what is needed to reset the session of a connection when it is released to a pool
+pub(super) struct ResetOnRelease {
+    // unset when the server does not know `COM_RESET_CONNECTION`
+    reset_connection: bool,
+
+    // to authenticate again with `COM_CHANGE_USER`, only kept when the server
+    // may not know `COM_RESET_CONNECTION`
+    change_user: Option<ChangeUserCredentials>,
+}
+
+struct ChangeUserCredentials {
+    username: String,
+    password: Option<String>,
+    database: Option<String>,
+    plugin: Option<AuthPlugin>,
+    nonce: Chain<Bytes, Bytes>,
+}
+
+impl ResetOnRelease {
+    pub(super) fn new(
+        server_version: &str,
+        options: &MySqlConnectOptions,
+        plugin: Option<AuthPlugin>,
+        nonce: Chain<Bytes, Bytes>,
+    ) -> Self {
+        let supported = supports_reset_connection(server_version);
+
+        let change_user = if supported == Some(true) {
+            None
+        } else {
+            Some(ChangeUserCredentials {
+                username: options.username.clone(),
+                password: options.password.clone(),
+                database: options.database.clone(),
+                plugin,
+                nonce,
+            })
+        };
+
+        Self {
+            // a server of an unknown version is asked first
+            reset_connection: supported != Some(false),
+            change_user,
+        }
+    }
+}
+
+// `COM_RESET_CONNECTION` was added in MySQL 5.7.3 and MariaDB 10.2.4;
+// returns `None` when the version cannot be parsed
+fn supports_reset_connection(server_version: &str) -> Option<bool> {
+    // MariaDB prefixes its version with `5.5.5-` for the replication protocol
+    let version = server_version
+        .strip_prefix("5.5.5-")
+        .unwrap_or(server_version);
+
+    let mut parts = version.split(|c: char| !c.is_ascii_digit());
+
+    let mut next = || -> Option<u16> { parts.next()?.parse().ok() };
+    let version = (next()?, next()?, next()?);
+
+    Some(if server_version.contains("MariaDB") {
+        version >= (10, 2, 4)
+    } else {
+        version >= (5, 7, 3)
+    })
+}
+
+impl MySqlConnection {
+    // resets the session to the state of a new connection, if enabled by
+    // `MySqlConnectOptions::reset_on_release`
+    pub(super) async fn reset_session(&mut self) -> Result<(), Error> {
+        let reset = match &mut self.reset_on_release {
+            Some(reset) => reset,
+            None => return Ok(()),
+        };
+
+        if reset.reset_connection {
+            self.stream.send_packet(ResetConnection).await?;
+
+            match self.stream.recv_ok().await {
+                Ok(_) => {}
+
+                Err(Error::Database(error))
+                    if reset.change_user.is_some()
+                        && error.downcast_ref::<MySqlDatabaseError>().number()
+                            == ER_UNKNOWN_COM_ERROR =>
+                {
+                    reset.reset_connection = false;
+                }
+
+                Err(error) => return Err(error),
+            }
+        }
+
+        if let (false, Some(credentials)) = (reset.reset_connection, &mut reset.change_user) {
+            // https://dev.mysql.com/doc/internals/en/com-change-user.html
+            let auth_response = match (credentials.plugin, &credentials.password) {
+                (Some(plugin), Some(password)) => Some(
+                    plugin
+                        .scramble(&mut self.stream, password, &credentials.nonce)
+                        .await?,
+                ),
+
+                _ => None,
+            };
+
+            let collation = self.stream.collation as u8;
+
+            self.stream
+                .send_packet(ChangeUser {
+                    username: &credentials.username,
+                    auth_response: auth_response.as_deref().unwrap_or_default(),
+                    database: credentials.database.as_deref(),
+                    collation,
+                    auth_plugin: credentials.plugin,
+                })
+                .await?;
+
+            authenticate(
+                &mut self.stream,
+                credentials.password.as_deref(),
+                &mut credentials.plugin,
+                &mut credentials.nonce,
+            )
+            .await?;
+        }
+
+        // the server has closed our prepared statements and rolled back any transaction
+        self.cache_statement.clear();
+        self.transaction_depth = 0;
+        self.local_infile.clear();
+
+        self.init_session().await
+    }
+}
+
+#[test]
+fn it_checks_support_for_reset_connection() {
+    assert_eq!(supports_reset_connection("8.0.22"), Some(true));
+    assert_eq!(supports_reset_connection("5.7.3-log"), Some(true));
+    assert_eq!(supports_reset_connection("5.6.50"), Some(false));
+    assert_eq!(
+        supports_reset_connection("5.5.5-10.4.12-MariaDB"),
+        Some(true)
+    );
+    assert_eq!(
+        supports_reset_connection("5.5.5-10.1.48-MariaDB-1~bionic"),
+        Some(false)
+    );
+    assert_eq!(supports_reset_connection("10.2.4-MariaDB"), Some(true));
+    assert_eq!(supports_reset_connection("unknown"), None);
+}
@@ -1,16 +1,20 @@
 use std::ops::{Deref, DerefMut};
 
-use bytes::{Buf, Bytes};
+use bytes::{Buf, Bytes, BytesMut};
 
 use crate::error::Error;
 use crate::io::{BufStream, Decode, Encode};
 use crate::mysql::collation::{CharSet, Collation};
+use crate::mysql::connection::compression::PacketCompression;
 use crate::mysql::io::MySqlBufExt;
 use crate::mysql::protocol::response::{EofPacket, ErrPacket, OkPacket, Status};
 use crate::mysql::protocol::{Capabilities, Packet};
-use crate::mysql::{MySqlConnectOptions, MySqlDatabaseError};
+use crate::mysql::{MySqlCompression, MySqlConnectOptions, MySqlDatabaseError};
 use crate::net::{MaybeTlsStream, Socket};
 
+// the largest payload of a single packet; larger payloads are split across packets
+const MAX_PAYLOAD_SIZE: usize = 0xFF_FF_FF;
+
 pub struct MySqlStream {
     stream: BufStream<MaybeTlsStream<Socket>>,
     pub(crate) server_version: (u16, u16, u16),
@@ -19,6 +23,9 @@ pub struct MySqlStream {
     pub(crate) busy: Busy,
     pub(crate) charset: CharSet,
     pub(crate) collation: Collation,
+
+    // set once compression was negotiated and the connection phase has completed
+    pub(super) compression: Option<PacketCompression>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -64,6 +71,18 @@ impl MySqlStream {
             capabilities |= Capabilities::CONNECT_WITH_DB;
         }
 
+        if options.local_infile {
+            capabilities |= Capabilities::LOCAL_FILES;
+        }
+
+        match options.compression {
+            MySqlCompression::Disabled => {}
+            MySqlCompression::Zlib => capabilities |= Capabilities::COMPRESS,
+
+            #[cfg(feature = "zstd")]
+            MySqlCompression::Zstd => capabilities |= Capabilities::ZSTD_COMPRESSION_ALGORITHM,
+        }
+
         Ok(Self {
             busy: Busy::NotBusy,
             capabilities,
@@ -72,12 +91,13 @@ impl MySqlStream {
             collation,
             charset,
             stream: BufStream::new(MaybeTlsStream::Raw(socket)),
+            compression: None,
         })
     }
 
     pub(crate) async fn wait_until_ready(&mut self) -> Result<(), Error> {
         if !self.stream.wbuf.is_empty() {
-            self.stream.flush().await?;
+            self.flush().await?;
         }
 
         while self.busy != Busy::NotBusy {
@@ -104,9 +124,22 @@ impl MySqlStream {
                     if !ok.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
                         self.busy = Busy::NotBusy;
                     }
+                } else if packet[0] == 0xfb {
+                    // the statement was dropped before the server requested a local file;
+                    // refuse it with an empty file and wait for the OK or ERR that follows
+                    self.write_packet(&[][..]);
+                    self.flush().await?;
                 } else {
-                    self.busy = Busy::Row;
-                    self.skip_result_metadata(packet).await?;
+                    let eof = self.skip_result_metadata(packet).await?;
+
+                    // the rows of a cursor are only sent once they are fetched
+                    self.busy = match eof {
+                        Some(eof) if eof.status.contains(Status::SERVER_STATUS_CURSOR_EXISTS) => {
+                            Busy::NotBusy
+                        }
+
+                        _ => Busy::Row,
+                    };
                 }
             }
         }
@@ -119,10 +152,25 @@ impl MySqlStream {
         T: Encode<'en, Capabilities>,
     {
         self.sequence_id = 0;
+
+        if let Some(compression) = &mut self.compression {
+            compression.sequence_id = 0;
+        }
+
         self.write_packet(payload);
         self.flush().await
     }
 
+    // writes the buffered packets to the stream, compressing them when enabled
+    pub(crate) async fn flush(&mut self) -> Result<(), Error> {
+        if let Some(compression) = &mut self.compression {
+            let packets = std::mem::take(&mut self.stream.wbuf);
+            compression.compress(&packets, &mut self.stream.wbuf)?;
+        }
+
+        self.stream.flush().await
+    }
+
     pub(crate) fn write_packet<'en, T>(&mut self, payload: T)
     where
         T: Encode<'en, Capabilities>,
@@ -137,17 +185,23 @@ impl MySqlStream {
         // https://dev.mysql.com/doc/dev/mysql-server/8.0.12/page_protocol_basic_packets.html
         // https://mariadb.com/kb/en/library/0-packet/#standard-packet
 
-        let mut header: Bytes = self.stream.read(4).await?;
+        let mut payload = self.recv_packet_part().await?;
 
-        let packet_size = header.get_uint_le(3) as usize;
-        let sequence_id = header.get_u8();
+        if payload.len() == MAX_PAYLOAD_SIZE {
+            // a payload of the maximum size continues in the next packet
+            let mut joined = BytesMut::from(&payload[..]);
 
-        self.sequence_id = sequence_id.wrapping_add(1);
+            loop {
+                let part = self.recv_packet_part().await?;
+                joined.extend_from_slice(&part);
 
-        let payload: Bytes = self.stream.read(packet_size).await?;
+                if part.len() < MAX_PAYLOAD_SIZE {
+                    break;
+                }
+            }
 
-        // TODO: packet compression
-        // TODO: packet joining
+            payload = joined.freeze();
+        }
 
         if payload[0] == 0xff {
             self.busy = Busy::NotBusy;
@@ -162,6 +216,38 @@ impl MySqlStream {
         Ok(Packet(payload))
     }
 
+    async fn recv_packet_part(&mut self) -> Result<Bytes, Error> {
+        let mut header: Bytes = self.read(4).await?;
+
+        let packet_size = header.get_uint_le(3) as usize;
+        let sequence_id = header.get_u8();
+
+        self.sequence_id = sequence_id.wrapping_add(1);
+
+        self.read(packet_size).await
+    }
+
+    // reads from the stream, or from the decompressed packets when compression is enabled
+    async fn read(&mut self, len: usize) -> Result<Bytes, Error> {
+        let compression = match &mut self.compression {
+            Some(compression) => compression,
+            None => return self.stream.read(len).await,
+        };
+
+        while compression.rbuf.len() < len {
+            let mut header: Bytes = self.stream.read(7).await?;
+
+            let compressed_len = header.get_uint_le(3) as usize;
+            compression.sequence_id = header.get_u8().wrapping_add(1);
+            let uncompressed_len = header.get_uint_le(3) as usize;
+
+            let payload: Bytes = self.stream.read(compressed_len).await?;
+            compression.decompress(&payload, uncompressed_len)?;
+        }
+
+        Ok(compression.rbuf.split_to(len).freeze())
+    }
+
     pub(crate) async fn recv<'de, T>(&mut self) -> Result<T, Error>
     where
         T: Decode<'de, Capabilities>,
@@ -181,16 +267,17 @@ impl MySqlStream {
         }
     }
 
-    async fn skip_result_metadata(&mut self, mut packet: Packet<Bytes>) -> Result<(), Error> {
+    async fn skip_result_metadata(
+        &mut self,
+        mut packet: Packet<Bytes>,
+    ) -> Result<Option<EofPacket>, Error> {
         let num_columns: u64 = packet.get_uint_lenenc(); // column count
 
         for _ in 0..num_columns {
             let _ = self.recv_packet().await?;
         }
 
-        self.maybe_recv_eof().await?;
-
-        Ok(())
+        self.maybe_recv_eof().await
     }
 }
 
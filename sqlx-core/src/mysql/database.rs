@@ -1,4 +1,6 @@
-use crate::database::{Database, HasArguments, HasStatement, HasStatementCache, HasValueRef};
+use crate::database::{
+    Database, HasArguments, HasFetchSize, HasStatement, HasStatementCache, HasValueRef,
+};
 use crate::mysql::value::{MySqlValue, MySqlValueRef};
 use crate::mysql::{
     MySqlArguments, MySqlColumn, MySqlConnection, MySqlDone, MySqlRow, MySqlStatement,
@@ -46,3 +48,5 @@ impl<'q> HasStatement<'q> for MySql {
 }
 
 impl HasStatementCache for MySql {}
+
+impl HasFetchSize for MySql {}
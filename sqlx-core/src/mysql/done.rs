@@ -6,12 +6,21 @@ use std::iter::{Extend, IntoIterator};
 pub struct MySqlDone {
     pub(super) rows_affected: u64,
     pub(super) last_insert_id: u64,
+    pub(super) result_set: usize,
 }
 
 impl MySqlDone {
     pub fn last_insert_id(&self) -> u64 {
         self.last_insert_id
     }
+
+    /// The index of the result set this ends, counting from 0.
+    ///
+    /// When the results of several result sets are combined, as by `execute`, this is the
+    /// index of the last one.
+    pub fn result_set(&self) -> usize {
+        self.result_set
+    }
 }
 
 impl Done for MySqlDone {
@@ -27,6 +36,7 @@ impl Extend<MySqlDone> for MySqlDone {
         for elem in iter {
             self.rows_affected += elem.rows_affected;
             self.last_insert_id = elem.last_insert_id;
+            self.result_set = elem.result_set;
         }
     }
 }
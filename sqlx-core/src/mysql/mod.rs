@@ -10,6 +10,7 @@ mod error;
 mod io;
 mod options;
 mod protocol;
+mod replication;
 mod row;
 mod statement;
 mod transaction;
@@ -22,11 +23,14 @@ mod migrate;
 
 pub use arguments::MySqlArguments;
 pub use column::MySqlColumn;
-pub use connection::MySqlConnection;
+pub use connection::{MySqlConnection, MySqlLocalInfile};
 pub use database::MySql;
 pub use done::MySqlDone;
 pub use error::MySqlDatabaseError;
-pub use options::{MySqlConnectOptions, MySqlSslMode};
+pub use options::{MySqlCompression, MySqlConnectOptions, MySqlSslMode};
+pub use replication::{
+    MySqlBinlogEvent, MySqlBinlogEventData, MySqlBinlogOptions, MySqlBinlogStream, MySqlBinlogTable,
+};
 pub use row::MySqlRow;
 pub use statement::MySqlStatement;
 pub use transaction::MySqlTransactionManager;
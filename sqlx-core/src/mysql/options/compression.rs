@@ -0,0 +1,47 @@
+use crate::error::Error;
+use std::str::FromStr;
+
+/// Options for compressing the packets exchanged with the MySQL server.
+///
+/// It is used by the [`compression`](MySqlConnectOptions::compression) method. When the server
+/// does not support the requested algorithm, the connection is not compressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MySqlCompression {
+    /// Do not compress the connection.
+    ///
+    /// This is the default if `compression` is not specified.
+    Disabled,
+
+    /// Compress with zlib (`CLIENT_COMPRESS`).
+    Zlib,
+
+    /// Compress with zstd (`CLIENT_ZSTD_COMPRESSION_ALGORITHM`), supported from MySQL 8.0.18.
+    #[cfg(feature = "zstd")]
+    Zstd,
+}
+
+impl Default for MySqlCompression {
+    fn default() -> Self {
+        MySqlCompression::Disabled
+    }
+}
+
+impl FromStr for MySqlCompression {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(match &*s.to_ascii_lowercase() {
+            "disabled" => MySqlCompression::Disabled,
+            "zlib" => MySqlCompression::Zlib,
+
+            #[cfg(feature = "zstd")]
+            "zstd" => MySqlCompression::Zstd,
+
+            _ => {
+                return Err(Error::Configuration(
+                    format!("unknown value {:?} for `compression`", s).into(),
+                ));
+            }
+        })
+    }
+}
@@ -16,53 +16,61 @@ impl ConnectOptions for MySqlConnectOptions {
         Box::pin(async move {
             let mut conn = MySqlConnection::establish(self).await?;
 
-            // After the connection is established, we initialize by configuring a few
-            // connection parameters
+            conn.init_session().await?;
 
-            // https://mariadb.com/kb/en/sql-mode/
+            Ok(conn)
+        })
+    }
+
+    fn log_statements(&mut self, level: LevelFilter) -> &mut Self {
+        self.log_settings.log_statements(level);
+        self
+    }
+
+    fn log_slow_statements(&mut self, level: LevelFilter, duration: Duration) -> &mut Self {
+        self.log_settings.log_slow_statements(level, duration);
+        self
+    }
+}
 
-            // PIPES_AS_CONCAT - Allows using the pipe character (ASCII 124) as string concatenation operator.
-            //                   This means that "A" || "B" can be used in place of CONCAT("A", "B").
+impl MySqlConnection {
+    pub(crate) async fn init_session(&mut self) -> Result<(), Error> {
+        // After the connection is established (or its session is reset), we initialize by
+        // configuring a few connection parameters
 
-            // NO_ENGINE_SUBSTITUTION - If not set, if the available storage engine specified by a CREATE TABLE is
-            //                          not available, a warning is given and the default storage
-            //                          engine is used instead.
+        // https://mariadb.com/kb/en/sql-mode/
 
-            // NO_ZERO_DATE - Don't allow '0000-00-00'. This is invalid in Rust.
+        // PIPES_AS_CONCAT - Allows using the pipe character (ASCII 124) as string concatenation operator.
+        //                   This means that "A" || "B" can be used in place of CONCAT("A", "B").
 
-            // NO_ZERO_IN_DATE - Don't allow 'YYYY-00-00'. This is invalid in Rust.
+        // NO_ENGINE_SUBSTITUTION - If not set, if the available storage engine specified by a CREATE TABLE is
+        //                          not available, a warning is given and the default storage
+        //                          engine is used instead.
 
-            // --
+        // NO_ZERO_DATE - Don't allow '0000-00-00'. This is invalid in Rust.
 
-            // Setting the time zone allows us to assume that the output
-            // from a TIMESTAMP field is UTC
+        // NO_ZERO_IN_DATE - Don't allow 'YYYY-00-00'. This is invalid in Rust.
 
-            // --
+        // --
 
-            // https://mathiasbynens.be/notes/mysql-utf8mb4
+        // Setting the time zone allows us to assume that the output
+        // from a TIMESTAMP field is UTC
 
-            let mut options = String::new();
-            options.push_str(r#"SET sql_mode=(SELECT CONCAT(@@sql_mode, ',PIPES_AS_CONCAT,NO_ENGINE_SUBSTITUTION')),"#);
-            options.push_str(r#"time_zone='+00:00',"#);
-            options.push_str(&format!(
-                r#"NAMES {} COLLATE {};"#,
-                conn.stream.charset.as_str(),
-                conn.stream.collation.as_str()
-            ));
+        // --
 
-            conn.execute(&*options).await?;
+        // https://mathiasbynens.be/notes/mysql-utf8mb4
 
-            Ok(conn)
-        })
-    }
+        let mut options = String::new();
+        options.push_str(r#"SET sql_mode=(SELECT CONCAT(@@sql_mode, ',PIPES_AS_CONCAT,NO_ENGINE_SUBSTITUTION')),"#);
+        options.push_str(r#"time_zone='+00:00',"#);
+        options.push_str(&format!(
+            r#"NAMES {} COLLATE {};"#,
+            self.stream.charset.as_str(),
+            self.stream.collation.as_str()
+        ));
 
-    fn log_statements(&mut self, level: LevelFilter) -> &mut Self {
-        self.log_settings.log_statements(level);
-        self
-    }
+        self.execute(&*options).await?;
 
-    fn log_slow_statements(&mut self, level: LevelFilter, duration: Duration) -> &mut Self {
-        self.log_settings.log_slow_statements(level, duration);
-        self
+        Ok(())
     }
 }
@@ -1,10 +1,12 @@
 use std::path::{Path, PathBuf};
 
+mod compression;
 mod connect;
 mod parse;
 mod ssl_mode;
 
 use crate::connection::LogSettings;
+pub use compression::MySqlCompression;
 pub use ssl_mode::MySqlSslMode;
 
 /// Options and flags which can be used to configure a MySQL connection.
@@ -26,6 +28,9 @@ pub use ssl_mode::MySqlSslMode;
 /// | `ssl-ca` | `None` | Sets the name of a file containing a list of trusted SSL Certificate Authorities. |
 /// | `statement-cache-capacity` | `100` | The maximum number of prepared statements stored in the cache. Set to `0` to disable. |
 /// | `socket` | `None` | Path to the unix domain socket, which will be used instead of TCP if set. |
+/// | `local-infile` | `false` | Allows `LOAD DATA LOCAL INFILE` to read files registered on the connection. |
+/// | `reset-on-release` | `false` | Resets the session when the connection is released to a pool. |
+/// | `compression` | `DISABLED` | Compresses the connection with `zlib` or `zstd`. See [`MySqlCompression`]. |
 ///
 /// # Example
 ///
@@ -53,6 +58,7 @@ pub use ssl_mode::MySqlSslMode;
 /// ```
 ///
 /// [`MySqlSslMode`]: enum.MySqlSslMode.html
+/// [`MySqlCompression`]: enum.MySqlCompression.html
 #[derive(Debug, Clone)]
 pub struct MySqlConnectOptions {
     pub(crate) host: String,
@@ -66,6 +72,9 @@ pub struct MySqlConnectOptions {
     pub(crate) statement_cache_capacity: usize,
     pub(crate) charset: String,
     pub(crate) collation: Option<String>,
+    pub(crate) local_infile: bool,
+    pub(crate) reset_on_release: bool,
+    pub(crate) compression: MySqlCompression,
     pub(crate) log_settings: LogSettings,
 }
 
@@ -90,6 +99,9 @@ impl MySqlConnectOptions {
             ssl_mode: MySqlSslMode::Preferred,
             ssl_ca: None,
             statement_cache_capacity: 100,
+            local_infile: false,
+            reset_on_release: false,
+            compression: MySqlCompression::Disabled,
             log_settings: Default::default(),
         }
     }
@@ -199,4 +211,54 @@ impl MySqlConnectOptions {
         self.collation = Some(collation.to_owned());
         self
     }
+
+    /// Sets whether `LOAD DATA LOCAL INFILE` may be used on the connection.
+    ///
+    /// The server can only read files that have been registered with
+    /// [`MySqlConnection::register_local_infile`](super::MySqlConnection::register_local_infile).
+    ///
+    /// The default is `false`.
+    pub fn local_infile(mut self, on: bool) -> Self {
+        self.local_infile = on;
+        self
+    }
+
+    /// Sets whether the session is reset when the connection is released to a pool.
+    ///
+    /// On release, `COM_RESET_CONNECTION` rolls back any transaction, drops temporary
+    /// tables, releases locks, and resets user and session variables. Servers without the
+    /// command (before MySQL 5.7.3 or MariaDB 10.2.4) are sent `COM_CHANGE_USER` instead,
+    /// for which the connection keeps its username and password.
+    /// Both close the prepared statements of the session, so the statement cache is
+    /// cleared as well.
+    ///
+    /// The session is configured again as if it was a new connection, but any changes made by
+    /// [`PoolOptions::after_connect`](crate::pool::PoolOptions::after_connect) are lost.
+    ///
+    /// The default is `false`.
+    pub fn reset_on_release(mut self, on: bool) -> Self {
+        self.reset_on_release = on;
+        self
+    }
+
+    /// Sets whether or with which algorithm the packets exchanged with the server
+    /// are compressed.
+    ///
+    /// Compression trades CPU time for bandwidth, which pays off for large result sets over
+    /// slow links. The connection is not compressed when the server does not support the
+    /// algorithm.
+    ///
+    /// The default is [`MySqlCompression::Disabled`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mysql::{MySqlCompression, MySqlConnectOptions};
+    /// let options = MySqlConnectOptions::new()
+    ///     .compression(MySqlCompression::Zlib);
+    /// ```
+    pub fn compression(mut self, compression: MySqlCompression) -> Self {
+        self.compression = compression;
+        self
+    }
 }
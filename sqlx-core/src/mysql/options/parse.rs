@@ -68,6 +68,18 @@ impl FromStr for MySqlConnectOptions {
                     options = options.socket(&*value);
                 }
 
+                "local-infile" => {
+                    options = options.local_infile(value.parse().map_err(Error::config)?);
+                }
+
+                "compression" => {
+                    options = options.compression(value.parse().map_err(Error::config)?);
+                }
+
+                "reset-on-release" => {
+                    options = options.reset_on_release(value.parse().map_err(Error::config)?);
+                }
+
                 _ => {}
             }
         }
@@ -91,3 +103,32 @@ fn it_parses_password_with_non_ascii_chars_correctly() {
 
     assert_eq!(Some("p@ssw0rd".into()), opts.password);
 }
+
+#[test]
+fn it_parses_local_infile() {
+    let uri = "mysql://root@localhost/database?local-infile=true";
+    let opts = MySqlConnectOptions::from_str(uri).unwrap();
+
+    assert!(opts.local_infile);
+}
+
+#[test]
+fn it_parses_reset_on_release() {
+    let uri = "mysql://root@localhost/database?reset-on-release=true";
+    let opts = MySqlConnectOptions::from_str(uri).unwrap();
+
+    assert!(opts.reset_on_release);
+}
+
+#[test]
+fn it_parses_compression() {
+    use crate::mysql::MySqlCompression;
+
+    let uri = "mysql://root@localhost/database?compression=zlib";
+    let opts = MySqlConnectOptions::from_str(uri).unwrap();
+
+    assert_eq!(opts.compression, MySqlCompression::Zlib);
+
+    let uri = "mysql://root@localhost/database?compression=lz4";
+    assert!(MySqlConnectOptions::from_str(uri).is_err());
+}
@@ -7,6 +7,8 @@ pub enum AuthPlugin {
     MySqlNativePassword,
     CachingSha2Password,
     Sha256Password,
+    ClientEd25519,
+    MySqlClearPassword,
 }
 
 impl AuthPlugin {
@@ -15,6 +17,8 @@ impl AuthPlugin {
             AuthPlugin::MySqlNativePassword => "mysql_native_password",
             AuthPlugin::CachingSha2Password => "caching_sha2_password",
             AuthPlugin::Sha256Password => "sha256_password",
+            AuthPlugin::ClientEd25519 => "client_ed25519",
+            AuthPlugin::MySqlClearPassword => "mysql_clear_password",
         }
     }
 }
@@ -27,8 +31,13 @@ impl FromStr for AuthPlugin {
             "mysql_native_password" => Ok(AuthPlugin::MySqlNativePassword),
             "caching_sha2_password" => Ok(AuthPlugin::CachingSha2Password),
             "sha256_password" => Ok(AuthPlugin::Sha256Password),
+            "client_ed25519" => Ok(AuthPlugin::ClientEd25519),
+            "mysql_clear_password" => Ok(AuthPlugin::MySqlClearPassword),
 
-            _ => Err(err_protocol!("unknown authentication plugin: {}", s)),
+            _ => Err(err_protocol!(
+                "server requested unsupported authentication plugin: {:?}",
+                s
+            )),
         }
     }
 }
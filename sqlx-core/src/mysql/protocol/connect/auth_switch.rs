@@ -39,3 +39,19 @@ impl Encode<'_, Capabilities> for AuthSwitchResponse {
         buf.extend_from_slice(&self.0);
     }
 }
+
+#[test]
+fn it_decodes_auth_switch_request() {
+    let mut packet = b"\xfeclient_ed25519\x00".to_vec();
+    packet.extend(&[0x2a; 32]);
+
+    let switch = AuthSwitchRequest::decode(Bytes::from(packet)).unwrap();
+
+    assert!(matches!(switch.plugin, AuthPlugin::ClientEd25519));
+    assert_eq!(&switch.data[..], &[0x2a; 32][..]);
+
+    let err =
+        AuthSwitchRequest::decode(Bytes::from_static(b"\xfeauth_gssapi_client\x00")).unwrap_err();
+
+    assert!(err.to_string().contains("auth_gssapi_client"));
+}
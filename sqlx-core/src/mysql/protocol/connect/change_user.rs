@@ -0,0 +1,67 @@
+use crate::io::{BufMutExt, Encode};
+use crate::mysql::protocol::auth::AuthPlugin;
+use crate::mysql::protocol::Capabilities;
+
+// https://dev.mysql.com/doc/internals/en/com-change-user.html
+// https://mariadb.com/kb/en/com_change_user/
+
+#[derive(Debug)]
+pub struct ChangeUser<'a> {
+    pub username: &'a str,
+
+    /// Opaque authentication response
+    pub auth_response: &'a [u8],
+
+    pub database: Option<&'a str>,
+
+    /// Default collation for the connection
+    pub collation: u8,
+
+    /// Authentication method used by the client
+    pub auth_plugin: Option<AuthPlugin>,
+}
+
+impl Encode<'_, Capabilities> for ChangeUser<'_> {
+    fn encode_with(&self, buf: &mut Vec<u8>, capabilities: Capabilities) {
+        buf.push(0x11); // COM_CHANGE_USER
+        buf.put_str_nul(self.username);
+
+        if capabilities.contains(Capabilities::SECURE_CONNECTION) {
+            buf.push(self.auth_response.len() as u8);
+            buf.extend(self.auth_response);
+        } else {
+            buf.extend(self.auth_response);
+            buf.push(0);
+        }
+
+        buf.put_str_nul(self.database.unwrap_or_default());
+
+        buf.extend(&u16::from(self.collation).to_le_bytes());
+
+        if capabilities.contains(Capabilities::PLUGIN_AUTH) {
+            buf.put_str_nul(self.auth_plugin.map_or("", |plugin| plugin.name()));
+        }
+    }
+}
+
+#[test]
+fn it_encodes_change_user() {
+    let mut buf = Vec::new();
+
+    ChangeUser {
+        username: "root",
+        auth_response: &[0xab, 0xcd],
+        database: Some("db"),
+        collation: 224,
+        auth_plugin: Some(AuthPlugin::MySqlNativePassword),
+    }
+    .encode_with(
+        &mut buf,
+        Capabilities::SECURE_CONNECTION | Capabilities::PLUGIN_AUTH,
+    );
+
+    assert_eq!(
+        &buf[..],
+        &b"\x11root\0\x02\xab\xcddb\0\xe0\0mysql_native_password\0"[..]
+    );
+}
@@ -2,6 +2,7 @@ pub(crate) mod auth;
 mod capabilities;
 pub(crate) mod connect;
 mod packet;
+pub(crate) mod replication;
 pub(crate) mod response;
 mod row;
 pub(crate) mod statement;
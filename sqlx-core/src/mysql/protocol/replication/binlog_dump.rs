@@ -0,0 +1,62 @@
+use crate::io::Encode;
+use crate::mysql::protocol::Capabilities;
+
+bitflags::bitflags! {
+    pub(crate) struct BinlogDumpFlags: u16 {
+        // Send an EOF packet instead of blocking at the end of the binary log.
+        const NON_BLOCK = 1;
+
+        // The GTID set of `COM_BINLOG_DUMP_GTID` is present.
+        const THROUGH_GTID = 4;
+    }
+}
+
+// https://dev.mysql.com/doc/internals/en/com-binlog-dump.html
+
+#[derive(Debug)]
+pub(crate) struct BinlogDump<'a> {
+    pub(crate) position: u32,
+    pub(crate) flags: BinlogDumpFlags,
+    pub(crate) server_id: u32,
+    pub(crate) filename: &'a str,
+}
+
+impl Encode<'_, Capabilities> for BinlogDump<'_> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        buf.push(0x12); // COM_BINLOG_DUMP
+        buf.extend(&self.position.to_le_bytes());
+        buf.extend(&self.flags.bits().to_le_bytes());
+        buf.extend(&self.server_id.to_le_bytes());
+        buf.extend(self.filename.as_bytes());
+    }
+}
+
+// https://dev.mysql.com/doc/internals/en/com-binlog-dump-gtid.html
+
+#[derive(Debug)]
+pub(crate) struct BinlogDumpGtid<'a> {
+    pub(crate) flags: BinlogDumpFlags,
+    pub(crate) server_id: u32,
+    pub(crate) filename: &'a str,
+    pub(crate) position: u64,
+
+    // the encoded GTID set
+    pub(crate) gtid_set: &'a [u8],
+}
+
+impl Encode<'_, Capabilities> for BinlogDumpGtid<'_> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        buf.push(0x1e); // COM_BINLOG_DUMP_GTID
+        buf.extend(
+            &(self.flags | BinlogDumpFlags::THROUGH_GTID)
+                .bits()
+                .to_le_bytes(),
+        );
+        buf.extend(&self.server_id.to_le_bytes());
+        buf.extend(&(self.filename.len() as u32).to_le_bytes());
+        buf.extend(self.filename.as_bytes());
+        buf.extend(&self.position.to_le_bytes());
+        buf.extend(&(self.gtid_set.len() as u32).to_le_bytes());
+        buf.extend_from_slice(self.gtid_set);
+    }
+}
@@ -0,0 +1,68 @@
+use bytes::{Buf, Bytes};
+
+use crate::error::Error;
+use crate::io::Decode;
+
+// https://dev.mysql.com/doc/internals/en/binlog-event-type.html
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct EventType(pub(crate) u8);
+
+impl EventType {
+    pub(crate) const QUERY: EventType = EventType(0x02);
+    pub(crate) const ROTATE: EventType = EventType(0x04);
+    pub(crate) const FORMAT_DESCRIPTION: EventType = EventType(0x0f);
+    pub(crate) const XID: EventType = EventType(0x10);
+    pub(crate) const TABLE_MAP: EventType = EventType(0x13);
+    pub(crate) const WRITE_ROWS_V1: EventType = EventType(0x17);
+    pub(crate) const UPDATE_ROWS_V1: EventType = EventType(0x18);
+    pub(crate) const DELETE_ROWS_V1: EventType = EventType(0x19);
+    pub(crate) const HEARTBEAT: EventType = EventType(0x1b);
+    pub(crate) const WRITE_ROWS_V2: EventType = EventType(0x1e);
+    pub(crate) const UPDATE_ROWS_V2: EventType = EventType(0x1f);
+    pub(crate) const DELETE_ROWS_V2: EventType = EventType(0x20);
+    pub(crate) const GTID: EventType = EventType(0x21);
+}
+
+// https://dev.mysql.com/doc/internals/en/binlog-event-header.html
+
+#[derive(Debug)]
+pub(crate) struct EventHeader {
+    pub(crate) timestamp: u32,
+    pub(crate) event_type: EventType,
+    pub(crate) server_id: u32,
+    pub(crate) log_position: u32,
+}
+
+impl EventHeader {
+    pub(crate) const SIZE: usize = 19;
+}
+
+impl Decode<'_> for EventHeader {
+    fn decode_with(mut buf: Bytes, _: ()) -> Result<Self, Error> {
+        if buf.len() < Self::SIZE {
+            return Err(err_protocol!(
+                "expected a binlog event header of {} bytes but found {}",
+                Self::SIZE,
+                buf.len()
+            ));
+        }
+
+        let timestamp = buf.get_u32_le();
+        let event_type = EventType(buf.get_u8());
+        let server_id = buf.get_u32_le();
+
+        // the size of the event is already known from its packet
+        let _event_size = buf.get_u32_le();
+
+        let log_position = buf.get_u32_le();
+        let _flags = buf.get_u16_le();
+
+        Ok(Self {
+            timestamp,
+            event_type,
+            server_id,
+            log_position,
+        })
+    }
+}
@@ -0,0 +1,7 @@
+mod binlog_dump;
+mod event;
+mod register_slave;
+
+pub(crate) use binlog_dump::{BinlogDump, BinlogDumpFlags, BinlogDumpGtid};
+pub(crate) use event::{EventHeader, EventType};
+pub(crate) use register_slave::RegisterSlave;
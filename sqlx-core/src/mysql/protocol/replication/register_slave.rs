@@ -0,0 +1,36 @@
+use crate::io::Encode;
+use crate::mysql::protocol::Capabilities;
+
+// https://dev.mysql.com/doc/internals/en/com-register-slave.html
+
+#[derive(Debug)]
+pub(crate) struct RegisterSlave<'a> {
+    pub(crate) server_id: u32,
+    pub(crate) hostname: &'a str,
+    pub(crate) user: &'a str,
+    pub(crate) password: &'a str,
+    pub(crate) port: u16,
+}
+
+impl Encode<'_, Capabilities> for RegisterSlave<'_> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        buf.push(0x15); // COM_REGISTER_SLAVE
+        buf.extend(&self.server_id.to_le_bytes());
+
+        for s in &[self.hostname, self.user, self.password] {
+            // each string is prefixed by a 1-byte length
+            let s = &s.as_bytes()[..s.len().min(0xff)];
+
+            buf.push(s.len() as u8);
+            buf.extend_from_slice(s);
+        }
+
+        buf.extend(&self.port.to_le_bytes());
+
+        // replication rank, ignored
+        buf.extend(&0_u32.to_le_bytes());
+
+        // master id, usually 0
+        buf.extend(&0_u32.to_le_bytes());
+    }
+}
@@ -9,13 +9,20 @@ use crate::mysql::MySqlArguments;
 pub struct Execute<'q> {
     pub statement: u32,
     pub arguments: &'q MySqlArguments,
+
+    // opens a read-only cursor, of which the rows are read with `COM_STMT_FETCH`
+    pub cursor: bool,
 }
 
 impl<'q> Encode<'_, Capabilities> for Execute<'q> {
     fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
         buf.push(0x17); // COM_STMT_EXECUTE
         buf.extend(&self.statement.to_le_bytes());
-        buf.push(0); // NO_CURSOR
+        buf.push(if self.cursor {
+            0x01 // CURSOR_TYPE_READ_ONLY
+        } else {
+            0x00 // CURSOR_TYPE_NO_CURSOR
+        });
         buf.extend(&1_u32.to_le_bytes()); // iterations (always 1): int<4>
 
         if !self.arguments.types.is_empty() {
@@ -36,3 +43,18 @@ impl<'q> Encode<'_, Capabilities> for Execute<'q> {
         }
     }
 }
+
+#[test]
+fn it_encodes_execute_with_cursor() {
+    let arguments = MySqlArguments::default();
+
+    let mut buf = Vec::new();
+    Execute {
+        statement: 1,
+        arguments: &arguments,
+        cursor: true,
+    }
+    .encode_with(&mut buf, Capabilities::empty());
+
+    assert_eq!(buf, b"\x17\x01\x00\x00\x00\x01\x01\x00\x00\x00");
+}
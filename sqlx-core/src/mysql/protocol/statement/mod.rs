@@ -1,11 +1,15 @@
 mod execute;
+mod fetch;
 mod prepare;
 mod prepare_ok;
 mod row;
+mod send_long_data;
 mod stmt_close;
 
 pub(crate) use execute::Execute;
+pub(crate) use fetch::Fetch;
 pub(crate) use prepare::Prepare;
 pub(crate) use prepare_ok::PrepareOk;
 pub(crate) use row::BinaryRow;
+pub(crate) use send_long_data::SendLongData;
 pub(crate) use stmt_close::StmtClose;
@@ -0,0 +1,498 @@
+use bytes::{Buf, Bytes};
+use std::sync::Arc;
+
+use crate::error::Error;
+use crate::ext::ustr::UStr;
+use crate::io::{BufExt, Decode};
+use crate::mysql::protocol::replication::{EventHeader, EventType};
+use crate::mysql::protocol::Row;
+use crate::mysql::replication::gtid::format_gtid;
+use crate::mysql::replication::value::{take, uint_lenenc, BinlogColumn};
+use crate::mysql::replication::{MySqlBinlogEvent, MySqlBinlogEventData, MySqlBinlogTable};
+use crate::mysql::{MySqlColumn, MySqlRow, MySqlValueFormat};
+use crate::HashMap;
+
+// https://dev.mysql.com/doc/internals/en/binlog-event.html
+
+// optional metadata of a `TABLE_MAP`, written with `binlog_row_metadata = FULL`
+const METADATA_SIGNEDNESS: u8 = 1;
+const METADATA_COLUMN_NAME: u8 = 4;
+
+/// Decodes the events of a binary log, keeping the state that later events depend on.
+#[derive(Debug)]
+pub(crate) struct EventDecoder {
+    // events end with a CRC32 checksum
+    checksum: bool,
+
+    // the size of a table id in `TABLE_MAP` and rows events
+    table_id_size: usize,
+
+    // tables by id, from their most recent `TABLE_MAP`
+    tables: HashMap<u64, Arc<MySqlBinlogTable>>,
+}
+
+impl EventDecoder {
+    pub(crate) fn new(checksum: bool) -> Self {
+        Self {
+            checksum,
+            table_id_size: 6,
+            tables: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn decode(&mut self, mut buf: Bytes) -> Result<Option<MySqlBinlogEvent>, Error> {
+        let header = EventHeader::decode(take(&mut buf, EventHeader::SIZE)?)?;
+
+        if self.checksum {
+            // NOTE: the connection is already protected against corruption by TCP
+            //       (and TLS), so the checksum is not verified
+            buf.truncate(buf.len().saturating_sub(4));
+        }
+
+        let data = match header.event_type {
+            EventType::FORMAT_DESCRIPTION => {
+                self.decode_format_description(buf)?;
+
+                return Ok(None);
+            }
+
+            EventType::ROTATE => {
+                let position = take(&mut buf, 8)?.get_u64_le();
+                let file = String::from_utf8_lossy(&buf).into_owned();
+
+                MySqlBinlogEventData::Rotate { file, position }
+            }
+
+            EventType::QUERY => {
+                let _thread_id = take(&mut buf, 4)?;
+                let _execution_time = take(&mut buf, 4)?;
+                let schema_len = take(&mut buf, 1)?.get_u8() as usize;
+                let _error_code = take(&mut buf, 2)?;
+                let status_len = take(&mut buf, 2)?.get_u16_le() as usize;
+                let _status = take(&mut buf, status_len)?;
+
+                let schema = String::from_utf8_lossy(&take(&mut buf, schema_len)?).into_owned();
+                let _nul = take(&mut buf, 1)?;
+                let query = String::from_utf8_lossy(&buf).into_owned();
+
+                MySqlBinlogEventData::Query { schema, query }
+            }
+
+            EventType::XID => MySqlBinlogEventData::Xid {
+                xid: take(&mut buf, 8)?.get_u64_le(),
+            },
+
+            EventType::GTID => {
+                let _flags = take(&mut buf, 1)?;
+                let sid = take(&mut buf, 16)?;
+                let gno = take(&mut buf, 8)?.get_u64_le();
+
+                MySqlBinlogEventData::Gtid {
+                    gtid: format_gtid(&sid, gno),
+                }
+            }
+
+            EventType::TABLE_MAP => {
+                let table = Arc::new(self.decode_table_map(buf)?);
+                self.tables.insert(table.id, Arc::clone(&table));
+
+                MySqlBinlogEventData::TableMap(table)
+            }
+
+            EventType::WRITE_ROWS_V1 | EventType::WRITE_ROWS_V2 => {
+                let (table, rows) = self.decode_rows(header.event_type, buf)?;
+
+                MySqlBinlogEventData::WriteRows {
+                    table,
+                    rows: rows.into_iter().map(|(row, _)| row).collect(),
+                }
+            }
+
+            EventType::UPDATE_ROWS_V1 | EventType::UPDATE_ROWS_V2 => {
+                let (table, rows) = self.decode_rows(header.event_type, buf)?;
+
+                MySqlBinlogEventData::UpdateRows {
+                    table,
+                    rows: rows
+                        .into_iter()
+                        .map(|(before, after)| {
+                            after.map(|after| (before, after)).ok_or_else(|| {
+                                err_protocol!("update rows event without an after image")
+                            })
+                        })
+                        .collect::<Result<_, Error>>()?,
+                }
+            }
+
+            EventType::DELETE_ROWS_V1 | EventType::DELETE_ROWS_V2 => {
+                let (table, rows) = self.decode_rows(header.event_type, buf)?;
+
+                MySqlBinlogEventData::DeleteRows {
+                    table,
+                    rows: rows.into_iter().map(|(row, _)| row).collect(),
+                }
+            }
+
+            // heartbeats only keep an idle connection alive
+            EventType::HEARTBEAT => {
+                return Ok(None);
+            }
+
+            // other events are not of interest
+            _ => {
+                return Ok(None);
+            }
+        };
+
+        Ok(Some(MySqlBinlogEvent {
+            timestamp: header.timestamp,
+            server_id: header.server_id,
+            log_position: header.log_position,
+            data,
+        }))
+    }
+
+    // https://dev.mysql.com/doc/internals/en/format-description-event.html
+    fn decode_format_description(&mut self, mut buf: Bytes) -> Result<(), Error> {
+        let _binlog_version = take(&mut buf, 2)?;
+        let _server_version = take(&mut buf, 50)?;
+        let _create_timestamp = take(&mut buf, 4)?;
+        let _header_len = take(&mut buf, 1)?;
+
+        // the length of the fixed part of each event type, by type - 1
+        let table_map = usize::from(EventType::TABLE_MAP.0) - 1;
+
+        if buf.get(table_map) == Some(&6) {
+            // servers before 5.1 used a 4-byte table id
+            self.table_id_size = 4;
+        }
+
+        Ok(())
+    }
+
+    fn read_table_id(&self, buf: &mut Bytes) -> Result<u64, Error> {
+        let id = take(buf, self.table_id_size)?;
+
+        Ok(id.iter().rev().fold(0, |v, b| (v << 8) | u64::from(*b)))
+    }
+
+    // https://dev.mysql.com/doc/internals/en/table-map-event.html
+    fn decode_table_map(&self, mut buf: Bytes) -> Result<MySqlBinlogTable, Error> {
+        let id = self.read_table_id(&mut buf)?;
+        let _flags = take(&mut buf, 2)?;
+
+        let schema_len = take(&mut buf, 1)?.get_u8() as usize;
+        let schema = take(&mut buf, schema_len + 1)?.get_str(schema_len)?;
+
+        let name_len = take(&mut buf, 1)?.get_u8() as usize;
+        let name = take(&mut buf, name_len + 1)?.get_str(name_len)?;
+
+        let num_columns = uint_lenenc(&mut buf)? as usize;
+        let types = take(&mut buf, num_columns)?;
+
+        let metadata_len = uint_lenenc(&mut buf)? as usize;
+        let mut metadata = take(&mut buf, metadata_len)?;
+
+        let mut binlog_columns = types
+            .iter()
+            .map(|&r#type| {
+                Ok(BinlogColumn {
+                    r#type,
+                    metadata: BinlogColumn::read_metadata(r#type, &mut metadata)?,
+                    unsigned: false,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let _nullable = take(&mut buf, (num_columns + 7) / 8)?;
+
+        let mut names = Vec::new();
+
+        while buf.has_remaining() {
+            let field_type = take(&mut buf, 1)?.get_u8();
+            let len = uint_lenenc(&mut buf)? as usize;
+            let mut field = take(&mut buf, len)?;
+
+            match field_type {
+                METADATA_SIGNEDNESS => {
+                    // one bit per numeric column, starting at the most significant bit
+                    let numeric = binlog_columns
+                        .iter_mut()
+                        .filter(|column| is_numeric(column.r#type));
+
+                    for (i, column) in numeric.enumerate() {
+                        column.unsigned = field
+                            .get(i / 8)
+                            .map_or(false, |byte| byte & (0x80 >> (i % 8)) != 0);
+                    }
+                }
+
+                METADATA_COLUMN_NAME => {
+                    while field.has_remaining() {
+                        let len = uint_lenenc(&mut field)? as usize;
+                        names.push(take(&mut field, len)?.get_str(len)?);
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        let mut column_names = HashMap::with_capacity(num_columns);
+
+        let columns = binlog_columns
+            .iter()
+            .enumerate()
+            .map(|(ordinal, column)| {
+                // without the names from the optional metadata, we name columns as
+                // `mysqlbinlog` does: `@1`, `@2`, ..
+                let name = UStr::new(
+                    &names
+                        .get(ordinal)
+                        .cloned()
+                        .unwrap_or_else(|| format!("@{}", ordinal + 1)),
+                );
+
+                column_names.insert(name.clone(), ordinal);
+
+                MySqlColumn {
+                    ordinal,
+                    name,
+                    type_info: column.type_info(),
+                    flags: None,
+                }
+            })
+            .collect();
+
+        Ok(MySqlBinlogTable {
+            id,
+            schema,
+            name,
+            columns: Arc::new(columns),
+            column_names: Arc::new(column_names),
+            binlog_columns,
+        })
+    }
+
+    // https://dev.mysql.com/doc/internals/en/rows-event.html
+    #[allow(clippy::type_complexity)]
+    fn decode_rows(
+        &self,
+        event_type: EventType,
+        mut buf: Bytes,
+    ) -> Result<(Arc<MySqlBinlogTable>, Vec<(MySqlRow, Option<MySqlRow>)>), Error> {
+        let id = self.read_table_id(&mut buf)?;
+        let _flags = take(&mut buf, 2)?;
+
+        if matches!(
+            event_type,
+            EventType::WRITE_ROWS_V2 | EventType::UPDATE_ROWS_V2 | EventType::DELETE_ROWS_V2
+        ) {
+            // the length of the extra data includes itself
+            let extra_len = take(&mut buf, 2)?.get_u16_le() as usize;
+            let _extra = take(&mut buf, extra_len.saturating_sub(2))?;
+        }
+
+        let table = self
+            .tables
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| err_protocol!("rows event for unknown table id {}", id))?;
+
+        let num_columns = uint_lenenc(&mut buf)? as usize;
+        let present = take(&mut buf, (num_columns + 7) / 8)?;
+
+        let is_update = matches!(
+            event_type,
+            EventType::UPDATE_ROWS_V1 | EventType::UPDATE_ROWS_V2
+        );
+
+        let present_after = if is_update {
+            Some(take(&mut buf, (num_columns + 7) / 8)?)
+        } else {
+            None
+        };
+
+        let mut rows = Vec::new();
+
+        while buf.has_remaining() {
+            let row = decode_row(&table, &present, &mut buf)?;
+
+            let after = match present_after {
+                Some(ref present) => Some(decode_row(&table, present, &mut buf)?),
+                None => None,
+            };
+
+            rows.push((row, after));
+        }
+
+        Ok((table, rows))
+    }
+}
+
+fn is_numeric(r#type: u8) -> bool {
+    matches!(r#type, 0x01..=0x05 | 0x08 | 0x09 | 0xf6)
+}
+
+fn is_set(bitmap: &[u8], i: usize) -> bool {
+    bitmap
+        .get(i / 8)
+        .map_or(false, |byte| byte & (1 << (i % 8)) != 0)
+}
+
+// reads a row image; columns that are not present in the image are NULL
+fn decode_row(
+    table: &MySqlBinlogTable,
+    present: &[u8],
+    buf: &mut Bytes,
+) -> Result<MySqlRow, Error> {
+    let num_present = (0..table.binlog_columns.len())
+        .filter(|i| is_set(present, *i))
+        .count();
+
+    let nulls = take(buf, (num_present + 7) / 8)?;
+
+    let mut storage = Vec::new();
+    let mut values = Vec::with_capacity(table.binlog_columns.len());
+    let mut present_i = 0;
+
+    for (i, column) in table.binlog_columns.iter().enumerate() {
+        if !is_set(present, i) {
+            values.push(None);
+            continue;
+        }
+
+        let is_null = is_set(&nulls, present_i);
+        present_i += 1;
+
+        if is_null {
+            values.push(None);
+            continue;
+        }
+
+        let start = storage.len();
+        column.read_value(buf, &mut storage)?;
+
+        values.push(Some(start..storage.len()));
+    }
+
+    Ok(MySqlRow {
+        row: Row {
+            storage: Bytes::from(storage),
+            values,
+        },
+        format: MySqlValueFormat::Binary,
+        columns: Arc::clone(&table.columns),
+        column_names: Arc::clone(&table.column_names),
+        result_set: 0,
+        out_params: false,
+    })
+}
+
+#[cfg(test)]
+fn event(event_type: EventType, body: &[u8]) -> Bytes {
+    let mut buf = Vec::new();
+    buf.extend(&1_600_000_000_u32.to_le_bytes());
+    buf.push(event_type.0);
+    buf.extend(&1_u32.to_le_bytes());
+    buf.extend(&((EventHeader::SIZE + body.len()) as u32).to_le_bytes());
+    buf.extend(&4_u32.to_le_bytes());
+    buf.extend(&0_u16.to_le_bytes());
+    buf.extend_from_slice(body);
+
+    Bytes::from(buf)
+}
+
+// CREATE TABLE db.t (id INT NOT NULL, name VARCHAR(64))
+#[cfg(test)]
+const TABLE_MAP: &[u8] = &[
+    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, // table id
+    0x01, 0x00, // flags
+    0x02, b'd', b'b', 0x00, // schema
+    0x01, b't', 0x00, // table
+    0x02, // 2 columns
+    0x03, 0x0f, // INT, VARCHAR
+    0x02, 0x40, 0x00, // metadata
+    0x02, // nullable
+    0x04, 0x08, 0x02, b'i', b'd', 0x04, b'n', b'a', b'm', b'e', // names
+];
+
+// INSERT INTO db.t VALUES (7, 'abc'), (8, NULL)
+#[cfg(test)]
+const WRITE_ROWS: &[u8] = &[
+    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, // table id
+    0x01, 0x00, // flags
+    0x02, 0x00, // extra data
+    0x02, // 2 columns
+    0x03, // present
+    0x00, 0x07, 0x00, 0x00, 0x00, 0x03, b'a', b'b', b'c', // (7, 'abc')
+    0x02, 0x08, 0x00, 0x00, 0x00, // (8, NULL)
+];
+
+#[test]
+fn it_decodes_rows() -> Result<(), Error> {
+    use crate::row::Row;
+
+    let mut decoder = EventDecoder::new(false);
+
+    let table_map = decoder.decode(event(EventType::TABLE_MAP, TABLE_MAP))?;
+
+    assert!(matches!(
+        table_map.map(|event| event.data),
+        Some(MySqlBinlogEventData::TableMap(table)) if table.name == "t" && table.schema == "db"
+    ));
+
+    let write_rows = decoder.decode(event(EventType::WRITE_ROWS_V2, WRITE_ROWS))?;
+
+    let rows = match write_rows.map(|event| event.data) {
+        Some(MySqlBinlogEventData::WriteRows { rows, .. }) => rows,
+        data => panic!("unexpected event {:?}", data),
+    };
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].try_get::<i32, _>("id")?, 7);
+    assert_eq!(
+        rows[0].try_get::<Option<String>, _>("name")?.as_deref(),
+        Some("abc")
+    );
+    assert_eq!(rows[1].try_get::<i32, _>(0)?, 8);
+    assert_eq!(rows[1].try_get::<Option<String>, _>(1)?, None);
+
+    Ok(())
+}
+
+#[test]
+fn it_rejects_truncated_events() -> Result<(), Error> {
+    let mut decoder = EventDecoder::new(false);
+
+    for len in 0..TABLE_MAP.len() {
+        // the optional metadata is read until the end of the event
+        if len != 22 {
+            assert!(decoder
+                .decode(event(EventType::TABLE_MAP, &TABLE_MAP[..len]))
+                .is_err());
+        }
+    }
+
+    decoder.decode(event(EventType::TABLE_MAP, TABLE_MAP))?;
+
+    for len in 0..WRITE_ROWS.len() {
+        // rows are read until the end of the event
+        if len != 12 && len != 21 {
+            assert!(decoder
+                .decode(event(EventType::WRITE_ROWS_V2, &WRITE_ROWS[..len]))
+                .is_err());
+        }
+    }
+
+    // an UPDATE_ROWS without the after image of its row
+    let mut update_rows = WRITE_ROWS[..12].to_vec();
+    update_rows.push(0x03);
+    update_rows.extend_from_slice(&WRITE_ROWS[12..21]);
+
+    assert!(decoder
+        .decode(event(EventType::UPDATE_ROWS_V2, &update_rows))
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use crate::error::Error;
+
+// encodes a GTID set such as `3E11FA47-71CA-11E1-9E33-C80AA9429562:1-5:11,<uuid>:1`
+// as expected by `COM_BINLOG_DUMP_GTID`
+pub(crate) fn encode_gtid_set(set: &str) -> Result<Vec<u8>, Error> {
+    let sets: Vec<&str> = set
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let mut buf = Vec::new();
+    buf.extend(&(sets.len() as u64).to_le_bytes());
+
+    for set in sets {
+        let mut parts = set.split(':');
+
+        let uuid = parse_uuid(parts.next().unwrap_or_default())
+            .ok_or_else(|| Error::Configuration(format!("invalid GTID set {:?}", set).into()))?;
+
+        let intervals = parts
+            .map(|interval| {
+                let mut bounds = interval.trim().splitn(2, '-');
+                let start: u64 = bounds.next().unwrap_or_default().parse().ok()?;
+
+                let end = match bounds.next() {
+                    Some(end) => end.parse().ok()?,
+                    None => start,
+                };
+
+                // the end of an interval is exclusive in the protocol
+                Some((start, end + 1))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| Error::Configuration(format!("invalid GTID set {:?}", set).into()))?;
+
+        buf.extend_from_slice(&uuid);
+        buf.extend(&(intervals.len() as u64).to_le_bytes());
+
+        for (start, end) in intervals {
+            buf.extend(&start.to_le_bytes());
+            buf.extend(&end.to_le_bytes());
+        }
+    }
+
+    Ok(buf)
+}
+
+fn parse_uuid(s: &str) -> Option<[u8; 16]> {
+    let hex: Vec<u8> = s.bytes().filter(|b| *b != b'-').collect();
+
+    if hex.len() != 32 {
+        return None;
+    }
+
+    let mut uuid = [0; 16];
+
+    for (i, pair) in hex.chunks(2).enumerate() {
+        let pair = std::str::from_utf8(pair).ok()?;
+        uuid[i] = u8::from_str_radix(pair, 16).ok()?;
+    }
+
+    Some(uuid)
+}
+
+// formats the source id and transaction number of a GTID as `<uuid>:<number>`
+pub(crate) fn format_gtid(sid: &[u8], gno: u64) -> String {
+    let mut s = String::with_capacity(36 + 21);
+
+    for (i, b) in sid.iter().enumerate() {
+        if i == 4 || i == 6 || i == 8 || i == 10 {
+            s.push('-');
+        }
+
+        s.push_str(&format!("{:02x}", b));
+    }
+
+    s.push(':');
+    s.push_str(&gno.to_string());
+
+    s
+}
+
+#[test]
+fn it_encodes_gtid_set() {
+    let buf = encode_gtid_set("3E11FA47-71CA-11E1-9E33-C80AA9429562:1-5:7").unwrap();
+
+    assert_eq!(buf.len(), 8 + 16 + 8 + 2 * 16);
+    assert_eq!(&buf[..8], &1_u64.to_le_bytes());
+    assert_eq!(&buf[8..12], &[0x3e, 0x11, 0xfa, 0x47]);
+    assert_eq!(&buf[24..32], &2_u64.to_le_bytes());
+    assert_eq!(&buf[32..40], &1_u64.to_le_bytes());
+    assert_eq!(&buf[40..48], &6_u64.to_le_bytes());
+    assert_eq!(&buf[48..56], &7_u64.to_le_bytes());
+    assert_eq!(&buf[56..64], &8_u64.to_le_bytes());
+
+    assert!(encode_gtid_set("3E11FA47:1-5").is_err());
+}
+
+#[test]
+fn it_formats_gtid() {
+    let sid = [
+        0x3e, 0x11, 0xfa, 0x47, 0x71, 0xca, 0x11, 0xe1, 0x9e, 0x33, 0xc8, 0x0a, 0xa9, 0x42, 0x95,
+        0x62,
+    ];
+
+    assert_eq!(
+        format_gtid(&sid, 23),
+        "3e11fa47-71ca-11e1-9e33-c80aa9429562:23"
+    );
+}
@@ -0,0 +1,295 @@
+use bytes::Bytes;
+use std::fmt::Write;
+
+use crate::error::Error;
+use crate::mysql::replication::value::{put_packed_datetime, put_packed_time, read_decimal};
+
+// https://dev.mysql.com/doc/dev/mysql-server/8.0.12/json__binary_8h.html
+
+const SMALL_OBJECT: u8 = 0x00;
+const LARGE_OBJECT: u8 = 0x01;
+const SMALL_ARRAY: u8 = 0x02;
+const LARGE_ARRAY: u8 = 0x03;
+const LITERAL: u8 = 0x04;
+const INT16: u8 = 0x05;
+const UINT16: u8 = 0x06;
+const INT32: u8 = 0x07;
+const UINT32: u8 = 0x08;
+const INT64: u8 = 0x09;
+const UINT64: u8 = 0x0a;
+const DOUBLE: u8 = 0x0b;
+const STRING: u8 = 0x0c;
+const OPAQUE: u8 = 0x0f;
+
+// writes the text of a JSON value in the binary format of MySQL
+pub(crate) fn to_text(value: &Bytes, out: &mut Vec<u8>) -> Result<(), Error> {
+    // an empty value is the JSON null of a column that was NULL when converted
+    if value.is_empty() {
+        out.extend_from_slice(b"null");
+        return Ok(());
+    }
+
+    let mut text = String::new();
+    write_value(value[0], &value[1..], &mut text)?;
+
+    out.extend_from_slice(text.as_bytes());
+
+    Ok(())
+}
+
+fn get(buf: &[u8], offset: usize, len: usize) -> Result<&[u8], Error> {
+    buf.get(offset..offset + len)
+        .ok_or_else(|| err_protocol!("unexpected end of JSON value in binlog"))
+}
+
+fn uint(buf: &[u8], offset: usize, len: usize) -> Result<u64, Error> {
+    Ok(get(buf, offset, len)?
+        .iter()
+        .rev()
+        .fold(0, |v, b| (v << 8) | u64::from(*b)))
+}
+
+// a length of 7 bits per byte, while the high bit is set
+fn var_len(buf: &[u8], offset: usize) -> Result<(usize, usize), Error> {
+    let mut len = 0;
+
+    for i in 0..5 {
+        let b = *get(buf, offset + i, 1)?.first().unwrap_or(&0);
+        len |= ((b & 0x7f) as usize) << (7 * i);
+
+        if b & 0x80 == 0 {
+            return Ok((len, i + 1));
+        }
+    }
+
+    Err(err_protocol!("invalid length of JSON value in binlog"))
+}
+
+fn write_value(r#type: u8, buf: &[u8], out: &mut String) -> Result<(), Error> {
+    match r#type {
+        SMALL_OBJECT | LARGE_OBJECT | SMALL_ARRAY | LARGE_ARRAY => {
+            write_container(r#type, buf, out)?;
+        }
+
+        LITERAL => out.push_str(match uint(buf, 0, 1)? {
+            0 => "null",
+            1 => "true",
+            _ => "false",
+        }),
+
+        INT16 => write!(out, "{}", uint(buf, 0, 2)? as u16 as i16).unwrap(),
+        UINT16 => write!(out, "{}", uint(buf, 0, 2)?).unwrap(),
+        INT32 => write!(out, "{}", uint(buf, 0, 4)? as u32 as i32).unwrap(),
+        UINT32 => write!(out, "{}", uint(buf, 0, 4)?).unwrap(),
+        INT64 => write!(out, "{}", uint(buf, 0, 8)? as i64).unwrap(),
+        UINT64 => write!(out, "{}", uint(buf, 0, 8)?).unwrap(),
+        DOUBLE => write!(out, "{:?}", f64::from_bits(uint(buf, 0, 8)?)).unwrap(),
+
+        STRING => {
+            let (len, size) = var_len(buf, 0)?;
+            let s = std::str::from_utf8(get(buf, size, len)?).map_err(Error::protocol)?;
+
+            write_string(s, out);
+        }
+
+        OPAQUE => {
+            let field_type = uint(buf, 0, 1)? as u8;
+            let (len, size) = var_len(buf, 1)?;
+            let data = get(buf, 1 + size, len)?;
+
+            write_opaque(field_type, data, out)?;
+        }
+
+        ty => {
+            return Err(err_protocol!(
+                "unknown JSON value type 0x{:02x} in binlog",
+                ty
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// an object or array is laid out as
+//   <count> <size> <key entries> <value entries> <keys> <values>
+// where every offset is relative to the start of the container
+fn write_container(r#type: u8, buf: &[u8], out: &mut String) -> Result<(), Error> {
+    let large = r#type == LARGE_OBJECT || r#type == LARGE_ARRAY;
+    let object = r#type == SMALL_OBJECT || r#type == LARGE_OBJECT;
+
+    let offset_size = if large { 4 } else { 2 };
+    let count = uint(buf, 0, offset_size)? as usize;
+
+    let key_entries = 2 * offset_size;
+    let value_entries = key_entries + if object { count * (offset_size + 2) } else { 0 };
+
+    out.push(if object { '{' } else { '[' });
+
+    for i in 0..count {
+        if i > 0 {
+            out.push_str(", ");
+        }
+
+        if object {
+            let entry = key_entries + i * (offset_size + 2);
+            let key_offset = uint(buf, entry, offset_size)? as usize;
+            let key_len = uint(buf, entry + offset_size, 2)? as usize;
+
+            let key =
+                std::str::from_utf8(get(buf, key_offset, key_len)?).map_err(Error::protocol)?;
+
+            write_string(key, out);
+            out.push_str(": ");
+        }
+
+        let entry = value_entries + i * (1 + offset_size);
+        let value_type = uint(buf, entry, 1)? as u8;
+
+        // small values are stored in the entry instead of an offset
+        let inlined = match value_type {
+            LITERAL | INT16 | UINT16 => true,
+            INT32 | UINT32 => large,
+            _ => false,
+        };
+
+        if inlined {
+            write_value(value_type, get(buf, entry + 1, offset_size)?, out)?;
+        } else {
+            let value_offset = uint(buf, entry + 1, offset_size)? as usize;
+
+            let value = buf
+                .get(value_offset..)
+                .ok_or_else(|| err_protocol!("unexpected end of JSON value in binlog"))?;
+
+            write_value(value_type, value, out)?;
+        }
+    }
+
+    out.push(if object { '}' } else { ']' });
+
+    Ok(())
+}
+
+// values of other MySQL types inside JSON, e.g. from `CAST(.. AS JSON)`
+fn write_opaque(field_type: u8, data: &[u8], out: &mut String) -> Result<(), Error> {
+    match field_type {
+        // DECIMAL as <precision> <scale> <binary decimal>
+        0xf6 => {
+            let precision = uint(data, 0, 1)? as usize;
+            let scale = uint(data, 1, 1)? as usize;
+
+            let mut buf = Bytes::copy_from_slice(get(data, 2, data.len().saturating_sub(2))?);
+            out.push_str(&read_decimal(&mut buf, precision, scale)?);
+        }
+
+        // DATE, DATETIME, TIMESTAMP, TIME as a packed 64-bit integer
+        0x0a | 0x0c | 0x07 | 0x0b => {
+            let packed = uint(data, 0, 8)? as i64;
+            let mut binary = Vec::new();
+
+            if field_type == 0x0b {
+                put_packed_time(&mut binary, packed);
+            } else {
+                put_packed_datetime(&mut binary, packed);
+            }
+
+            out.push('"');
+            write_binary_time(field_type, &binary, out);
+            out.push('"');
+        }
+
+        ty => {
+            return Err(err_protocol!(
+                "unsupported opaque JSON value of type 0x{:02x} in binlog",
+                ty
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// formats a value of the binary protocol as the text MySQL would use
+fn write_binary_time(field_type: u8, binary: &[u8], out: &mut String) {
+    let mut value = [0_u8; 13];
+    let len = binary.len().min(value.len());
+    value[..len].copy_from_slice(&binary[..len]);
+
+    if field_type == 0x0b {
+        // <len> <negative> <days> <hours> <minutes> <seconds> <micros>
+        let days = u32::from_le_bytes([value[2], value[3], value[4], value[5]]);
+        let hours = days * 24 + u32::from(value[6]);
+
+        if value[1] != 0 {
+            out.push('-');
+        }
+
+        write!(out, "{:02}:{:02}:{:02}", hours, value[7], value[8]).unwrap();
+
+        if value[0] > 8 {
+            let micros = u32::from_le_bytes([value[9], value[10], value[11], value[12]]);
+            write!(out, ".{:06}", micros).unwrap();
+        }
+    } else {
+        // <len> <year> <month> <day> <hours> <minutes> <seconds> <micros>
+        let year = u16::from_le_bytes([value[1], value[2]]);
+
+        write!(out, "{:04}-{:02}-{:02}", year, value[3], value[4]).unwrap();
+
+        if field_type != 0x0a {
+            write!(out, " {:02}:{:02}:{:02}", value[5], value[6], value[7]).unwrap();
+
+            if value[0] > 7 {
+                let micros = u32::from_le_bytes([value[8], value[9], value[10], value[11]]);
+                write!(out, ".{:06}", micros).unwrap();
+            }
+        }
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+}
+
+#[test]
+fn it_converts_json_to_text() {
+    // {"a": [1, "b\"", true, null]}, as written by MySQL 8.0
+    let value = Bytes::from_static(&[
+        0x00, // small object
+        0x01, 0x00, // 1 key
+        0x1f, 0x00, // 31 bytes
+        0x0b, 0x00, 0x01, 0x00, // key at 11 of 1 byte
+        0x02, 0x0c, 0x00, // small array at 12
+        b'a', // key
+        0x04, 0x00, // 4 elements
+        0x13, 0x00, // 19 bytes
+        0x05, 0x01, 0x00, // int16 1
+        0x0c, 0x10, 0x00, // string at 16
+        0x04, 0x01, 0x00, // true
+        0x04, 0x00, 0x00, // null
+        0x02, b'b', b'"',
+    ]);
+
+    let mut out = Vec::new();
+    to_text(&value, &mut out).unwrap();
+
+    assert_eq!(
+        std::str::from_utf8(&out).unwrap(),
+        r#"{"a": [1, "b\"", true, null]}"#
+    );
+}
@@ -0,0 +1,353 @@
+//! Reading the binary log of a MySQL server as a replica.
+
+use futures_core::stream::BoxStream;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use std::fmt::{self, Debug, Formatter};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::ext::ustr::UStr;
+use crate::mysql::protocol::replication::{
+    BinlogDump, BinlogDumpFlags, BinlogDumpGtid, RegisterSlave,
+};
+use crate::mysql::{MySqlColumn, MySqlConnection, MySqlRow};
+use crate::query_scalar::query_scalar;
+use crate::HashMap;
+
+mod event;
+mod gtid;
+mod json;
+mod value;
+
+use event::EventDecoder;
+use value::BinlogColumn;
+
+/// Options for reading the binary log with [`MySqlConnection::binlog`].
+///
+/// By default the binary log is read from the start of the oldest binary log file still
+/// available on the server.
+#[derive(Debug, Clone)]
+pub struct MySqlBinlogOptions {
+    server_id: u32,
+    file: String,
+    position: u64,
+    gtid_set: Option<String>,
+    non_blocking: bool,
+    heartbeat_period: Option<Duration>,
+}
+
+impl MySqlBinlogOptions {
+    /// Creates options for a replica identified by `server_id`.
+    ///
+    /// The id must be unique among the server and all of its replicas.
+    pub fn new(server_id: u32) -> Self {
+        Self {
+            server_id,
+            file: String::new(),
+            position: 4,
+            gtid_set: None,
+            non_blocking: false,
+            heartbeat_period: None,
+        }
+    }
+
+    /// Starts reading at `position` in the binary log file `file`.
+    pub fn file(mut self, file: &str, position: u64) -> Self {
+        self.file = file.to_owned();
+        self.position = position;
+        self
+    }
+
+    /// Starts reading after the transactions of a GTID set such as
+    /// `3E11FA47-71CA-11E1-9E33-C80AA9429562:1-5`.
+    ///
+    /// Requires `gtid_mode = ON` on the server. MariaDB GTIDs are not supported.
+    pub fn gtid_set(mut self, gtid_set: &str) -> Self {
+        self.gtid_set = Some(gtid_set.to_owned());
+        self
+    }
+
+    /// Ends the stream at the end of the binary log instead of waiting for new events.
+    pub fn non_blocking(mut self, non_blocking: bool) -> Self {
+        self.non_blocking = non_blocking;
+        self
+    }
+
+    /// Asks the server to send a heartbeat when no event was sent for `period`.
+    ///
+    /// Heartbeats are not yielded by the stream; they only keep an idle connection alive.
+    pub fn heartbeat_period(mut self, period: Duration) -> Self {
+        self.heartbeat_period = Some(period);
+        self
+    }
+}
+
+/// An event of the binary log.
+#[derive(Debug)]
+pub struct MySqlBinlogEvent {
+    timestamp: u32,
+    server_id: u32,
+    log_position: u32,
+    data: MySqlBinlogEventData,
+}
+
+impl MySqlBinlogEvent {
+    /// The time the event was created, in seconds since the Unix epoch.
+    pub fn timestamp(&self) -> u32 {
+        self.timestamp
+    }
+
+    /// The id of the server that created the event.
+    pub fn server_id(&self) -> u32 {
+        self.server_id
+    }
+
+    /// The position of the next event in the binary log file.
+    pub fn log_position(&self) -> u32 {
+        self.log_position
+    }
+
+    pub fn data(&self) -> &MySqlBinlogEventData {
+        &self.data
+    }
+
+    pub fn into_data(self) -> MySqlBinlogEventData {
+        self.data
+    }
+}
+
+/// The contents of an event of the binary log.
+///
+/// Rows are decoded with the types of the table they belong to and are read like the rows
+/// of a query. `ENUM` and `SET` values are their index and bitmask and `TIMESTAMP` values
+/// are in UTC. Columns that are not part of a row image, such as with
+/// `binlog_row_image = MINIMAL`, are `NULL`.
+///
+/// Column names are only known with `binlog_row_metadata = FULL` (MySQL 8.0.1 or later);
+/// otherwise the columns are named `@1`, `@2`, and so on.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MySqlBinlogEventData {
+    /// The server continues with another binary log file.
+    Rotate { file: String, position: u64 },
+
+    /// The following events are the transaction with this GTID.
+    Gtid { gtid: String },
+
+    /// A statement, such as `BEGIN` or a statement of statement-based replication.
+    Query { schema: String, query: String },
+
+    /// The table that following rows events refer to.
+    TableMap(Arc<MySqlBinlogTable>),
+
+    /// Rows inserted into a table.
+    WriteRows {
+        table: Arc<MySqlBinlogTable>,
+        rows: Vec<MySqlRow>,
+    },
+
+    /// Rows of a table updated from the first to the second row.
+    UpdateRows {
+        table: Arc<MySqlBinlogTable>,
+        rows: Vec<(MySqlRow, MySqlRow)>,
+    },
+
+    /// Rows deleted from a table.
+    DeleteRows {
+        table: Arc<MySqlBinlogTable>,
+        rows: Vec<MySqlRow>,
+    },
+
+    /// The commit of a transaction.
+    Xid { xid: u64 },
+}
+
+/// A table referenced by rows events.
+#[derive(Debug)]
+pub struct MySqlBinlogTable {
+    id: u64,
+    schema: String,
+    name: String,
+    columns: Arc<Vec<MySqlColumn>>,
+    column_names: Arc<HashMap<UStr, usize>>,
+    binlog_columns: Vec<BinlogColumn>,
+}
+
+impl MySqlBinlogTable {
+    /// The id of the table, which is only valid within the binary log.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn columns(&self) -> &[MySqlColumn] {
+        &self.columns
+    }
+}
+
+/// A stream of the events of the binary log, returned by [`MySqlConnection::binlog`].
+pub struct MySqlBinlogStream {
+    stream: BoxStream<'static, Result<MySqlBinlogEvent, Error>>,
+}
+
+impl Stream for MySqlBinlogStream {
+    type Item = Result<MySqlBinlogEvent, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx)
+    }
+}
+
+impl Debug for MySqlBinlogStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MySqlBinlogStream").finish()
+    }
+}
+
+impl MySqlConnection {
+    /// Registers this connection as a replica and streams the events of the binary log.
+    ///
+    /// The server must use `binlog_format = ROW` for rows events, and the user needs the
+    /// `REPLICATION SLAVE` privilege. The connection can not be used for anything else
+    /// afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_core::error::Error;
+    /// # use sqlx_core::connection::Connection;
+    /// # use sqlx_core::mysql::{MySqlBinlogEventData, MySqlBinlogOptions, MySqlConnection};
+    /// # use futures_util::TryStreamExt;
+    /// #
+    /// # fn main() {
+    /// # #[cfg(feature = "_rt-async-std")]
+    /// # sqlx_rt::async_std::task::block_on::<_, Result<(), Error>>(async move {
+    /// let conn = MySqlConnection::connect("mysql://repl@localhost").await?;
+    /// let mut events = conn.binlog(MySqlBinlogOptions::new(1000)).await?;
+    ///
+    /// while let Some(event) = events.try_next().await? {
+    ///     if let MySqlBinlogEventData::WriteRows { table, rows } = event.data() {
+    ///         println!("{} rows inserted into {}", rows.len(), table.name());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }).unwrap();
+    /// # }
+    /// ```
+    pub async fn binlog(mut self, options: MySqlBinlogOptions) -> Result<MySqlBinlogStream, Error> {
+        let gtid_set = options
+            .gtid_set
+            .as_deref()
+            .map(gtid::encode_gtid_set)
+            .transpose()?;
+
+        // announce that we understand checksums, which the server would otherwise refuse
+        self.execute("SET @master_binlog_checksum = @@global.binlog_checksum")
+            .await?;
+
+        let checksum: Option<Vec<u8>> = query_scalar("SELECT @@global.binlog_checksum")
+            .fetch_one(&mut self)
+            .await?;
+
+        let checksum = checksum.map_or(false, |checksum| !checksum.eq_ignore_ascii_case(b"NONE"));
+
+        if let Some(period) = options.heartbeat_period {
+            self.execute(&*format!(
+                "SET @master_heartbeat_period = {}",
+                period.as_nanos()
+            ))
+            .await?;
+        }
+
+        self.stream.wait_until_ready().await?;
+
+        self.stream
+            .send_packet(RegisterSlave {
+                server_id: options.server_id,
+                hostname: "",
+                user: "",
+                password: "",
+                port: 0,
+            })
+            .await?;
+
+        self.stream.recv_ok().await?;
+
+        let mut flags = BinlogDumpFlags::empty();
+
+        if options.non_blocking {
+            flags |= BinlogDumpFlags::NON_BLOCK;
+        }
+
+        match gtid_set {
+            Some(ref gtid_set) => {
+                self.stream
+                    .send_packet(BinlogDumpGtid {
+                        flags,
+                        server_id: options.server_id,
+                        filename: &options.file,
+                        position: options.position,
+                        gtid_set,
+                    })
+                    .await?;
+            }
+
+            None => {
+                self.stream
+                    .send_packet(BinlogDump {
+                        position: options.position as u32,
+                        flags,
+                        server_id: options.server_id,
+                        filename: &options.file,
+                    })
+                    .await?;
+            }
+        }
+
+        let mut decoder = EventDecoder::new(checksum);
+
+        let stream = try_stream! {
+            loop {
+                let packet = self.stream.recv_packet().await?;
+
+                match packet[0] {
+                    0x00 => {
+                        if let Some(event) = decoder.decode(packet.0.slice(1..))? {
+                            r#yield!(event);
+                        }
+                    }
+
+                    // EOF at the end of the binary log when not blocking
+                    0xfe if packet.len() < 9 => {
+                        break;
+                    }
+
+                    header => {
+                        return Err(err_protocol!(
+                            "unexpected packet 0x{:02x} in binlog stream",
+                            header
+                        ));
+                    }
+                }
+            }
+
+            Ok(())
+        };
+
+        Ok(MySqlBinlogStream {
+            stream: stream.boxed(),
+        })
+    }
+}
@@ -0,0 +1,572 @@
+use bytes::Bytes;
+
+use crate::error::Error;
+use crate::mysql::protocol::text::{ColumnFlags, ColumnType};
+use crate::mysql::replication::json;
+use crate::mysql::MySqlTypeInfo;
+
+// https://dev.mysql.com/doc/internals/en/binary-protocol-value.html
+// https://dev.mysql.com/doc/dev/mysql-server/8.0.12/classbinary__log_1_1Table__map__event.html
+
+// column types that only appear in the binary log
+const TYPE_TIMESTAMP2: u8 = 0x11;
+const TYPE_DATETIME2: u8 = 0x12;
+const TYPE_TIME2: u8 = 0x13;
+
+const COLLATE_UTF8MB4_UNICODE_CI: u16 = 224;
+const COLLATE_BINARY: u16 = 63;
+
+// the number of bytes used to store a number of decimal digits (up to 9)
+const DIG_TO_BYTES: [usize; 10] = [0, 1, 1, 2, 2, 3, 3, 4, 4, 4];
+
+/// A column of a table, as described by a `TABLE_MAP` event.
+#[derive(Debug, Clone)]
+pub(crate) struct BinlogColumn {
+    pub(crate) r#type: u8,
+    pub(crate) metadata: u16,
+    pub(crate) unsigned: bool,
+}
+
+// takes <len> bytes from <buf>, failing instead of panicking on a short buffer
+pub(crate) fn take(buf: &mut Bytes, len: usize) -> Result<Bytes, Error> {
+    if buf.len() < len {
+        return Err(err_protocol!(
+            "expected {} more bytes in binlog event but found {}",
+            len,
+            buf.len()
+        ));
+    }
+
+    Ok(buf.split_to(len))
+}
+
+// reads a length-encoded integer
+pub(crate) fn uint_lenenc(buf: &mut Bytes) -> Result<u64, Error> {
+    Ok(match uint_le(buf, 1)? {
+        0xfc => uint_le(buf, 2)?,
+        0xfd => uint_le(buf, 3)?,
+        0xfe => uint_le(buf, 8)?,
+
+        v => v,
+    })
+}
+
+fn uint_le(buf: &mut Bytes, len: usize) -> Result<u64, Error> {
+    Ok(take(buf, len)?
+        .iter()
+        .rev()
+        .fold(0, |v, b| (v << 8) | u64::from(*b)))
+}
+
+fn uint_be(buf: &mut Bytes, len: usize) -> Result<u64, Error> {
+    Ok(take(buf, len)?
+        .iter()
+        .fold(0, |v, b| (v << 8) | u64::from(*b)))
+}
+
+impl BinlogColumn {
+    // reads the metadata of a column of <type> from the metadata block of a `TABLE_MAP`
+    pub(crate) fn read_metadata(r#type: u8, buf: &mut Bytes) -> Result<u16, Error> {
+        Ok(match r#type {
+            0x04 | 0x05 | 0xfc | 0xff | 0xf5 | TYPE_TIMESTAMP2 | TYPE_DATETIME2 | TYPE_TIME2 => {
+                uint_le(buf, 1)? as u16
+            }
+
+            0x0f | 0xfd | 0x10 => uint_le(buf, 2)? as u16,
+
+            // [real type, length] or [precision, scale]
+            0xfe | 0xf7 | 0xf8 | 0xf6 => uint_be(buf, 2)? as u16,
+
+            _ => 0,
+        })
+    }
+
+    // resolves the type of a STRING, which may really be an ENUM or SET, and its length
+    fn string_type(&self) -> (u8, usize) {
+        let byte0 = (self.metadata >> 8) as u8;
+        let byte1 = (self.metadata & 0xff) as usize;
+
+        if byte0 != 0 && (byte0 & 0x30) != 0x30 {
+            // the length of a CHAR(N) may need more than 8 bits
+            (
+                byte0 | 0x30,
+                byte1 | ((((byte0 & 0x30) ^ 0x30) as usize) << 4),
+            )
+        } else if byte0 != 0 {
+            (byte0, byte1)
+        } else {
+            (self.r#type, byte1)
+        }
+    }
+
+    /// The type of the values we produce for this column.
+    pub(crate) fn type_info(&self) -> MySqlTypeInfo {
+        let mut flags = ColumnFlags::empty();
+
+        if self.unsigned {
+            flags |= ColumnFlags::UNSIGNED;
+        }
+
+        let (r#type, char_set) = match self.r#type {
+            TYPE_TIMESTAMP2 => (ColumnType::Timestamp, COLLATE_BINARY),
+            TYPE_DATETIME2 => (ColumnType::Datetime, COLLATE_BINARY),
+            TYPE_TIME2 => (ColumnType::Time, COLLATE_BINARY),
+
+            0xfe => match self.string_type().0 {
+                // ENUM and SET are stored as their index and bitmask
+                0xf7 | 0xf8 => {
+                    flags |= ColumnFlags::UNSIGNED;
+                    (ColumnType::LongLong, COLLATE_BINARY)
+                }
+
+                _ => (ColumnType::String, COLLATE_UTF8MB4_UNICODE_CI),
+            },
+
+            0xf7 | 0xf8 => {
+                flags |= ColumnFlags::UNSIGNED;
+                (ColumnType::LongLong, COLLATE_BINARY)
+            }
+
+            // the binary log does not tell BLOB and TEXT apart
+            0x0f | 0xfc | 0xfd | 0xf5 => (
+                ColumnType::try_from_u16(self.r#type).unwrap_or(ColumnType::Blob),
+                COLLATE_UTF8MB4_UNICODE_CI,
+            ),
+
+            ty => (
+                ColumnType::try_from_u16(ty).unwrap_or(ColumnType::Null),
+                COLLATE_BINARY,
+            ),
+        };
+
+        MySqlTypeInfo {
+            r#type,
+            flags,
+            char_set,
+            max_size: None,
+        }
+    }
+
+    // reads a value of this column from a row event into <out>,
+    // re-encoded as the binary protocol would send it
+    pub(crate) fn read_value(&self, buf: &mut Bytes, out: &mut Vec<u8>) -> Result<(), Error> {
+        match self.r#type {
+            // TINYINT, SMALLINT, INT, BIGINT, MEDIUMINT, FLOAT, DOUBLE
+            // integers keep their width; the decoders extend them as needed
+            0x01 => out.extend_from_slice(&take(buf, 1)?),
+            0x02 => out.extend_from_slice(&take(buf, 2)?),
+            0x03 | 0x04 => out.extend_from_slice(&take(buf, 4)?),
+            0x08 | 0x05 => out.extend_from_slice(&take(buf, 8)?),
+            0x09 => out.extend_from_slice(&take(buf, 3)?),
+
+            // YEAR
+            0x0d => {
+                let year = uint_le(buf, 1)? as u16;
+                let year = if year == 0 { 0 } else { year + 1900 };
+
+                out.extend_from_slice(&year.to_le_bytes());
+            }
+
+            // DECIMAL
+            0xf6 => {
+                let precision = (self.metadata >> 8) as usize;
+                let scale = (self.metadata & 0xff) as usize;
+
+                out.extend_from_slice(read_decimal(buf, precision, scale)?.as_bytes());
+            }
+
+            // VARCHAR
+            0x0f | 0xfd => {
+                let len = uint_le(buf, if self.metadata < 256 { 1 } else { 2 })? as usize;
+                out.extend_from_slice(&take(buf, len)?);
+            }
+
+            // CHAR, ENUM, SET
+            0xfe | 0xf7 | 0xf8 => {
+                let (r#type, len) = self.string_type();
+
+                match r#type {
+                    0xf7 | 0xf8 => {
+                        let value = uint_le(buf, len)?;
+                        out.extend_from_slice(&value.to_le_bytes());
+                    }
+
+                    _ => {
+                        let len = uint_le(buf, if len < 256 { 1 } else { 2 })? as usize;
+                        out.extend_from_slice(&take(buf, len)?);
+                    }
+                }
+            }
+
+            // BLOB, TEXT, GEOMETRY
+            0xfc | 0xff => {
+                let len = uint_le(buf, self.metadata as usize)? as usize;
+                out.extend_from_slice(&take(buf, len)?);
+            }
+
+            // JSON is stored in a binary format; we produce text
+            0xf5 => {
+                let len = uint_le(buf, self.metadata as usize)? as usize;
+                let value = take(buf, len)?;
+
+                json::to_text(&value, out)?;
+            }
+
+            // BIT
+            0x10 => {
+                let bits = (self.metadata & 0xff) as usize;
+                let bytes = (self.metadata >> 8) as usize;
+
+                out.extend_from_slice(&take(buf, bytes + (bits > 0) as usize)?);
+            }
+
+            // DATE
+            0x0a => {
+                let value = uint_le(buf, 3)?;
+
+                put_date(
+                    out,
+                    (value >> 9) as u16,
+                    ((value >> 5) & 15) as u8,
+                    (value & 31) as u8,
+                );
+            }
+
+            // DATETIME, as the digits of YYYYMMDDhhmmss
+            0x0c => {
+                let value = uint_le(buf, 8)?;
+                let date = value / 1_000_000;
+                let time = value % 1_000_000;
+
+                put_datetime(
+                    out,
+                    (
+                        (date / 10000) as u16,
+                        (date / 100 % 100) as u8,
+                        (date % 100) as u8,
+                    ),
+                    (
+                        (time / 10000) as u8,
+                        (time / 100 % 100) as u8,
+                        (time % 100) as u8,
+                    ),
+                    0,
+                );
+            }
+
+            // DATETIME(fsp)
+            TYPE_DATETIME2 => {
+                let packed = uint_be(buf, 5)? as i64 - 0x80_0000_0000;
+                let micros = read_fraction(buf, self.metadata)?;
+
+                put_packed_datetime(out, packed << 24 | i64::from(micros));
+            }
+
+            // TIMESTAMP, as seconds since the Unix epoch
+            0x07 => {
+                let seconds = uint_le(buf, 4)?;
+                put_timestamp(out, seconds, 0);
+            }
+
+            // TIMESTAMP(fsp)
+            TYPE_TIMESTAMP2 => {
+                let seconds = uint_be(buf, 4)?;
+                let micros = read_fraction(buf, self.metadata)?;
+
+                put_timestamp(out, seconds, micros);
+            }
+
+            // TIME, as the digits of hhmmss
+            0x0b => {
+                let value = uint_le(buf, 3)? as i64;
+
+                // sign-extend the 24-bit value
+                let value = (value << 40) >> 40;
+                let abs = value.abs();
+
+                put_time(
+                    out,
+                    value < 0,
+                    (abs / 10000) as u32,
+                    (abs / 100 % 100) as u8,
+                    (abs % 100) as u8,
+                    0,
+                );
+            }
+
+            // TIME(fsp)
+            TYPE_TIME2 => {
+                let packed = read_time2(buf, self.metadata)?;
+                put_packed_time(out, packed);
+            }
+
+            ty => {
+                return Err(err_protocol!(
+                    "unsupported column type 0x{:02x} in binlog row",
+                    ty
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// reads the fractional seconds of a DATETIME2 or TIMESTAMP2 as microseconds
+fn read_fraction(buf: &mut Bytes, fsp: u16) -> Result<u32, Error> {
+    let len = (fsp as usize + 1) / 2;
+    let value = uint_be(buf, len)? as u32;
+
+    Ok(match len {
+        1 => value * 10_000,
+        2 => value * 100,
+        _ => value,
+    })
+}
+
+// reads a TIME2 as a packed value of (hhhhhhhhhh mmmmmm ssssss) << 24 | microseconds
+fn read_time2(buf: &mut Bytes, fsp: u16) -> Result<i64, Error> {
+    Ok(match fsp {
+        1 | 2 => {
+            let mut int = uint_be(buf, 3)? as i64 - 0x80_0000;
+            let mut frac = uint_be(buf, 1)? as i64;
+
+            if int < 0 && frac != 0 {
+                // a negative value stores its fraction as a separate negative number
+                int += 1;
+                frac -= 0x100;
+            }
+
+            (int << 24) + frac * 10_000
+        }
+
+        3 | 4 => {
+            let mut int = uint_be(buf, 3)? as i64 - 0x80_0000;
+            let mut frac = uint_be(buf, 2)? as i64;
+
+            if int < 0 && frac != 0 {
+                int += 1;
+                frac -= 0x10000;
+            }
+
+            (int << 24) + frac * 100
+        }
+
+        5 | 6 => uint_be(buf, 6)? as i64 - 0x8000_0000_0000,
+
+        _ => (uint_be(buf, 3)? as i64 - 0x80_0000) << 24,
+    })
+}
+
+// reads a DECIMAL in the binary format of MySQL as its text
+pub(crate) fn read_decimal(
+    buf: &mut Bytes,
+    precision: usize,
+    scale: usize,
+) -> Result<String, Error> {
+    let integral = precision.saturating_sub(scale);
+    let (integral_words, integral_rest) = (integral / 9, integral % 9);
+    let (fraction_words, fraction_rest) = (scale / 9, scale % 9);
+
+    let size = integral_words * 4
+        + DIG_TO_BYTES[integral_rest]
+        + fraction_words * 4
+        + DIG_TO_BYTES[fraction_rest];
+
+    let mut bytes = take(buf, size)?.to_vec();
+
+    if bytes.is_empty() {
+        return Ok("0".into());
+    }
+
+    // the sign is the inverted high bit; negative numbers have every bit inverted
+    let negative = bytes[0] & 0x80 == 0;
+    bytes[0] ^= 0x80;
+
+    if negative {
+        for b in &mut bytes {
+            *b = !*b;
+        }
+    }
+
+    let mut bytes = Bytes::from(bytes);
+    let mut integral_digits = String::new();
+
+    if integral_rest > 0 {
+        let v = uint_be(&mut bytes, DIG_TO_BYTES[integral_rest])?;
+        integral_digits.push_str(&v.to_string());
+    }
+
+    for _ in 0..integral_words {
+        let v = uint_be(&mut bytes, 4)?;
+        integral_digits.push_str(&format!("{:09}", v));
+    }
+
+    let mut text = String::new();
+
+    if negative {
+        text.push('-');
+    }
+
+    match integral_digits.trim_start_matches('0') {
+        "" => text.push('0'),
+        digits => text.push_str(digits),
+    }
+
+    if scale > 0 {
+        text.push('.');
+
+        for _ in 0..fraction_words {
+            let v = uint_be(&mut bytes, 4)?;
+            text.push_str(&format!("{:09}", v));
+        }
+
+        if fraction_rest > 0 {
+            let v = uint_be(&mut bytes, DIG_TO_BYTES[fraction_rest])?;
+            text.push_str(&format!("{:0width$}", v, width = fraction_rest));
+        }
+    }
+
+    Ok(text)
+}
+
+fn put_date(out: &mut Vec<u8>, year: u16, month: u8, day: u8) {
+    if year == 0 && month == 0 && day == 0 {
+        out.push(0);
+        return;
+    }
+
+    out.push(4);
+    out.extend_from_slice(&year.to_le_bytes());
+    out.push(month);
+    out.push(day);
+}
+
+fn put_datetime(
+    out: &mut Vec<u8>,
+    (year, month, day): (u16, u8, u8),
+    (hour, minute, second): (u8, u8, u8),
+    micros: u32,
+) {
+    out.push(if micros > 0 { 11 } else { 7 });
+    out.extend_from_slice(&year.to_le_bytes());
+    out.extend_from_slice(&[month, day, hour, minute, second]);
+
+    if micros > 0 {
+        out.extend_from_slice(&micros.to_le_bytes());
+    }
+}
+
+// a DATETIME packed as (year * 13 + month) << 22 | day << 17 | hh << 12 | mm << 6 | ss,
+// shifted left by 24 bits to make room for the microseconds
+pub(crate) fn put_packed_datetime(out: &mut Vec<u8>, packed: i64) {
+    let packed = packed.abs();
+    let micros = (packed % (1 << 24)) as u32;
+    let ymdhms = packed >> 24;
+
+    let ymd = ymdhms >> 17;
+    let ym = ymd >> 5;
+    let hms = ymdhms % (1 << 17);
+
+    put_datetime(
+        out,
+        ((ym / 13) as u16, (ym % 13) as u8, (ymd % (1 << 5)) as u8),
+        (
+            (hms >> 12) as u8,
+            ((hms >> 6) % (1 << 6)) as u8,
+            (hms % (1 << 6)) as u8,
+        ),
+        micros,
+    );
+}
+
+// a TIMESTAMP, which we produce in UTC
+fn put_timestamp(out: &mut Vec<u8>, seconds: u64, micros: u32) {
+    let days = (seconds / 86400) as i64;
+    let time = seconds % 86400;
+
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+
+    put_datetime(
+        out,
+        (year as u16, month as u8, day as u8),
+        (
+            (time / 3600) as u8,
+            (time / 60 % 60) as u8,
+            (time % 60) as u8,
+        ),
+        micros,
+    );
+}
+
+fn put_time(out: &mut Vec<u8>, negative: bool, hours: u32, minute: u8, second: u8, micros: u32) {
+    if hours == 0 && minute == 0 && second == 0 && micros == 0 {
+        out.push(0);
+        return;
+    }
+
+    out.push(if micros > 0 { 12 } else { 8 });
+    out.push(negative as u8);
+    out.extend_from_slice(&(hours / 24).to_le_bytes());
+    out.extend_from_slice(&[(hours % 24) as u8, minute, second]);
+
+    if micros > 0 {
+        out.extend_from_slice(&micros.to_le_bytes());
+    }
+}
+
+// a TIME packed as (hhhhhhhhhh mmmmmm ssssss) << 24 | microseconds
+pub(crate) fn put_packed_time(out: &mut Vec<u8>, packed: i64) {
+    let negative = packed < 0;
+    let packed = packed.abs();
+    let micros = (packed % (1 << 24)) as u32;
+    let hms = packed >> 24;
+
+    put_time(
+        out,
+        negative,
+        ((hms >> 12) % (1 << 10)) as u32,
+        ((hms >> 6) % (1 << 6)) as u8,
+        (hms % (1 << 6)) as u8,
+        micros,
+    );
+}
+
+#[test]
+fn it_reads_decimal() {
+    // DECIMAL(14, 4): 1234567890.1234 and -1234567890.1234
+    let mut buf = Bytes::from_static(&[0x81, 0x0d, 0xfb, 0x38, 0xd2, 0x04, 0xd2]);
+    assert_eq!(read_decimal(&mut buf, 14, 4).unwrap(), "1234567890.1234");
+
+    let mut buf = Bytes::from_static(&[0x7e, 0xf2, 0x04, 0xc7, 0x2d, 0xfb, 0x2d]);
+    assert_eq!(read_decimal(&mut buf, 14, 4).unwrap(), "-1234567890.1234");
+}
+
+#[test]
+fn it_reads_timestamp() {
+    let mut out = Vec::new();
+    put_timestamp(&mut out, 1_600_000_000, 0);
+
+    // 2020-09-13 12:26:40
+    assert_eq!(out, [7, 0xe4, 0x07, 9, 13, 12, 26, 40]);
+}
+
+#[test]
+fn it_reads_time2() {
+    // -00:00:01.5 with a precision of 1
+    let mut buf = Bytes::from_static(&[0x7f, 0xff, 0xfe, 0xce]);
+    let mut out = Vec::new();
+
+    put_packed_time(&mut out, read_time2(&mut buf, 1).unwrap());
+
+    assert_eq!(out, [12, 1, 0, 0, 0, 0, 0, 0, 1, 0x20, 0xa1, 0x07, 0x00]);
+}
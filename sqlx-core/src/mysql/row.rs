@@ -13,6 +13,29 @@ pub struct MySqlRow {
     pub(crate) format: MySqlValueFormat,
     pub(crate) columns: Arc<Vec<MySqlColumn>>,
     pub(crate) column_names: Arc<HashMap<UStr, usize>>,
+    pub(crate) result_set: usize,
+    pub(crate) out_params: bool,
+}
+
+impl MySqlRow {
+    /// The index of the result set this row is part of, counting from 0.
+    ///
+    /// Multiple statements and the `CALL` of a stored procedure return several result sets,
+    /// each of which ends with a [`MySqlDone`](super::MySqlDone) in
+    /// [`fetch_many`](crate::executor::Executor::fetch_many).
+    pub fn result_set(&self) -> usize {
+        self.result_set
+    }
+
+    /// Whether this row holds the values of the `OUT` and `INOUT` parameters of a stored
+    /// procedure, which are returned when the parameters are placeholders of a prepared
+    /// statement such as `CALL p(?, ?)`.
+    ///
+    /// This row is the only row of the last result set of the `CALL`, with a column for every
+    /// `OUT` and `INOUT` parameter.
+    pub fn is_out_params(&self) -> bool {
+        self.out_params
+    }
 }
 
 impl crate::row::private_row::Sealed for MySqlRow {}
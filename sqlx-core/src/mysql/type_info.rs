@@ -44,12 +44,29 @@ impl MySqlTypeInfo {
             }
 
             ColumnType::Json => Some("json"),
+            ColumnType::Geometry => Some("geo-types"),
             ColumnType::NewDecimal => Some("bigdecimal"),
 
             _ => None,
         }
     }
 
+    // YEAR is sent as a SMALLINT and needs at least 16 bits
+    pub(crate) fn is_year(&self) -> bool {
+        self.r#type == ColumnType::Year
+    }
+
+    // BIT(M) fits an unsigned integer of `bits` bits when M is not larger
+    pub(crate) fn is_bit_of(&self, bits: u32) -> bool {
+        self.r#type == ColumnType::Bit && self.max_size.map_or(true, |size| size <= bits)
+    }
+
+    // SET is sent as a string column with the SET flag
+    pub(crate) fn is_set(&self) -> bool {
+        self.r#type == ColumnType::Set
+            || (self.r#type == ColumnType::String && self.flags.contains(ColumnFlags::SET))
+    }
+
     pub(crate) fn from_column(column: &ColumnDefinition) -> Self {
         Self {
             r#type: column.r#type,
@@ -0,0 +1,53 @@
+use bit_vec::BitVec;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mysql::io::MySqlBufMutExt;
+use crate::mysql::protocol::text::ColumnType;
+use crate::mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
+use crate::types::Type;
+
+impl Type<MySql> for BitVec {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTypeInfo::binary(ColumnType::Bit)
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        ty.r#type == ColumnType::Bit
+    }
+}
+
+impl Encode<'_, MySql> for BitVec {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        // the bits are right-aligned in whole bytes, so pad the front with zeroes
+        let mut bits = BitVec::from_elem((8 - self.len() % 8) % 8, false);
+        bits.extend(self.iter());
+
+        buf.put_bytes_lenenc(&bits.to_bytes());
+
+        IsNull::No
+    }
+
+    fn produces(&self) -> Option<MySqlTypeInfo> {
+        // a BIT parameter is sent as a binary string
+        Some(MySqlTypeInfo::binary(ColumnType::VarString))
+    }
+}
+
+impl Decode<'_, MySql> for BitVec {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        // NOTE: Regardless of the value format, there is raw binary data here
+        let bytes = value.as_bytes()?;
+        let mut bits = BitVec::from_bytes(bytes);
+
+        // BIT(M) is right-aligned in whole bytes, so chop the padding off the front
+        let len = value.type_info.max_size.unwrap_or(bits.len() as u32) as usize;
+
+        if len < bits.len() {
+            bits = bits.iter().skip(bits.len() - len).collect();
+        }
+
+        Ok(bits)
+    }
+}
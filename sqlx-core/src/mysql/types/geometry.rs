@@ -0,0 +1,108 @@
+use geo_types::{Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+use std::convert::TryFrom;
+
+use crate::common::wkb;
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mysql::io::MySqlBufMutExt;
+use crate::mysql::protocol::text::ColumnType;
+use crate::mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
+use crate::types::Type;
+
+// https://dev.mysql.com/doc/refman/8.0/en/gis-data-formats.html#gis-internal-format
+
+// a spatial value is its SRID as a 4-byte integer, followed by the WKB of the geometry
+fn encode_geometry<T: wkb::Encode>(geometry: &T, buf: &mut Vec<u8>) -> IsNull {
+    let mut value = 0_u32.to_le_bytes().to_vec();
+    wkb::encode(geometry, None, &mut value);
+
+    buf.put_bytes_lenenc(&value);
+
+    IsNull::No
+}
+
+fn decode_geometry(value: MySqlValueRef<'_>) -> Result<Geometry<f64>, BoxDynError> {
+    // NOTE: Regardless of the value format, there is raw binary data here
+    let buf = value.as_bytes()?;
+
+    if buf.len() < 4 {
+        return Err("unexpected end of a spatial value".into());
+    }
+
+    let (geometry, _) = wkb::decode(&buf[4..])?;
+
+    Ok(geometry)
+}
+
+impl Type<MySql> for Geometry<f64> {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTypeInfo::binary(ColumnType::Geometry)
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        ty.r#type == ColumnType::Geometry
+    }
+}
+
+impl Encode<'_, MySql> for Geometry<f64> {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        encode_geometry(self, buf)
+    }
+
+    fn produces(&self) -> Option<MySqlTypeInfo> {
+        // a spatial parameter is sent as a binary string of its internal format
+        Some(MySqlTypeInfo::binary(ColumnType::Blob))
+    }
+}
+
+impl Decode<'_, MySql> for Geometry<f64> {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        decode_geometry(value)
+    }
+}
+
+macro_rules! impl_geometry {
+    ($($ty:ident),*) => {
+        $(
+            impl Type<MySql> for $ty<f64> {
+                fn type_info() -> MySqlTypeInfo {
+                    <Geometry<f64> as Type<MySql>>::type_info()
+                }
+
+                fn compatible(ty: &MySqlTypeInfo) -> bool {
+                    <Geometry<f64> as Type<MySql>>::compatible(ty)
+                }
+            }
+
+            impl Encode<'_, MySql> for $ty<f64> {
+                fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+                    encode_geometry(self, buf)
+                }
+
+                fn produces(&self) -> Option<MySqlTypeInfo> {
+                    Some(MySqlTypeInfo::binary(ColumnType::Blob))
+                }
+            }
+
+            impl Decode<'_, MySql> for $ty<f64> {
+                fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+                    let geometry = decode_geometry(value)?;
+
+                    $ty::try_from(geometry).map_err(|_| {
+                        concat!("spatial value is not a ", stringify!($ty)).into()
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_geometry!(
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon
+);
@@ -10,13 +10,16 @@
 //! | `i32`                                 | INT                                                  |
 //! | `i64`                                 | BIGINT                                               |
 //! | `u8`                                  | TINYINT UNSIGNED                                     |
-//! | `u16`                                 | SMALLINT UNSIGNED                                    |
+//! | `u16`                                 | SMALLINT UNSIGNED, YEAR                              |
 //! | `u32`                                 | INT UNSIGNED                                         |
-//! | `u64`                                 | BIGINT UNSIGNED                                      |
+//! | `u64`                                 | BIGINT UNSIGNED, BIT                                 |
 //! | `f32`                                 | FLOAT                                                |
 //! | `f64`                                 | DOUBLE                                               |
 //! | `&str`, `String`                      | VARCHAR, CHAR, TEXT                                  |
 //! | `&[u8]`, `Vec<u8>`                    | VARBINARY, BINARY, BLOB                              |
+//! | `Vec<String>`, `HashSet<String>`      | SET                                                  |
+//!
+//! An unsigned integer can hold a `BIT(M)` when it has at least M bits.
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
@@ -72,6 +75,30 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `json::JsonValue`             | JSON
 //!
+//! ### [`bit-vec`](https://crates.io/crates/bit-vec)
+//!
+//! Requires the `bit-vec` Cargo feature flag.
+//!
+//! | Rust type                             | MySQL type(s)                                        |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `bit_vec::BitVec`                     | BIT                                                  |
+//!
+//! ### [`geo-types`](https://crates.io/crates/geo-types)
+//!
+//! Requires the `geo-types` Cargo feature flag.
+//!
+//! | Rust type                             | MySQL type(s)                                        |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `geo_types::Geometry<f64>`            | GEOMETRY                                             |
+//! | `geo_types::Point<f64>`               | POINT                                                |
+//! | `geo_types::LineString<f64>`          | LINESTRING                                           |
+//! | `geo_types::Polygon<f64>`             | POLYGON                                              |
+//! | `geo_types::MultiPoint<f64>`          | MULTIPOINT                                           |
+//! | `geo_types::MultiLineString<f64>`     | MULTILINESTRING                                      |
+//! | `geo_types::MultiPolygon<f64>`        | MULTIPOLYGON                                         |
+//!
+//! Geometries are sent with an SRID of 0.
+//!
 //! # Nullable
 //!
 //! In addition, `Option<T>` is supported where `T` implements `Type`. An `Option<T>` represents
@@ -82,6 +109,7 @@ mod bool;
 mod bytes;
 mod float;
 mod int;
+mod set;
 mod str;
 mod uint;
 
@@ -102,3 +130,9 @@ mod uuid;
 
 #[cfg(feature = "json")]
 mod json;
+
+#[cfg(feature = "bit-vec")]
+mod bit_vec;
+
+#[cfg(feature = "geo-types")]
+mod geometry;
@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mysql::io::MySqlBufMutExt;
+use crate::mysql::protocol::text::{ColumnFlags, ColumnType};
+use crate::mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
+use crate::types::Type;
+
+// the members of a SET are sent as a comma-separated string, in the order of the definition
+
+fn set_type_info() -> MySqlTypeInfo {
+    MySqlTypeInfo {
+        r#type: ColumnType::String,
+        flags: ColumnFlags::SET,
+        char_set: <str as Type<MySql>>::type_info().char_set,
+        max_size: None,
+    }
+}
+
+fn encode_set<'a>(members: impl Iterator<Item = &'a String>, buf: &mut Vec<u8>) -> IsNull {
+    let members: Vec<&str> = members.map(String::as_str).collect();
+    buf.put_str_lenenc(&members.join(","));
+
+    IsNull::No
+}
+
+fn decode_set(value: MySqlValueRef<'_>) -> Result<impl Iterator<Item = String> + '_, BoxDynError> {
+    let s = value.as_str()?;
+
+    // an empty string is the empty set, not a set of one empty member
+    Ok(s.split(',')
+        .filter(move |_| !s.is_empty())
+        .map(ToOwned::to_owned))
+}
+
+impl Type<MySql> for Vec<String> {
+    fn type_info() -> MySqlTypeInfo {
+        set_type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        ty.is_set()
+    }
+}
+
+impl Encode<'_, MySql> for Vec<String> {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        encode_set(self.iter(), buf)
+    }
+}
+
+impl Decode<'_, MySql> for Vec<String> {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(decode_set(value)?.collect())
+    }
+}
+
+impl Type<MySql> for HashSet<String> {
+    fn type_info() -> MySqlTypeInfo {
+        set_type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        ty.is_set()
+    }
+}
+
+impl Encode<'_, MySql> for HashSet<String> {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        encode_set(self.iter(), buf)
+    }
+}
+
+impl Decode<'_, MySql> for HashSet<String> {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(decode_set(value)?.collect())
+    }
+}
@@ -16,17 +16,17 @@ fn uint_type_info(ty: ColumnType) -> MySqlTypeInfo {
     }
 }
 
-fn uint_compatible(ty: &MySqlTypeInfo) -> bool {
-    matches!(
+fn uint_compatible(ty: &MySqlTypeInfo, bits: u32) -> bool {
+    let is_unsigned_int = matches!(
         ty.r#type,
         ColumnType::Tiny
             | ColumnType::Short
             | ColumnType::Long
             | ColumnType::Int24
             | ColumnType::LongLong
-            | ColumnType::Year
-            | ColumnType::Bit
-    ) && ty.flags.contains(ColumnFlags::UNSIGNED)
+    ) && ty.flags.contains(ColumnFlags::UNSIGNED);
+
+    is_unsigned_int || (ty.is_year() && bits >= 16) || ty.is_bit_of(bits)
 }
 
 impl Type<MySql> for u8 {
@@ -35,7 +35,7 @@ impl Type<MySql> for u8 {
     }
 
     fn compatible(ty: &MySqlTypeInfo) -> bool {
-        uint_compatible(ty)
+        uint_compatible(ty, 8)
     }
 }
 
@@ -45,7 +45,7 @@ impl Type<MySql> for u16 {
     }
 
     fn compatible(ty: &MySqlTypeInfo) -> bool {
-        uint_compatible(ty)
+        uint_compatible(ty, 16)
     }
 }
 
@@ -55,7 +55,7 @@ impl Type<MySql> for u32 {
     }
 
     fn compatible(ty: &MySqlTypeInfo) -> bool {
-        uint_compatible(ty)
+        uint_compatible(ty, 32)
     }
 }
 
@@ -65,7 +65,7 @@ impl Type<MySql> for u64 {
     }
 
     fn compatible(ty: &MySqlTypeInfo) -> bool {
-        uint_compatible(ty)
+        uint_compatible(ty, 64)
     }
 }
 
@@ -68,18 +68,24 @@ impl<DB: Database> Drop for PoolConnection<DB> {
         if let Some(mut live) = self.live.take() {
             let pool = self.pool.clone();
 
-            if live.raw.should_flush() {
+            if live.raw.should_flush() || live.raw.should_reset() {
                 spawn(async move {
-                    // flush the connection (will immediately return if not needed) before
-                    // we fully release to the pool
-                    if let Err(e) = live.raw.flush().await {
-                        log::error!("error occurred while flushing the connection: {}", e);
+                    // flush the connection (will immediately return if not needed) and
+                    // reset its session, if requested, before we fully release to the pool
+                    let mut result = live.raw.flush().await;
+
+                    if result.is_ok() && live.raw.should_reset() {
+                        result = live.raw.reset().await;
+                    }
+
+                    if let Err(e) = result {
+                        log::error!("error occurred while releasing the connection: {}", e);
 
                         // we now consider the connection to be broken
                         // close the connection and drop from the pool
                         let _ = live.float(&pool).into_idle().close().await;
                     } else {
-                        // after we have flushed successfully, release to the pool
+                        // after we have flushed and reset successfully, release to the pool
                         pool.release(live.float(&pool));
                     }
                 });
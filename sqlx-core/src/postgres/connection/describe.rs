@@ -104,13 +104,38 @@ impl PgConnection {
 
     fn fetch_type_by_oid(&mut self, oid: u32) -> BoxFuture<'_, Result<PgTypeInfo, Error>> {
         Box::pin(async move {
-            let (name, category, relation_id, element): (String, i8, u32, u32) = query_as(
-                "SELECT typname, typcategory, typrelid, typelem FROM pg_catalog.pg_type WHERE oid = $1",
+            let (name, typ_type, category, relation_id, element, base_type): (
+                String,
+                i8,
+                i8,
+                u32,
+                u32,
+                u32,
+            ) = query_as(
+                "SELECT typname, typtype, typcategory, typrelid, typelem, typbasetype FROM pg_catalog.pg_type WHERE oid = $1",
             )
             .bind(oid)
             .fetch_one(&mut *self)
             .await?;
 
+            // domains and multiranges take the category of their base and range type, so the
+            // type itself must be checked first
+            match typ_type as u8 {
+                b'd' => {
+                    return Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
+                        kind: PgTypeKind::Domain(
+                            self.maybe_fetch_type_info_by_oid(base_type, true).await?,
+                        ),
+                        name: name.into(),
+                        oid,
+                    }))));
+                }
+
+                b'm' => return self.fetch_multirange_by_oid(oid, name).await,
+
+                _ => {}
+            }
+
             match category as u8 {
                 b'A' => Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
                     kind: PgTypeKind::Array(self.fetch_type_by_oid(element).await?),
@@ -223,15 +248,45 @@ WHERE rngtypid = $1
         })
     }
 
+    fn fetch_multirange_by_oid(
+        &mut self,
+        oid: u32,
+        name: String,
+    ) -> BoxFuture<'_, Result<PgTypeInfo, Error>> {
+        Box::pin(async move {
+            let range_oid: u32 = query_scalar(
+                r#"
+SELECT rngtypid
+FROM pg_catalog.pg_range
+WHERE rngmultitypid = $1
+                "#,
+            )
+            .bind(oid)
+            .fetch_one(&mut *self)
+            .await?;
+
+            let range = self.maybe_fetch_type_info_by_oid(range_oid, true).await?;
+
+            Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
+                kind: PgTypeKind::Multirange(range),
+                name: name.into(),
+                oid,
+            }))))
+        })
+    }
+
     pub(crate) async fn fetch_type_id_by_name(&mut self, name: &str) -> Result<u32, Error> {
         if let Some(oid) = self.cache_type_oid.get(name) {
             return Ok(*oid);
         }
 
+        // compared with `lower` rather than `ILIKE`, where the `_` that starts the name of an
+        // array type would match any character
+
         // language=SQL
         let (oid,): (u32,) = query_as(
             "
-SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
+SELECT oid FROM pg_catalog.pg_type WHERE lower(typname) = lower($1)
                 ",
         )
         .bind(name)
@@ -3,8 +3,8 @@ use crate::error::Error;
 use crate::executor::{Execute, Executor};
 use crate::logger::QueryLogger;
 use crate::postgres::message::{
-    self, Bind, Close, CommandComplete, DataRow, MessageFormat, ParameterDescription, Parse, Query,
-    RowDescription,
+    self, Bind, Close, CommandComplete, DataRow, Flush, MessageFormat, ParameterDescription, Parse,
+    Query, RowDescription,
 };
 use crate::postgres::statement::PgStatementMetadata;
 use crate::postgres::type_info::PgType;
@@ -198,6 +198,7 @@ impl PgConnection {
         limit: u8,
         persistent: bool,
         metadata_opt: Option<Arc<PgStatementMetadata>>,
+        fetch_size: Option<u32>,
     ) -> Result<impl Stream<Item = Result<Either<PgDone, PgRow>, Error>> + 'e, Error> {
         let mut logger = QueryLogger::new(query, self.log_settings.clone());
 
@@ -218,6 +219,10 @@ impl PgConnection {
             // patch holes created during encoding
             arguments.apply_patches(self, &metadata.parameters).await?;
 
+            // patching may have looked up the OIDs of types by name, so wait until those
+            // queries are complete before sending this one
+            self.wait_until_ready().await?;
+
             // bind to attach the arguments to the statement and create a portal
             self.stream.write(Bind {
                 portal: None,
@@ -228,19 +233,32 @@ impl PgConnection {
                 result_formats: &[PgValueFormat::Binary],
             });
 
-            // executes the portal up to the passed limit
-            // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
-            self.stream.write(message::Execute {
-                portal: None,
-                limit: limit.into(),
-            });
-
-            // finally, [Sync] asks postgres to process the messages that we sent and respond with
-            // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
-            // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
-            // is still serial but it would reduce round-trips. Some kind of builder pattern that is
-            // termed batching might suit this.
-            self.write_sync();
+            if let Some(fetch_size) = fetch_size {
+                // executes the portal for a batch of rows at a time; the portal is suspended
+                // after each batch and would be closed by a [Sync], so only [Flush] is sent
+                // until the last batch was received
+                self.stream.write(message::Execute {
+                    portal: None,
+                    limit: fetch_size,
+                });
+
+                self.stream.write(Flush);
+                self.pending_portal = true;
+            } else {
+                // executes the portal up to the passed limit
+                // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
+                self.stream.write(message::Execute {
+                    portal: None,
+                    limit: limit.into(),
+                });
+
+                // finally, [Sync] asks postgres to process the messages that we sent and respond with
+                // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
+                // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
+                // is still serial but it would reduce round-trips. Some kind of builder pattern that is
+                // termed batching might suit this.
+                self.write_sync();
+            }
 
             // prepared statements are binary
             PgValueFormat::Binary
@@ -274,11 +292,31 @@ impl PgConnection {
                         // a SQL command completed normally
                         let cc: CommandComplete = message.decode()?;
 
+                        if self.pending_portal {
+                            // the last batch of the portal was received
+                            self.pending_portal = false;
+                            self.write_sync();
+                            self.stream.flush().await?;
+                        }
+
                         r#yield!(Either::Left(PgDone {
                             rows_affected: cc.rows_affected(),
                         }));
                     }
 
+                    MessageFormat::PortalSuspended => {
+                        // the batch of rows was received, so execute the portal for the next one
+                        if let Some(fetch_size) = fetch_size {
+                            self.stream.write(message::Execute {
+                                portal: None,
+                                limit: fetch_size,
+                            });
+
+                            self.stream.write(Flush);
+                            self.stream.flush().await?;
+                        }
+                    }
+
                     MessageFormat::EmptyQueryResponse => {
                         // empty query string passed to an unprepared execute
                     }
@@ -345,9 +383,10 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
         let arguments = query.take_arguments();
         let persistent = query.persistent();
+        let fetch_size = query.fetch_size();
 
         Box::pin(try_stream! {
-            let s = self.run(sql, arguments, 0, persistent, metadata).await?;
+            let s = self.run(sql, arguments, 0, persistent, metadata, fetch_size).await?;
             pin_mut!(s);
 
             while let Some(v) = s.try_next().await? {
@@ -372,7 +411,9 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let persistent = query.persistent();
 
         Box::pin(async move {
-            let s = self.run(sql, arguments, 1, persistent, metadata).await?;
+            let s = self
+                .run(sql, arguments, 1, persistent, metadata, None)
+                .await?;
             pin_mut!(s);
 
             while let Some(s) = s.try_next().await? {
@@ -57,6 +57,9 @@ pub struct PgConnection {
     // number of ReadyForQuery messages that we are currently expecting
     pub(crate) pending_ready_for_query_count: usize,
 
+    // a portal is executed in batches of rows and awaits the SYNC that closes it
+    pub(crate) pending_portal: bool,
+
     // current transaction status
     transaction_status: TransactionStatus,
     pub(crate) transaction_depth: usize,
@@ -67,6 +70,12 @@ pub struct PgConnection {
 impl PgConnection {
     // will return when the connection is ready for another query
     async fn wait_until_ready(&mut self) -> Result<(), Error> {
+        if self.pending_portal {
+            // the rows of the portal were not read to the end, the SYNC discards the rest
+            self.pending_portal = false;
+            self.write_sync();
+        }
+
         if !self.stream.wbuf.is_empty() {
             self.stream.flush().await?;
         }
@@ -174,6 +183,6 @@ impl Connection for PgConnection {
 
     #[doc(hidden)]
     fn should_flush(&self) -> bool {
-        !self.stream.wbuf.is_empty()
+        !self.stream.wbuf.is_empty() || self.pending_portal
     }
 }
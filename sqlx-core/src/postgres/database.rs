@@ -1,4 +1,6 @@
-use crate::database::{Database, HasArguments, HasStatement, HasStatementCache, HasValueRef};
+use crate::database::{
+    Database, HasArguments, HasFetchSize, HasStatement, HasStatementCache, HasValueRef,
+};
 use crate::postgres::arguments::PgArgumentBuffer;
 use crate::postgres::value::{PgValue, PgValueRef};
 use crate::postgres::{
@@ -47,3 +49,5 @@ impl<'q> HasStatement<'q> for Postgres {
 }
 
 impl HasStatementCache for Postgres {}
+
+impl HasFetchSize for Postgres {}
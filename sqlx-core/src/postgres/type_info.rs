@@ -35,6 +35,8 @@ pub enum PgType {
     Oid,
     Json,
     JsonArray,
+    Xml,
+    XmlArray,
     Point,
     Lseg,
     Path,
@@ -99,6 +101,12 @@ pub enum PgType {
     UuidArray,
     Jsonb,
     JsonbArray,
+    PgLsn,
+    PgLsnArray,
+    Tsvector,
+    TsvectorArray,
+    Tsquery,
+    TsqueryArray,
     Int4Range,
     Int4RangeArray,
     NumRange,
@@ -111,6 +119,18 @@ pub enum PgType {
     DateRangeArray,
     Int8Range,
     Int8RangeArray,
+    Int4Multirange,
+    Int4MultirangeArray,
+    NumMultirange,
+    NumMultirangeArray,
+    TsMultirange,
+    TsMultirangeArray,
+    TstzMultirange,
+    TstzMultirangeArray,
+    DateMultirange,
+    DateMultirangeArray,
+    Int8Multirange,
+    Int8MultirangeArray,
     Jsonpath,
     JsonpathArray,
     Money,
@@ -150,6 +170,7 @@ pub enum PgTypeKind {
     Array(PgTypeInfo),
     Enum(Arc<[String]>),
     Range(PgTypeInfo),
+    Multirange(PgTypeInfo),
 }
 
 impl PgTypeInfo {
@@ -163,6 +184,17 @@ impl PgTypeInfo {
         self.0.kind()
     }
 
+    /// Returns the type a domain is defined over, looking through nested domains, or this type
+    /// if it is not a domain.
+    ///
+    /// A value of a domain is sent the same way as a value of its base type.
+    pub fn base_type(&self) -> &PgTypeInfo {
+        match self.0.domain_base() {
+            Some(base) => base.base_type(),
+            None => self,
+        }
+    }
+
     #[doc(hidden)]
     pub fn __type_feature_gate(&self) -> Option<&'static str> {
         if [
@@ -244,6 +276,8 @@ impl PgType {
             26 => PgType::Oid,
             114 => PgType::Json,
             199 => PgType::JsonArray,
+            142 => PgType::Xml,
+            143 => PgType::XmlArray,
             600 => PgType::Point,
             601 => PgType::Lseg,
             602 => PgType::Path,
@@ -311,6 +345,12 @@ impl PgType {
             2951 => PgType::UuidArray,
             3802 => PgType::Jsonb,
             3807 => PgType::JsonbArray,
+            3220 => PgType::PgLsn,
+            3221 => PgType::PgLsnArray,
+            3614 => PgType::Tsvector,
+            3615 => PgType::Tsquery,
+            3643 => PgType::TsvectorArray,
+            3645 => PgType::TsqueryArray,
             3904 => PgType::Int4Range,
             3905 => PgType::Int4RangeArray,
             3906 => PgType::NumRange,
@@ -325,6 +365,18 @@ impl PgType {
             3927 => PgType::Int8RangeArray,
             4072 => PgType::Jsonpath,
             4073 => PgType::JsonpathArray,
+            4451 => PgType::Int4Multirange,
+            4532 => PgType::NumMultirange,
+            4533 => PgType::TsMultirange,
+            4534 => PgType::TstzMultirange,
+            4535 => PgType::DateMultirange,
+            4536 => PgType::Int8Multirange,
+            6150 => PgType::Int4MultirangeArray,
+            6151 => PgType::NumMultirangeArray,
+            6152 => PgType::TsMultirangeArray,
+            6153 => PgType::TstzMultirangeArray,
+            6155 => PgType::DateMultirangeArray,
+            6157 => PgType::Int8MultirangeArray,
 
             _ => {
                 return None;
@@ -352,6 +404,8 @@ impl PgType {
             PgType::Oid => 26,
             PgType::Json => 114,
             PgType::JsonArray => 199,
+            PgType::Xml => 142,
+            PgType::XmlArray => 143,
             PgType::Point => 600,
             PgType::Lseg => 601,
             PgType::Path => 602,
@@ -419,6 +473,12 @@ impl PgType {
             PgType::UuidArray => 2951,
             PgType::Jsonb => 3802,
             PgType::JsonbArray => 3807,
+            PgType::PgLsn => 3220,
+            PgType::PgLsnArray => 3221,
+            PgType::Tsvector => 3614,
+            PgType::TsvectorArray => 3643,
+            PgType::Tsquery => 3615,
+            PgType::TsqueryArray => 3645,
             PgType::Int4Range => 3904,
             PgType::Int4RangeArray => 3905,
             PgType::NumRange => 3906,
@@ -433,6 +493,18 @@ impl PgType {
             PgType::Int8RangeArray => 3927,
             PgType::Jsonpath => 4072,
             PgType::JsonpathArray => 4073,
+            PgType::Int4Multirange => 4451,
+            PgType::Int4MultirangeArray => 6150,
+            PgType::NumMultirange => 4532,
+            PgType::NumMultirangeArray => 6151,
+            PgType::TsMultirange => 4533,
+            PgType::TsMultirangeArray => 6152,
+            PgType::TstzMultirange => 4534,
+            PgType::TstzMultirangeArray => 6153,
+            PgType::DateMultirange => 4535,
+            PgType::DateMultirangeArray => 6155,
+            PgType::Int8Multirange => 4536,
+            PgType::Int8MultirangeArray => 6157,
             PgType::Custom(ty) => ty.oid,
 
             PgType::DeclareWithOid(oid) => *oid,
@@ -455,6 +527,8 @@ impl PgType {
             PgType::Oid => "OID",
             PgType::Json => "JSON",
             PgType::JsonArray => "JSON[]",
+            PgType::Xml => "XML",
+            PgType::XmlArray => "XML[]",
             PgType::Point => "POINT",
             PgType::Lseg => "LSEG",
             PgType::Path => "PATH",
@@ -519,6 +593,12 @@ impl PgType {
             PgType::UuidArray => "UUID[]",
             PgType::Jsonb => "JSONB",
             PgType::JsonbArray => "JSONB[]",
+            PgType::PgLsn => "PG_LSN",
+            PgType::PgLsnArray => "PG_LSN[]",
+            PgType::Tsvector => "TSVECTOR",
+            PgType::TsvectorArray => "TSVECTOR[]",
+            PgType::Tsquery => "TSQUERY",
+            PgType::TsqueryArray => "TSQUERY[]",
             PgType::Int4Range => "INT4RANGE",
             PgType::Int4RangeArray => "INT4RANGE[]",
             PgType::NumRange => "NUMRANGE",
@@ -531,6 +611,18 @@ impl PgType {
             PgType::DateRangeArray => "DATERANGE[]",
             PgType::Int8Range => "INT8RANGE",
             PgType::Int8RangeArray => "INT8RANGE[]",
+            PgType::Int4Multirange => "INT4MULTIRANGE",
+            PgType::Int4MultirangeArray => "INT4MULTIRANGE[]",
+            PgType::NumMultirange => "NUMMULTIRANGE",
+            PgType::NumMultirangeArray => "NUMMULTIRANGE[]",
+            PgType::TsMultirange => "TSMULTIRANGE",
+            PgType::TsMultirangeArray => "TSMULTIRANGE[]",
+            PgType::TstzMultirange => "TSTZMULTIRANGE",
+            PgType::TstzMultirangeArray => "TSTZMULTIRANGE[]",
+            PgType::DateMultirange => "DATEMULTIRANGE",
+            PgType::DateMultirangeArray => "DATEMULTIRANGE[]",
+            PgType::Int8Multirange => "INT8MULTIRANGE",
+            PgType::Int8MultirangeArray => "INT8MULTIRANGE[]",
             PgType::Jsonpath => "JSONPATH",
             PgType::JsonpathArray => "JSONPATH[]",
             PgType::Money => "MONEY",
@@ -555,6 +647,8 @@ impl PgType {
             PgType::Oid => "oid",
             PgType::Json => "json",
             PgType::JsonArray => "_json",
+            PgType::Xml => "xml",
+            PgType::XmlArray => "_xml",
             PgType::Point => "point",
             PgType::Lseg => "lseg",
             PgType::Path => "path",
@@ -619,6 +713,12 @@ impl PgType {
             PgType::UuidArray => "_uuid",
             PgType::Jsonb => "jsonb",
             PgType::JsonbArray => "_jsonb",
+            PgType::PgLsn => "pg_lsn",
+            PgType::PgLsnArray => "_pg_lsn",
+            PgType::Tsvector => "tsvector",
+            PgType::TsvectorArray => "_tsvector",
+            PgType::Tsquery => "tsquery",
+            PgType::TsqueryArray => "_tsquery",
             PgType::Int4Range => "int4range",
             PgType::Int4RangeArray => "_int4range",
             PgType::NumRange => "numrange",
@@ -631,6 +731,18 @@ impl PgType {
             PgType::DateRangeArray => "_daterange",
             PgType::Int8Range => "int8range",
             PgType::Int8RangeArray => "_int8range",
+            PgType::Int4Multirange => "int4multirange",
+            PgType::Int4MultirangeArray => "_int4multirange",
+            PgType::NumMultirange => "nummultirange",
+            PgType::NumMultirangeArray => "_nummultirange",
+            PgType::TsMultirange => "tsmultirange",
+            PgType::TsMultirangeArray => "_tsmultirange",
+            PgType::TstzMultirange => "tstzmultirange",
+            PgType::TstzMultirangeArray => "_tstzmultirange",
+            PgType::DateMultirange => "datemultirange",
+            PgType::DateMultirangeArray => "_datemultirange",
+            PgType::Int8Multirange => "int8multirange",
+            PgType::Int8MultirangeArray => "_int8multirange",
             PgType::Jsonpath => "jsonpath",
             PgType::JsonpathArray => "_jsonpath",
             PgType::Money => "money",
@@ -642,6 +754,17 @@ impl PgType {
         }
     }
 
+    fn domain_base(&self) -> Option<&PgTypeInfo> {
+        match self {
+            PgType::Custom(ty) => match &ty.kind {
+                PgTypeKind::Domain(base) => Some(base),
+                _ => None,
+            },
+
+            _ => None,
+        }
+    }
+
     pub(crate) fn kind(&self) -> &PgTypeKind {
         match self {
             PgType::Bool => &PgTypeKind::Simple,
@@ -655,6 +778,8 @@ impl PgType {
             PgType::Oid => &PgTypeKind::Simple,
             PgType::Json => &PgTypeKind::Simple,
             PgType::JsonArray => &PgTypeKind::Array(PgTypeInfo(PgType::Json)),
+            PgType::Xml => &PgTypeKind::Simple,
+            PgType::XmlArray => &PgTypeKind::Array(PgTypeInfo(PgType::Xml)),
             PgType::Point => &PgTypeKind::Simple,
             PgType::Lseg => &PgTypeKind::Simple,
             PgType::Path => &PgTypeKind::Simple,
@@ -719,6 +844,12 @@ impl PgType {
             PgType::UuidArray => &PgTypeKind::Array(PgTypeInfo(PgType::Uuid)),
             PgType::Jsonb => &PgTypeKind::Simple,
             PgType::JsonbArray => &PgTypeKind::Array(PgTypeInfo(PgType::Jsonb)),
+            PgType::PgLsn => &PgTypeKind::Simple,
+            PgType::PgLsnArray => &PgTypeKind::Array(PgTypeInfo(PgType::PgLsn)),
+            PgType::Tsvector => &PgTypeKind::Simple,
+            PgType::TsvectorArray => &PgTypeKind::Array(PgTypeInfo(PgType::Tsvector)),
+            PgType::Tsquery => &PgTypeKind::Simple,
+            PgType::TsqueryArray => &PgTypeKind::Array(PgTypeInfo(PgType::Tsquery)),
             PgType::Int4Range => &PgTypeKind::Range(PgTypeInfo::INT4),
             PgType::Int4RangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Int4Range)),
             PgType::NumRange => &PgTypeKind::Range(PgTypeInfo::NUMERIC),
@@ -731,6 +862,18 @@ impl PgType {
             PgType::DateRangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::DateRange)),
             PgType::Int8Range => &PgTypeKind::Range(PgTypeInfo::INT8),
             PgType::Int8RangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Int8Range)),
+            PgType::Int4Multirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::Int4Range)),
+            PgType::Int4MultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Int4Multirange)),
+            PgType::NumMultirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::NumRange)),
+            PgType::NumMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::NumMultirange)),
+            PgType::TsMultirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::TsRange)),
+            PgType::TsMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::TsMultirange)),
+            PgType::TstzMultirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::TstzRange)),
+            PgType::TstzMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::TstzMultirange)),
+            PgType::DateMultirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::DateRange)),
+            PgType::DateMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::DateMultirange)),
+            PgType::Int8Multirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::Int8Range)),
+            PgType::Int8MultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Int8Multirange)),
             PgType::Jsonpath => &PgTypeKind::Simple,
             PgType::JsonpathArray => &PgTypeKind::Array(PgTypeInfo(PgType::Jsonpath)),
             PgType::Money => &PgTypeKind::Simple,
@@ -798,6 +941,14 @@ impl PgTypeInfo {
     pub(crate) const JSONPATH: Self = Self(PgType::Jsonpath);
     pub(crate) const JSONPATH_ARRAY: Self = Self(PgType::JsonpathArray);
 
+    //
+    // XML type
+    // https://www.postgresql.org/docs/current/datatype-xml.html
+    //
+
+    pub(crate) const XML: Self = Self(PgType::Xml);
+    pub(crate) const XML_ARRAY: Self = Self(PgType::XmlArray);
+
     //
     // network address types
     // https://www.postgresql.org/docs/current/datatype-net-types.html
@@ -953,6 +1104,25 @@ impl PgTypeInfo {
     pub(crate) const VARBIT: Self = Self(PgType::Varbit);
     pub(crate) const VARBIT_ARRAY: Self = Self(PgType::VarbitArray);
 
+    //
+    // log sequence number type
+    // https://www.postgresql.org/docs/current/datatype-pg-lsn.html
+    //
+
+    pub(crate) const PG_LSN: Self = Self(PgType::PgLsn);
+    pub(crate) const PG_LSN_ARRAY: Self = Self(PgType::PgLsnArray);
+
+    //
+    // text search types
+    // https://www.postgresql.org/docs/current/datatype-textsearch.html
+    //
+
+    pub(crate) const TSVECTOR: Self = Self(PgType::Tsvector);
+    pub(crate) const TSVECTOR_ARRAY: Self = Self(PgType::TsvectorArray);
+
+    pub(crate) const TSQUERY: Self = Self(PgType::Tsquery);
+    pub(crate) const TSQUERY_ARRAY: Self = Self(PgType::TsqueryArray);
+
     //
     // range types
     // https://www.postgresql.org/docs/current/rangetypes.html
@@ -976,6 +1146,29 @@ impl PgTypeInfo {
     pub(crate) const INT8_RANGE: Self = Self(PgType::Int8Range);
     pub(crate) const INT8_RANGE_ARRAY: Self = Self(PgType::Int8RangeArray);
 
+    //
+    // multirange types, since Postgres 14
+    // https://www.postgresql.org/docs/current/rangetypes.html
+    //
+
+    pub(crate) const INT4_MULTIRANGE: Self = Self(PgType::Int4Multirange);
+    pub(crate) const INT4_MULTIRANGE_ARRAY: Self = Self(PgType::Int4MultirangeArray);
+
+    pub(crate) const NUM_MULTIRANGE: Self = Self(PgType::NumMultirange);
+    pub(crate) const NUM_MULTIRANGE_ARRAY: Self = Self(PgType::NumMultirangeArray);
+
+    pub(crate) const TS_MULTIRANGE: Self = Self(PgType::TsMultirange);
+    pub(crate) const TS_MULTIRANGE_ARRAY: Self = Self(PgType::TsMultirangeArray);
+
+    pub(crate) const TSTZ_MULTIRANGE: Self = Self(PgType::TstzMultirange);
+    pub(crate) const TSTZ_MULTIRANGE_ARRAY: Self = Self(PgType::TstzMultirangeArray);
+
+    pub(crate) const DATE_MULTIRANGE: Self = Self(PgType::DateMultirange);
+    pub(crate) const DATE_MULTIRANGE_ARRAY: Self = Self(PgType::DateMultirangeArray);
+
+    pub(crate) const INT8_MULTIRANGE: Self = Self(PgType::Int8Multirange);
+    pub(crate) const INT8_MULTIRANGE_ARRAY: Self = Self(PgType::Int8MultirangeArray);
+
     //
     // pseudo types
     // https://www.postgresql.org/docs/9.3/datatype-pseudo.html
@@ -994,7 +1187,11 @@ impl PartialEq<PgType> for PgType {
     fn eq(&self, other: &PgType) -> bool {
         if let (Some(a), Some(b)) = (self.try_oid(), other.try_oid()) {
             // If there are OIDs available, use OIDs to perform a direct match
+            // A domain also matches the type it is defined over, so that Rust types written for
+            // the base type accept it
             a == b
+                || matches!(self.domain_base(), Some(base) if base.0 == *other)
+                || matches!(other.domain_base(), Some(base) if *self == base.0)
         } else if matches!(
             (self, other),
             (PgType::DeclareWithName(_), PgType::DeclareWithOid(_))
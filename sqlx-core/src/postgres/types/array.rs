@@ -3,10 +3,55 @@ use bytes::Buf;
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
-use crate::postgres::type_info::PgType;
+use crate::postgres::type_info::{PgType, PgTypeKind};
+use crate::postgres::types::array_compatible;
 use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use crate::types::Type;
 
+/// Provides the array type of a type whose array type is not built in, such as a
+/// user-defined enum or composite type.
+///
+/// Implementing this trait makes `Vec<T>` and `&[T]` usable as Postgres arrays of `T`. The
+/// array type is usually declared by name with [`PgTypeInfo::with_name`]; the name of the
+/// array type of `mood` is `_mood`. Its OID is looked up by each connection on first use.
+///
+/// This is implemented by `#[derive(sqlx::Type)]` for enums, composite types and domains.
+///
+/// [`PgTypeInfo::with_name`]: crate::postgres::PgTypeInfo::with_name
+pub trait PgHasArrayType: Type<Postgres> {
+    fn array_type_info() -> PgTypeInfo;
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        *ty == Self::array_type_info() || array_compatible::<Self>(ty)
+    }
+}
+
+impl<T> Type<Postgres> for [T]
+where
+    T: PgHasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        T::array_compatible(ty)
+    }
+}
+
+impl<T> Type<Postgres> for Vec<T>
+where
+    T: PgHasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        T::array_compatible(ty)
+    }
+}
+
 impl<T> Type<Postgres> for [Option<T>]
 where
     [T]: Type<Postgres>,
@@ -109,6 +154,7 @@ where
                 // the OID of the element
                 let element_type_oid = buf.get_u32();
                 element_type_info = PgTypeInfo::try_from_oid(element_type_oid)
+                    .or_else(|| custom_element_type(&value.type_info, element_type_oid))
                     .unwrap_or_else(|| PgTypeInfo(PgType::DeclareWithOid(element_type_oid)));
 
                 // length of the array axis
@@ -218,3 +264,15 @@ where
         }
     }
 }
+
+// the element type of a user-defined array type, as fetched by the connection
+fn custom_element_type(ty: &PgTypeInfo, oid: u32) -> Option<PgTypeInfo> {
+    match &ty.base_type().0 {
+        PgType::Custom(ty) => match &ty.kind {
+            PgTypeKind::Array(element) if element.0.try_oid() == Some(oid) => Some(element.clone()),
+            _ => None,
+        },
+
+        _ => None,
+    }
+}
@@ -0,0 +1,555 @@
+use std::mem;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/datatype-geometric.html
+// https://github.com/postgres/postgres/blob/REL_13_STABLE/src/backend/utils/adt/geo_ops.c
+
+/// A point on a plane, the Postgres `POINT` type.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// An infinite line given by the equation `a*x + b*y + c = 0`, the Postgres `LINE` type.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgLine {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+/// A finite line segment between two points, the Postgres `LSEG` type.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgLseg {
+    pub start: PgPoint,
+    pub end: PgPoint,
+}
+
+/// A rectangle given by its upper right and lower left corners, the Postgres `BOX` type.
+///
+/// Postgres swaps the coordinates of the corners as needed, so `high` is never below or left
+/// of `low` in a box that is read from the database.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgBox {
+    pub high: PgPoint,
+    pub low: PgPoint,
+}
+
+/// A sequence of connected points, the Postgres `PATH` type.
+///
+/// A closed path connects the last point back to the first one.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PgPath {
+    pub closed: bool,
+    pub points: Vec<PgPoint>,
+}
+
+/// A closed path which encloses an area, the Postgres `POLYGON` type.
+///
+/// The first point is not repeated at the end.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PgPolygon {
+    pub points: Vec<PgPoint>,
+}
+
+/// A circle given by its center and radius, the Postgres `CIRCLE` type.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgCircle {
+    pub center: PgPoint,
+    pub radius: f64,
+}
+
+macro_rules! impl_type {
+    ($ty:ty, $type_info:ident, $array_type_info:ident) => {
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::$type_info
+            }
+        }
+
+        impl Type<Postgres> for [$ty] {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::$array_type_info
+            }
+        }
+
+        impl Type<Postgres> for Vec<$ty> {
+            fn type_info() -> PgTypeInfo {
+                <[$ty] as Type<Postgres>>::type_info()
+            }
+        }
+    };
+}
+
+impl_type!(PgPoint, POINT, POINT_ARRAY);
+impl_type!(PgLine, LINE, LINE_ARRAY);
+impl_type!(PgLseg, LSEG, LSEG_ARRAY);
+impl_type!(PgBox, BOX, BOX_ARRAY);
+impl_type!(PgPath, PATH, PATH_ARRAY);
+impl_type!(PgPolygon, POLYGON, POLYGON_ARRAY);
+impl_type!(PgCircle, CIRCLE, CIRCLE_ARRAY);
+
+impl Encode<'_, Postgres> for PgPoint {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        write_point(self, buf);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        2 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgPoint {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => read_point(&mut value.as_bytes()?),
+
+            // (x,y)
+            PgValueFormat::Text => {
+                let floats = parse_floats(value.as_str()?, 2)?;
+
+                Ok(PgPoint {
+                    x: floats[0],
+                    y: floats[1],
+                })
+            }
+        }
+    }
+}
+
+impl Encode<'_, Postgres> for PgLine {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&self.a.to_be_bytes());
+        buf.extend(&self.b.to_be_bytes());
+        buf.extend(&self.c.to_be_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        3 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgLine {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+
+                Ok(PgLine {
+                    a: buf.read_f64::<NetworkEndian>()?,
+                    b: buf.read_f64::<NetworkEndian>()?,
+                    c: buf.read_f64::<NetworkEndian>()?,
+                })
+            }
+
+            // {a,b,c}
+            PgValueFormat::Text => {
+                let floats = parse_floats(value.as_str()?, 3)?;
+
+                Ok(PgLine {
+                    a: floats[0],
+                    b: floats[1],
+                    c: floats[2],
+                })
+            }
+        }
+    }
+}
+
+impl Encode<'_, Postgres> for PgLseg {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        write_point(&self.start, buf);
+        write_point(&self.end, buf);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        4 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgLseg {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let (start, end) = decode_point_pair(value)?;
+
+        Ok(PgLseg { start, end })
+    }
+}
+
+impl Encode<'_, Postgres> for PgBox {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        write_point(&self.high, buf);
+        write_point(&self.low, buf);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        4 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgBox {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let (high, low) = decode_point_pair(value)?;
+
+        Ok(PgBox { high, low })
+    }
+}
+
+impl Encode<'_, Postgres> for PgPath {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.push(self.closed as u8);
+        encode_points(&self.points, buf);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + mem::size_of::<i32>() + self.points.len() * 2 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgPath {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+                let closed = buf.read_u8()? != 0;
+                let points = read_points(&mut buf)?;
+
+                Ok(PgPath { closed, points })
+            }
+
+            // [(x1,y1),...] when open and ((x1,y1),...) when closed
+            PgValueFormat::Text => {
+                let s = value.as_str()?;
+
+                Ok(PgPath {
+                    closed: !s.trim_start().starts_with('['),
+                    points: parse_points(s)?,
+                })
+            }
+        }
+    }
+}
+
+impl Encode<'_, Postgres> for PgPolygon {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_points(&self.points, buf);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i32>() + self.points.len() * 2 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgPolygon {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let points = match value.format() {
+            PgValueFormat::Binary => read_points(&mut value.as_bytes()?)?,
+
+            // ((x1,y1),...)
+            PgValueFormat::Text => parse_points(value.as_str()?)?,
+        };
+
+        Ok(PgPolygon { points })
+    }
+}
+
+impl Encode<'_, Postgres> for PgCircle {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        write_point(&self.center, buf);
+        buf.extend(&self.radius.to_be_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        3 * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgCircle {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+                let center = read_point(&mut buf)?;
+                let radius = buf.read_f64::<NetworkEndian>()?;
+
+                Ok(PgCircle { center, radius })
+            }
+
+            // <(x,y),r>
+            PgValueFormat::Text => {
+                let floats = parse_floats(value.as_str()?, 3)?;
+
+                Ok(PgCircle {
+                    center: PgPoint {
+                        x: floats[0],
+                        y: floats[1],
+                    },
+                    radius: floats[2],
+                })
+            }
+        }
+    }
+}
+
+fn read_point(buf: &mut &[u8]) -> Result<PgPoint, BoxDynError> {
+    Ok(PgPoint {
+        x: buf.read_f64::<NetworkEndian>()?,
+        y: buf.read_f64::<NetworkEndian>()?,
+    })
+}
+
+fn write_point(point: &PgPoint, buf: &mut PgArgumentBuffer) {
+    buf.extend(&point.x.to_be_bytes());
+    buf.extend(&point.y.to_be_bytes());
+}
+
+fn read_points(buf: &mut &[u8]) -> Result<Vec<PgPoint>, BoxDynError> {
+    let len = buf.read_i32::<NetworkEndian>()?;
+
+    if len < 0 || len as usize > buf.len() / 16 {
+        return Err(format!("invalid number of points: {}", len).into());
+    }
+
+    (0..len).map(|_| read_point(buf)).collect()
+}
+
+fn encode_points(points: &[PgPoint], buf: &mut PgArgumentBuffer) {
+    buf.extend(&(points.len() as i32).to_be_bytes());
+
+    for point in points {
+        write_point(point, buf);
+    }
+}
+
+// decodes the two points of a LSEG or a BOX
+fn decode_point_pair(value: PgValueRef<'_>) -> Result<(PgPoint, PgPoint), BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => {
+            let mut buf = value.as_bytes()?;
+
+            Ok((read_point(&mut buf)?, read_point(&mut buf)?))
+        }
+
+        // [(x1,y1),(x2,y2)] or (x1,y1),(x2,y2)
+        PgValueFormat::Text => {
+            let points = parse_points(value.as_str()?)?;
+
+            match &*points {
+                [first, second] => Ok((*first, *second)),
+                _ => Err(format!("expected 2 points but found {}", points.len()).into()),
+            }
+        }
+    }
+}
+
+// parses the numbers of the text format of a geometric type, ignoring the delimiters
+fn split_floats(s: &str) -> Result<Vec<f64>, BoxDynError> {
+    s.split(&['(', ')', '[', ']', '{', '}', '<', '>', ','][..])
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().map_err(Into::into))
+        .collect()
+}
+
+fn parse_floats(s: &str, len: usize) -> Result<Vec<f64>, BoxDynError> {
+    let floats = split_floats(s)?;
+
+    if floats.len() != len {
+        return Err(format!("expected {} numbers in {:?}", len, s).into());
+    }
+
+    Ok(floats)
+}
+
+fn parse_points(s: &str) -> Result<Vec<PgPoint>, BoxDynError> {
+    let floats = split_floats(s)?;
+
+    if floats.len() % 2 != 0 {
+        return Err(format!("expected pairs of numbers in {:?}", s).into());
+    }
+
+    Ok(floats
+        .chunks(2)
+        .map(|xy| PgPoint { x: xy[0], y: xy[1] })
+        .collect())
+}
+
+#[cfg(feature = "geo-types")]
+mod geo {
+    use std::convert::TryFrom;
+
+    use geo_types::{Coordinate, Line, LineString, Point, Polygon, Rect};
+
+    use super::{PgBox, PgLseg, PgPath, PgPoint, PgPolygon};
+    use crate::error::BoxDynError;
+
+    impl From<PgPoint> for Coordinate<f64> {
+        fn from(point: PgPoint) -> Self {
+            Coordinate {
+                x: point.x,
+                y: point.y,
+            }
+        }
+    }
+
+    impl From<Coordinate<f64>> for PgPoint {
+        fn from(coordinate: Coordinate<f64>) -> Self {
+            PgPoint {
+                x: coordinate.x,
+                y: coordinate.y,
+            }
+        }
+    }
+
+    impl From<PgPoint> for Point<f64> {
+        fn from(point: PgPoint) -> Self {
+            Point(point.into())
+        }
+    }
+
+    impl From<Point<f64>> for PgPoint {
+        fn from(point: Point<f64>) -> Self {
+            point.0.into()
+        }
+    }
+
+    impl From<PgLseg> for Line<f64> {
+        fn from(lseg: PgLseg) -> Self {
+            Line::new(lseg.start, lseg.end)
+        }
+    }
+
+    impl From<Line<f64>> for PgLseg {
+        fn from(line: Line<f64>) -> Self {
+            PgLseg {
+                start: line.start.into(),
+                end: line.end.into(),
+            }
+        }
+    }
+
+    impl From<PgBox> for Rect<f64> {
+        fn from(b: PgBox) -> Self {
+            Rect::new(b.low, b.high)
+        }
+    }
+
+    impl From<Rect<f64>> for PgBox {
+        fn from(rect: Rect<f64>) -> Self {
+            PgBox {
+                high: rect.max().into(),
+                low: rect.min().into(),
+            }
+        }
+    }
+
+    /// A closed path becomes a line string which repeats the first point at the end.
+    impl From<PgPath> for LineString<f64> {
+        fn from(path: PgPath) -> Self {
+            let mut line_string: LineString<f64> =
+                path.points.into_iter().map(Coordinate::from).collect();
+
+            if path.closed {
+                line_string.close();
+            }
+
+            line_string
+        }
+    }
+
+    /// A line string which ends at its first point becomes a closed path.
+    impl From<LineString<f64>> for PgPath {
+        fn from(line_string: LineString<f64>) -> Self {
+            let closed = line_string.0.len() > 1 && line_string.is_closed();
+            let mut points: Vec<PgPoint> = line_string.0.into_iter().map(PgPoint::from).collect();
+
+            if closed {
+                points.pop();
+            }
+
+            PgPath { closed, points }
+        }
+    }
+
+    impl From<PgPolygon> for Polygon<f64> {
+        fn from(polygon: PgPolygon) -> Self {
+            let exterior = polygon.points.into_iter().map(Coordinate::from).collect();
+
+            Polygon::new(exterior, Vec::new())
+        }
+    }
+
+    /// Fails for a polygon with interior rings, which Postgres polygons can not have.
+    impl TryFrom<Polygon<f64>> for PgPolygon {
+        type Error = BoxDynError;
+
+        fn try_from(polygon: Polygon<f64>) -> Result<Self, BoxDynError> {
+            let (exterior, interiors) = polygon.into_inner();
+
+            if !interiors.is_empty() {
+                return Err("a Postgres `POLYGON` can not have interior rings".into());
+            }
+
+            let PgPath { points, .. } = PgPath::from(exterior);
+
+            Ok(PgPolygon { points })
+        }
+    }
+}
+
+#[test]
+fn test_decode_text_geometry() {
+    assert_eq!(parse_floats("(1.5,-2)", 2).unwrap(), vec![1.5, -2.0]);
+    assert_eq!(
+        parse_floats("<(1,2),1e+20>", 3).unwrap(),
+        vec![1.0, 2.0, 1e20]
+    );
+    assert_eq!(
+        parse_points("[(0,0),(1,1),(2,0)]").unwrap(),
+        vec![
+            PgPoint { x: 0.0, y: 0.0 },
+            PgPoint { x: 1.0, y: 1.0 },
+            PgPoint { x: 2.0, y: 0.0 },
+        ],
+    );
+
+    assert!(parse_floats("(1,2,3)", 2).is_err());
+    assert!(parse_points("((0,0),(1))").is_err());
+}
+
+#[test]
+fn test_encode_path() {
+    let path = PgPath {
+        closed: true,
+        points: vec![PgPoint { x: 1.0, y: 2.0 }],
+    };
+
+    let mut buf = PgArgumentBuffer::default();
+    let _ = path.encode_by_ref(&mut buf);
+
+    assert_eq!(
+        &**buf,
+        b"\x01\x00\x00\x00\x01\x3f\xf0\x00\x00\x00\x00\x00\x00\x40\x00\x00\x00\x00\x00\x00\x00"
+    );
+}
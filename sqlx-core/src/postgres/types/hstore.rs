@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::str;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/hstore.html
+// https://github.com/postgres/postgres/blob/REL_13_STABLE/contrib/hstore/hstore_io.c
+
+// `hstore` is defined by an extension, so its OID is looked up by name
+
+impl<S> Type<Postgres> for HashMap<String, Option<String>, S> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("hstore")
+    }
+}
+
+impl<S> Encode<'_, Postgres> for HashMap<String, Option<String>, S> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&(self.len() as i32).to_be_bytes());
+
+        for (key, value) in self {
+            buf.extend(&(key.len() as i32).to_be_bytes());
+            buf.extend(key.as_bytes());
+
+            match value {
+                Some(value) => {
+                    buf.extend(&(value.len() as i32).to_be_bytes());
+                    buf.extend(value.as_bytes());
+                }
+
+                None => {
+                    buf.extend(&(-1_i32).to_be_bytes());
+                }
+            }
+        }
+
+        IsNull::No
+    }
+}
+
+impl<S> Decode<'_, Postgres> for HashMap<String, Option<String>, S>
+where
+    S: BuildHasher + Default,
+{
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+                let len = buf.read_i32::<NetworkEndian>()?;
+
+                if len < 0 {
+                    return Err(format!("invalid number of hstore pairs: {}", len).into());
+                }
+
+                let mut map = HashMap::with_capacity_and_hasher(len as usize, S::default());
+
+                for _ in 0..len {
+                    let key = read_string(&mut buf)?
+                        .ok_or("unexpected NULL key in hstore")?
+                        .to_owned();
+
+                    let value = read_string(&mut buf)?.map(str::to_owned);
+
+                    map.insert(key, value);
+                }
+
+                Ok(map)
+            }
+
+            // "key"=>"value", "other"=>NULL
+            PgValueFormat::Text => parse_hstore(value.as_str()?),
+        }
+    }
+}
+
+// reads a length-prefixed string, where a length of -1 is NULL
+fn read_string<'a>(buf: &mut &'a [u8]) -> Result<Option<&'a str>, BoxDynError> {
+    let len = buf.read_i32::<NetworkEndian>()?;
+
+    if len < 0 {
+        return Ok(None);
+    }
+
+    let len = len as usize;
+
+    if buf.len() < len {
+        return Err("unexpected end of hstore".into());
+    }
+
+    let (s, rest) = buf.split_at(len);
+    *buf = rest;
+
+    Ok(Some(str::from_utf8(s)?))
+}
+
+fn parse_hstore<S>(s: &str) -> Result<HashMap<String, Option<String>, S>, BoxDynError>
+where
+    S: BuildHasher + Default,
+{
+    let mut map = HashMap::default();
+    let mut chars = s.chars().peekable();
+
+    loop {
+        while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+            chars.next();
+        }
+
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let key = parse_quoted(&mut chars)?;
+
+        if chars.next() != Some('=') || chars.next() != Some('>') {
+            return Err(format!("expected `=>` after hstore key {:?}", key).into());
+        }
+
+        let value = if chars.peek() == Some(&'"') {
+            Some(parse_quoted(&mut chars)?)
+        } else {
+            let null: String = chars.by_ref().take(4).collect();
+
+            if !null.eq_ignore_ascii_case("NULL") {
+                return Err(format!("unexpected hstore value {:?}", null).into());
+            }
+
+            None
+        };
+
+        map.insert(key, value);
+
+        match chars.next() {
+            Some(',') | None => {}
+
+            Some(c) => {
+                return Err(format!("unexpected {:?} after hstore value", c).into());
+            }
+        }
+    }
+
+    Ok(map)
+}
+
+// parses a string in double quotes, where a backslash escapes the next character
+fn parse_quoted(chars: &mut impl Iterator<Item = char>) -> Result<String, BoxDynError> {
+    if chars.next() != Some('"') {
+        return Err("expected a quoted string in hstore".into());
+    }
+
+    let mut s = String::new();
+
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(s),
+            Some('\\') => s.push(chars.next().ok_or("unexpected end of hstore")?),
+            Some(c) => s.push(c),
+            None => return Err("unexpected end of hstore".into()),
+        }
+    }
+}
+
+#[test]
+fn test_decode_text_hstore() {
+    let map: HashMap<String, Option<String>> =
+        parse_hstore(r#""a"=>"1", "b c"=>NULL, "q\"uote"=>"back\\slash""#).unwrap();
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map["a"], Some("1".to_owned()));
+    assert_eq!(map["b c"], None);
+    assert_eq!(map["q\"uote"], Some("back\\slash".to_owned()));
+
+    assert!(parse_hstore::<std::collections::hash_map::RandomState>("")
+        .unwrap()
+        .is_empty());
+    assert!(parse_hstore::<std::collections::hash_map::RandomState>(r#""a"=>"#).is_err());
+}
@@ -1,5 +1,7 @@
 use std::convert::{TryFrom, TryInto};
+use std::fmt::{self, Display, Formatter, Write};
 use std::mem;
+use std::str::FromStr;
 
 use byteorder::{NetworkEndian, ReadBytesExt};
 
@@ -9,8 +11,24 @@ use crate::error::BoxDynError;
 use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use crate::types::Type;
 
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SECOND;
+const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+const DAYS_PER_MONTH: i32 = 30;
+const MONTHS_PER_YEAR: i32 = 12;
+
 // `PgInterval` is available for direct access to the INTERVAL type
 
+/// A span of time, the Postgres [`INTERVAL`] type.
+///
+/// Months, days and microseconds are kept apart, as in Postgres, because the length of a month
+/// or a day depends on the date it is added to.
+///
+/// It is displayed in the default `postgres` interval style, like `1 year 2 mons 3 days
+/// 04:05:06.789`, and parsed from that style or from ISO 8601, like `P1Y2M3DT4H5M6.789S`.
+///
+/// [`INTERVAL`]: https://www.postgresql.org/docs/current/datatype-datetime.html#DATATYPE-INTERVAL-INPUT
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct PgInterval {
     pub months: i32,
@@ -18,6 +36,313 @@ pub struct PgInterval {
     pub microseconds: i64,
 }
 
+impl PgInterval {
+    /// Moves each whole 24 hours of the time into the days, like `justify_hours` in Postgres.
+    ///
+    /// Returns `None` if the days overflow.
+    pub fn justify_hours(&self) -> Option<Self> {
+        let (days, microseconds) = justify_time(self.days, self.microseconds)?;
+
+        Some(PgInterval {
+            months: self.months,
+            days,
+            microseconds,
+        })
+    }
+
+    /// Moves each whole 30 days into the months, like `justify_days` in Postgres.
+    ///
+    /// Returns `None` if the months overflow.
+    pub fn justify_days(&self) -> Option<Self> {
+        let mut months = self.months.checked_add(self.days / DAYS_PER_MONTH)?;
+        let mut days = self.days % DAYS_PER_MONTH;
+
+        if months > 0 && days < 0 {
+            days += DAYS_PER_MONTH;
+            months -= 1;
+        } else if months < 0 && days > 0 {
+            days -= DAYS_PER_MONTH;
+            months += 1;
+        }
+
+        Some(PgInterval {
+            months,
+            days,
+            microseconds: self.microseconds,
+        })
+    }
+
+    /// Moves whole days into the months and whole 24 hours into the days, so that all parts
+    /// have the same sign, like `justify_interval` in Postgres.
+    ///
+    /// Returns `None` if the months or days overflow.
+    pub fn justify_interval(&self) -> Option<Self> {
+        let mut days = self.days;
+        let mut microseconds = self.microseconds;
+
+        // move the time into the days first when that can not push the days out of range
+        if (days > 0 && microseconds > 0) || (days < 0 && microseconds < 0) {
+            let (d, us) = justify_time(days, microseconds)?;
+            days = d;
+            microseconds = us;
+        }
+
+        let mut months = self.months.checked_add(days / DAYS_PER_MONTH)?;
+        days %= DAYS_PER_MONTH;
+
+        if months > 0 && (days < 0 || (days == 0 && microseconds < 0)) {
+            days += DAYS_PER_MONTH;
+            months -= 1;
+        } else if months < 0 && (days > 0 || (days == 0 && microseconds > 0)) {
+            days -= DAYS_PER_MONTH;
+            months += 1;
+        }
+
+        let (days, microseconds) = justify_time(days, microseconds)?;
+
+        Some(PgInterval {
+            months,
+            days,
+            microseconds,
+        })
+    }
+
+    /// Formats the interval in ISO 8601, like the `iso_8601` interval style of Postgres.
+    pub fn to_iso_8601(&self) -> String {
+        if self.months == 0 && self.days == 0 && self.microseconds == 0 {
+            return "PT0S".to_owned();
+        }
+
+        let mut s = String::from("P");
+
+        for &(value, unit) in &[
+            (self.months / MONTHS_PER_YEAR, 'Y'),
+            (self.months % MONTHS_PER_YEAR, 'M'),
+            (self.days, 'D'),
+        ] {
+            if value != 0 {
+                let _ = write!(s, "{}{}", value, unit);
+            }
+        }
+
+        if self.microseconds != 0 {
+            s.push('T');
+
+            let hours = self.microseconds / MICROS_PER_HOUR;
+            let minutes = self.microseconds / MICROS_PER_MINUTE % 60;
+            let seconds = self.microseconds % MICROS_PER_MINUTE;
+
+            if hours != 0 {
+                let _ = write!(s, "{}H", hours);
+            }
+
+            if minutes != 0 {
+                let _ = write!(s, "{}M", minutes);
+            }
+
+            if seconds != 0 {
+                if seconds < 0 {
+                    s.push('-');
+                }
+
+                let _ = write_seconds(&mut s, seconds.unsigned_abs(), false);
+                s.push('S');
+            }
+        }
+
+        s
+    }
+
+    /// Adds the interval to `date_time` the way Postgres adds an interval to a `TIMESTAMP`:
+    /// first the months, keeping the day of the month where it can, then the days, then the
+    /// time.
+    ///
+    /// Returns `None` if the result is out of range.
+    #[cfg(feature = "chrono")]
+    pub fn checked_add_naive_date_time(
+        &self,
+        date_time: chrono::NaiveDateTime,
+    ) -> Option<chrono::NaiveDateTime> {
+        self.checked_add_chrono_calendar(date_time)?
+            .checked_add_signed(chrono::Duration::microseconds(self.microseconds))
+    }
+
+    /// Adds the interval to `date_time` the way Postgres adds an interval to a `TIMESTAMPTZ`:
+    /// the months and then the days in the local time of its time zone, then the time.
+    ///
+    /// A local time that is skipped when the clocks go forward takes the offset from before the
+    /// change, and one that repeats when they go back takes the offset from after it, as in
+    /// Postgres.
+    ///
+    /// Returns `None` if the result is out of range.
+    #[cfg(feature = "chrono")]
+    pub fn checked_add_date_time<Tz: chrono::TimeZone>(
+        &self,
+        date_time: &chrono::DateTime<Tz>,
+    ) -> Option<chrono::DateTime<Tz>> {
+        use chrono::{LocalResult, Offset};
+
+        let tz = date_time.timezone();
+        let local = self.checked_add_chrono_calendar(date_time.naive_local())?;
+
+        let date_time = match tz.from_local_datetime(&local) {
+            LocalResult::Single(date_time) => date_time,
+            LocalResult::Ambiguous(_, latest) => latest,
+            LocalResult::None => {
+                let before = local.checked_sub_signed(chrono::Duration::days(1))?;
+                let offset = tz.offset_from_utc_datetime(&before).fix();
+
+                tz.from_utc_datetime(&(local - offset))
+            }
+        };
+
+        date_time.checked_add_signed(chrono::Duration::microseconds(self.microseconds))
+    }
+
+    // adds the months and then the days
+    #[cfg(feature = "chrono")]
+    fn checked_add_chrono_calendar(
+        &self,
+        date_time: chrono::NaiveDateTime,
+    ) -> Option<chrono::NaiveDateTime> {
+        use chrono::Datelike;
+
+        let (year, month) = add_months(date_time.year(), date_time.month(), self.months)?;
+        let day = date_time.day().min(days_in_month(year, month));
+
+        chrono::NaiveDate::from_ymd_opt(year, month, day)?
+            .checked_add_signed(chrono::Duration::days(self.days.into()))
+            .map(|date| date.and_time(date_time.time()))
+    }
+
+    /// Adds the interval to `date_time` the way Postgres adds an interval to a timestamp: first
+    /// the months, keeping the day of the month where it can, then the days, then the time.
+    ///
+    /// Returns `None` if the result is out of range.
+    #[cfg(feature = "time")]
+    pub fn checked_add_offset_date_time(
+        &self,
+        date_time: time::OffsetDateTime,
+    ) -> Option<time::OffsetDateTime> {
+        const NANOS_PER_DAY: i128 = MICROS_PER_DAY as i128 * 1000;
+
+        // the days of the Julian calendar before 1970-01-01
+        const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+        let (year, month) = add_months(date_time.year(), date_time.month().into(), self.months)?;
+        let day = u32::from(date_time.day()).min(days_in_month(year, month));
+        let date = time::Date::try_from_ymd(year, month as u8, day as u8).ok()?;
+
+        // with a fixed offset, the days and the time can be added together
+        let time = date_time.time();
+        let nanos = i128::from(date.julian_day() - UNIX_EPOCH_JULIAN_DAY + i64::from(self.days))
+            * NANOS_PER_DAY
+            + i128::from(time.hour()) * 3_600_000_000_000
+            + i128::from(time.minute()) * 60_000_000_000
+            + i128::from(time.second()) * 1_000_000_000
+            + i128::from(time.nanosecond())
+            + i128::from(self.microseconds) * 1000
+            - i128::from(date_time.offset().as_seconds()) * 1_000_000_000;
+
+        // the date and time in UTC, which `PrimitiveDateTime::assume_offset` would panic
+        // to compute when out of range
+        let days = i64::try_from(nanos.div_euclid(NANOS_PER_DAY)).ok()?;
+        let nanos = nanos.rem_euclid(NANOS_PER_DAY) as u64;
+
+        let date = crate::common::date::date_from_unix_days(days)?;
+        let time = time::Time::try_from_hms_nano(
+            (nanos / 3_600_000_000_000) as u8,
+            (nanos / 60_000_000_000 % 60) as u8,
+            (nanos / 1_000_000_000 % 60) as u8,
+            (nanos % 1_000_000_000) as u32,
+        )
+        .ok()?;
+
+        Some(
+            time::PrimitiveDateTime::new(date, time)
+                .assume_utc()
+                .to_offset(date_time.offset()),
+        )
+    }
+}
+
+impl Display for PgInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // https://github.com/postgres/postgres/blob/REL_13_0/src/backend/utils/adt/datetime.c#L4378
+
+        let mut is_zero = true;
+        let mut is_before = false;
+
+        for &(value, unit) in &[
+            (self.months / MONTHS_PER_YEAR, "year"),
+            (self.months % MONTHS_PER_YEAR, "mon"),
+            (self.days, "day"),
+        ] {
+            if value == 0 {
+                continue;
+            }
+
+            write!(
+                f,
+                "{}{}{} {}{}",
+                if is_zero { "" } else { " " },
+                if is_before && value > 0 { "+" } else { "" },
+                value,
+                unit,
+                if value != 1 { "s" } else { "" }
+            )?;
+
+            is_before = value < 0;
+            is_zero = false;
+        }
+
+        if is_zero || self.microseconds != 0 {
+            let micros = self.microseconds.unsigned_abs();
+
+            write!(
+                f,
+                "{}{}{:02}:{:02}:",
+                if is_zero { "" } else { " " },
+                if self.microseconds < 0 {
+                    "-"
+                } else if is_before {
+                    "+"
+                } else {
+                    ""
+                },
+                micros / MICROS_PER_HOUR as u64,
+                micros / MICROS_PER_MINUTE as u64 % 60,
+            )?;
+
+            write_seconds(f, micros % MICROS_PER_MINUTE as u64, true)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for PgInterval {
+    type Err = BoxDynError;
+
+    /// Parses an interval in the `postgres` or `postgres_verbose` interval style, like
+    /// `1 year 2 mons -3 days 04:05:06` or `@ 1 year 2 mons ago`, or in ISO 8601 with
+    /// designators, like `P1Y2M-3DT4H5M6S`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let mut parts = IntervalParts::default();
+
+        match s.strip_prefix('P') {
+            Some(iso) => parts.parse_iso_8601(iso)?,
+            None => parts.parse_postgres(s)?,
+        }
+
+        parts
+            .finish()
+            .ok_or_else(|| format!("interval out of range: {:?}", s).into())
+    }
+}
+
 impl Type<Postgres> for PgInterval {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::INTERVAL
@@ -46,10 +371,7 @@ impl<'de> Decode<'de, Postgres> for PgInterval {
                 })
             }
 
-            // TODO: Implement parsing of text mode
-            PgValueFormat::Text => {
-                Err("not implemented: decode `INTERVAL` in text mode (unprepared queries)".into())
-            }
+            PgValueFormat::Text => value.as_str()?.parse(),
         }
     }
 }
@@ -201,6 +523,323 @@ impl TryFrom<time::Duration> for PgInterval {
     }
 }
 
+#[cfg(feature = "time")]
+impl<'de> Decode<'de, Postgres> for time::Duration {
+    fn decode(value: PgValueRef<'de>) -> Result<Self, BoxDynError> {
+        PgInterval::decode(value)?.try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<PgInterval> for time::Duration {
+    type Error = BoxDynError;
+
+    /// Convert a `PgInterval` to a `time::Duration`.
+    ///
+    /// This returns an error if the interval has months, which have no fixed length. A day is
+    /// taken to be 24 hours.
+    fn try_from(value: PgInterval) -> Result<Self, BoxDynError> {
+        if value.months != 0 {
+            return Err("an `INTERVAL` with months can not be converted to a duration".into());
+        }
+
+        Ok(time::Duration::days(value.days.into())
+            + time::Duration::microseconds(value.microseconds))
+    }
+}
+
+// the parts of an interval being parsed, kept wide to detect overflow at the end
+#[derive(Default)]
+struct IntervalParts {
+    months: i64,
+    days: i64,
+    microseconds: i64,
+}
+
+impl IntervalParts {
+    fn parse_postgres(&mut self, s: &str) -> Result<(), BoxDynError> {
+        let invalid = || -> BoxDynError { format!("invalid interval {:?}", s).into() };
+
+        let mut tokens = s.split_whitespace().peekable();
+
+        // `postgres_verbose` starts with `@` and ends with `ago` when negative
+        if tokens.peek() == Some(&"@") {
+            tokens.next();
+        }
+
+        let mut ago = false;
+        let mut any = false;
+
+        while let Some(token) = tokens.next() {
+            any = true;
+
+            if token.eq_ignore_ascii_case("ago") && tokens.peek().is_none() {
+                ago = true;
+            } else if token.contains(':') {
+                self.add_micros(parse_time(token).ok_or_else(invalid)?)?;
+            } else {
+                let unit = tokens.next().ok_or_else(invalid)?.to_ascii_lowercase();
+
+                match unit.trim_end_matches('s') {
+                    "year" | "yr" => self.add_months(token, MONTHS_PER_YEAR.into())?,
+                    "mon" | "month" => self.add_months(token, 1)?,
+                    "week" => self.add_days(token, 7)?,
+                    "day" => self.add_days(token, 1)?,
+                    "hour" | "hr" => self.add_micros_of(token, MICROS_PER_HOUR)?,
+                    "min" | "minute" => self.add_micros_of(token, MICROS_PER_MINUTE)?,
+                    "sec" | "second" => self.add_micros(parse_seconds(token)?)?,
+                    "millisecond" | "m" => self.add_micros_of(token, 1000)?,
+                    "microsecond" | "u" => self.add_micros_of(token, 1)?,
+                    _ => return Err(invalid()),
+                }
+            }
+        }
+
+        if !any {
+            return Err(invalid());
+        }
+
+        if ago {
+            self.months = -self.months;
+            self.days = -self.days;
+            self.microseconds = -self.microseconds;
+        }
+
+        Ok(())
+    }
+
+    fn parse_iso_8601(&mut self, s: &str) -> Result<(), BoxDynError> {
+        let invalid = || -> BoxDynError { format!("invalid interval \"P{}\"", s).into() };
+
+        if s.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut rest = s;
+        let mut in_time = false;
+
+        while !rest.is_empty() {
+            if let Some(time) = rest.strip_prefix('T') {
+                if in_time || time.is_empty() {
+                    return Err(invalid());
+                }
+
+                in_time = true;
+                rest = time;
+
+                continue;
+            }
+
+            let end = rest
+                .find(|ch: char| ch.is_ascii_alphabetic())
+                .ok_or_else(invalid)?;
+
+            let (value, unit) = rest.split_at(end);
+            rest = &unit[1..];
+
+            match (in_time, &unit[..1]) {
+                (false, "Y") => self.add_months(value, MONTHS_PER_YEAR.into())?,
+                (false, "M") => self.add_months(value, 1)?,
+                (false, "W") => self.add_days(value, 7)?,
+                (false, "D") => self.add_days(value, 1)?,
+                (true, "H") => self.add_micros_of(value, MICROS_PER_HOUR)?,
+                (true, "M") => self.add_micros_of(value, MICROS_PER_MINUTE)?,
+                (true, "S") => self.add_micros(parse_seconds(value)?)?,
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn add_months(&mut self, value: &str, months: i64) -> Result<(), BoxDynError> {
+        self.months = value
+            .parse::<i64>()?
+            .checked_mul(months)
+            .and_then(|months| self.months.checked_add(months))
+            .ok_or("interval out of range")?;
+
+        Ok(())
+    }
+
+    fn add_days(&mut self, value: &str, days: i64) -> Result<(), BoxDynError> {
+        self.days = value
+            .parse::<i64>()?
+            .checked_mul(days)
+            .and_then(|days| self.days.checked_add(days))
+            .ok_or("interval out of range")?;
+
+        Ok(())
+    }
+
+    fn add_micros_of(&mut self, value: &str, micros: i64) -> Result<(), BoxDynError> {
+        self.add_micros(
+            value
+                .parse::<i64>()?
+                .checked_mul(micros)
+                .ok_or("interval out of range")?,
+        )
+    }
+
+    fn add_micros(&mut self, micros: i64) -> Result<(), BoxDynError> {
+        self.microseconds = self
+            .microseconds
+            .checked_add(micros)
+            .ok_or("interval out of range")?;
+
+        Ok(())
+    }
+
+    fn finish(self) -> Option<PgInterval> {
+        Some(PgInterval {
+            months: self.months.try_into().ok()?,
+            days: self.days.try_into().ok()?,
+            microseconds: self.microseconds,
+        })
+    }
+}
+
+// parses `[-+]hh:mm[:ss[.ffffff]]` into microseconds
+fn parse_time(s: &str) -> Option<i64> {
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    // only the leading sign is allowed
+    if s.contains(&['-', '+'][..]) {
+        return None;
+    }
+
+    let mut parts = s.splitn(3, ':');
+
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let minutes: i64 = parts.next()?.parse().ok()?;
+    let seconds = match parts.next() {
+        Some(seconds) => parse_seconds(seconds).ok()?,
+        None => 0,
+    };
+
+    if minutes >= 60 || !(0..MICROS_PER_MINUTE).contains(&seconds) {
+        return None;
+    }
+
+    let micros = hours
+        .checked_mul(MICROS_PER_HOUR)?
+        .checked_add(minutes * MICROS_PER_MINUTE + seconds)?;
+
+    Some(if negative { -micros } else { micros })
+}
+
+// parses `[-+]ss[.ffffff]` into microseconds, rounding any further digits
+fn parse_seconds(s: &str) -> Result<i64, BoxDynError> {
+    let invalid = || -> BoxDynError { format!("invalid seconds {:?}", s).into() };
+
+    let (negative, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let (whole, fraction) = match unsigned.find('.') {
+        Some(i) => (&unsigned[..i], &unsigned[i + 1..]),
+        None => (unsigned, ""),
+    };
+
+    if (whole.is_empty() && fraction.is_empty())
+        || !whole.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let mut micros: i64 = if whole.is_empty() {
+        0
+    } else {
+        whole
+            .parse::<i64>()?
+            .checked_mul(MICROS_PER_SECOND)
+            .ok_or_else(invalid)?
+    };
+
+    let mut scale = MICROS_PER_SECOND;
+
+    for (i, digit) in fraction.bytes().enumerate() {
+        let digit = i64::from(digit - b'0');
+
+        if i < 6 {
+            scale /= 10;
+            micros += digit * scale;
+        } else {
+            if digit >= 5 {
+                micros = micros.checked_add(1).ok_or_else(invalid)?;
+            }
+
+            break;
+        }
+    }
+
+    Ok(if negative { -micros } else { micros })
+}
+
+// writes seconds and their fraction without trailing zeros, like Postgres
+fn write_seconds(w: &mut impl Write, micros: u64, zero_pad: bool) -> fmt::Result {
+    let seconds = micros / MICROS_PER_SECOND as u64;
+    let fraction = micros % MICROS_PER_SECOND as u64;
+
+    if zero_pad {
+        write!(w, "{:02}", seconds)?;
+    } else {
+        write!(w, "{}", seconds)?;
+    }
+
+    if fraction != 0 {
+        let fraction = format!("{:06}", fraction);
+        write!(w, ".{}", fraction.trim_end_matches('0'))?;
+    }
+
+    Ok(())
+}
+
+// moves whole days out of the time, leaving the time with the same sign as the days
+fn justify_time(days: i32, microseconds: i64) -> Option<(i32, i64)> {
+    let whole_days = i32::try_from(microseconds / MICROS_PER_DAY).ok()?;
+
+    let mut days = days.checked_add(whole_days)?;
+    let mut microseconds = microseconds % MICROS_PER_DAY;
+
+    if days > 0 && microseconds < 0 {
+        microseconds += MICROS_PER_DAY;
+        days -= 1;
+    } else if days < 0 && microseconds > 0 {
+        microseconds -= MICROS_PER_DAY;
+        days += 1;
+    }
+
+    Some((days, microseconds))
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn add_months(year: i32, month: u32, months: i32) -> Option<(i32, u32)> {
+    let months = i64::from(year) * 12 + i64::from(month) - 1 + i64::from(months);
+
+    Some((
+        i32::try_from(months.div_euclid(12)).ok()?,
+        months.rem_euclid(12) as u32 + 1,
+    ))
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
 #[test]
 fn test_encode_interval() {
     let mut buf = PgArgumentBuffer::default();
@@ -321,3 +960,201 @@ fn test_pginterval_time() {
         &interval
     );
 }
+
+#[test]
+fn test_pginterval_display() {
+    let interval = |months, days, microseconds| PgInterval {
+        months,
+        days,
+        microseconds,
+    };
+
+    assert_eq!(interval(0, 0, 0).to_string(), "00:00:00");
+    assert_eq!(interval(1, 1, 0).to_string(), "1 mon 1 day");
+    assert_eq!(
+        interval(14, 3, 14_706_789_000).to_string(),
+        "1 year 2 mons 3 days 04:05:06.789"
+    );
+    assert_eq!(
+        interval(0, -1, 7_200_000_000).to_string(),
+        "-1 days +02:00:00"
+    );
+    assert_eq!(
+        interval(-13, 0, -1).to_string(),
+        "-1 years -1 mons -00:00:00.000001"
+    );
+    assert_eq!(interval(0, 0, 90_000_000_000).to_string(), "25:00:00");
+}
+
+#[test]
+fn test_pginterval_iso_8601() {
+    let interval = |months, days, microseconds| PgInterval {
+        months,
+        days,
+        microseconds,
+    };
+
+    assert_eq!(interval(0, 0, 0).to_iso_8601(), "PT0S");
+    assert_eq!(
+        interval(14, 3, 14_706_789_000).to_iso_8601(),
+        "P1Y2M3DT4H5M6.789S"
+    );
+    assert_eq!(interval(0, -1, 7_200_000_000).to_iso_8601(), "P-1DT2H");
+    assert_eq!(interval(0, 0, -1_500_000).to_iso_8601(), "PT-1.5S");
+}
+
+#[test]
+fn test_pginterval_from_str() {
+    let interval = |months, days, microseconds| PgInterval {
+        months,
+        days,
+        microseconds,
+    };
+
+    for (s, expected) in &[
+        ("00:00:00", interval(0, 0, 0)),
+        (
+            "1 year 2 mons 3 days 04:05:06.789",
+            interval(14, 3, 14_706_789_000),
+        ),
+        ("-1 days +02:00:00", interval(0, -1, 7_200_000_000)),
+        ("-00:00:00.000001", interval(0, 0, -1)),
+        ("2 weeks 1 hour 30 mins", interval(0, 14, 5_400_000_000)),
+        ("@ 1 year 2 mons 5.5 secs ago", interval(-14, 0, -5_500_000)),
+        ("P1Y2M3DT4H5M6.789S", interval(14, 3, 14_706_789_000)),
+        ("P-1DT2H", interval(0, -1, 7_200_000_000)),
+        ("PT-1.5S", interval(0, 0, -1_500_000)),
+        ("P2W", interval(0, 14, 0)),
+        ("PT0.0000005S", interval(0, 0, 1)),
+    ] {
+        assert_eq!(s.parse::<PgInterval>().unwrap(), *expected, "{}", s);
+    }
+
+    for s in &[
+        "",
+        "1",
+        "1 fortnight",
+        "P",
+        "P1H",
+        "PT",
+        "1:60",
+        "01:-30",
+        "--1:00",
+        "1:+30",
+        "1:30:-5",
+        "2147483648 days",
+    ] {
+        assert!(s.parse::<PgInterval>().is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn test_pginterval_justify() {
+    let interval = |months, days, microseconds| PgInterval {
+        months,
+        days,
+        microseconds,
+    };
+
+    assert_eq!(
+        interval(0, 1, 97_200_000_000).justify_hours(),
+        Some(interval(0, 2, 10_800_000_000))
+    );
+    assert_eq!(
+        interval(0, 1, -3_600_000_000).justify_hours(),
+        Some(interval(0, 0, 82_800_000_000))
+    );
+    assert_eq!(interval(1, -35, 0).justify_days(), Some(interval(0, -5, 0)));
+    assert_eq!(
+        interval(1, 0, -3_600_000_000).justify_interval(),
+        Some(interval(0, 29, 82_800_000_000))
+    );
+    assert_eq!(interval(0, i32::MAX, MICROS_PER_DAY).justify_hours(), None);
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_pginterval_add_chrono() {
+    use chrono::{FixedOffset, NaiveDate, TimeZone};
+
+    let interval = |months, days, microseconds| PgInterval {
+        months,
+        days,
+        microseconds,
+    };
+
+    let date_time = NaiveDate::from_ymd(2020, 1, 31).and_hms(12, 0, 0);
+
+    // the day of the month is kept within the month, and only then the days are added
+    assert_eq!(
+        interval(1, 1, 0).checked_add_naive_date_time(date_time),
+        Some(NaiveDate::from_ymd(2020, 3, 1).and_hms(12, 0, 0))
+    );
+    assert_eq!(
+        interval(-13, 0, -43_200_000_000).checked_add_naive_date_time(date_time),
+        Some(NaiveDate::from_ymd(2018, 12, 31).and_hms(0, 0, 0))
+    );
+    assert_eq!(
+        interval(i32::MAX, 0, 0).checked_add_naive_date_time(date_time),
+        None
+    );
+
+    let offset = FixedOffset::east(5 * 3600);
+    let date_time = offset.ymd(2021, 2, 28).and_hms(23, 0, 0);
+
+    assert_eq!(
+        interval(1, 0, 3_600_000_000).checked_add_date_time(&date_time),
+        Some(offset.ymd(2021, 3, 29).and_hms(0, 0, 0))
+    );
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_pginterval_add_time() {
+    use time::{date, offset, time};
+
+    let interval = |months, days, microseconds| PgInterval {
+        months,
+        days,
+        microseconds,
+    };
+
+    let date_time = date!(2020 - 01 - 31)
+        .with_time(time!(12:00))
+        .assume_offset(offset!(-3));
+
+    assert_eq!(
+        interval(1, 1, 0).checked_add_offset_date_time(date_time),
+        Some(
+            date!(2020 - 03 - 01)
+                .with_time(time!(12:00))
+                .assume_offset(offset!(-3))
+        )
+    );
+    assert_eq!(
+        interval(-13, 0, -43_200_000_000).checked_add_offset_date_time(date_time),
+        Some(
+            date!(2018 - 12 - 31)
+                .with_time(time!(00:00))
+                .assume_offset(offset!(-3))
+        )
+    );
+    assert_eq!(
+        interval(0, 0, 43_200_000_001).checked_add_offset_date_time(date_time),
+        Some(
+            date!(2020 - 02 - 01)
+                .with_time(time!(00:00:00.000_001))
+                .assume_offset(offset!(-3))
+        )
+    );
+    assert_eq!(
+        interval(i32::MAX, 0, 0).checked_add_offset_date_time(date_time),
+        None
+    );
+
+    assert_eq!(
+        time::Duration::try_from(interval(0, 1, 1_000)).unwrap(),
+        time::Duration::days(1) + time::Duration::milliseconds(1)
+    );
+    assert!(time::Duration::try_from(interval(1, 0, 0)).is_err());
+}
@@ -0,0 +1,187 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+/// A position in the write-ahead log, the Postgres [`PG_LSN`] type.
+///
+/// A log sequence number is a byte offset into the log. It is written as two hex numbers
+/// separated by a slash, the high and low 32 bits of the offset, like `16/B374D848`.
+///
+/// Adding or subtracting a number of bytes moves the position; subtracting two positions gives
+/// the number of bytes between them, as in Postgres.
+///
+/// [`PG_LSN`]: https://www.postgresql.org/docs/current/datatype-pg-lsn.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PgLsn(pub u64);
+
+impl PgLsn {
+    /// Moves the position forward by `bytes`, returning `None` on overflow.
+    pub fn checked_add(self, bytes: u64) -> Option<Self> {
+        self.0.checked_add(bytes).map(PgLsn)
+    }
+
+    /// Moves the position back by `bytes`, returning `None` on underflow.
+    pub fn checked_sub(self, bytes: u64) -> Option<Self> {
+        self.0.checked_sub(bytes).map(PgLsn)
+    }
+}
+
+impl From<u64> for PgLsn {
+    fn from(offset: u64) -> Self {
+        PgLsn(offset)
+    }
+}
+
+impl From<PgLsn> for u64 {
+    fn from(lsn: PgLsn) -> Self {
+        lsn.0
+    }
+}
+
+impl Add<u64> for PgLsn {
+    type Output = PgLsn;
+
+    /// Moves the position forward.
+    ///
+    /// # Panics
+    /// Panics on overflow.
+    fn add(self, bytes: u64) -> Self::Output {
+        self.checked_add(bytes)
+            .expect("overflow moving log sequence number forward")
+    }
+}
+
+impl AddAssign<u64> for PgLsn {
+    /// Moves the position forward.
+    ///
+    /// # Panics
+    /// Panics on overflow.
+    fn add_assign(&mut self, bytes: u64) {
+        *self = *self + bytes;
+    }
+}
+
+impl Sub<u64> for PgLsn {
+    type Output = PgLsn;
+
+    /// Moves the position back.
+    ///
+    /// # Panics
+    /// Panics on underflow.
+    fn sub(self, bytes: u64) -> Self::Output {
+        self.checked_sub(bytes)
+            .expect("overflow moving log sequence number back")
+    }
+}
+
+impl SubAssign<u64> for PgLsn {
+    /// Moves the position back.
+    ///
+    /// # Panics
+    /// Panics on underflow.
+    fn sub_assign(&mut self, bytes: u64) {
+        *self = *self - bytes;
+    }
+}
+
+impl Sub<PgLsn> for PgLsn {
+    type Output = i128;
+
+    /// Returns the number of bytes between two positions, which is negative when `rhs` is
+    /// ahead of `self`.
+    fn sub(self, rhs: PgLsn) -> Self::Output {
+        i128::from(self.0) - i128::from(rhs.0)
+    }
+}
+
+impl Display for PgLsn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:X}/{:X}", self.0 >> 32, self.0 as u32)
+    }
+}
+
+impl FromStr for PgLsn {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (high, low) = match s.find('/') {
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => return Err(format!("invalid log sequence number {:?}", s).into()),
+        };
+
+        let high = u32::from_str_radix(high, 16)?;
+        let low = u32::from_str_radix(low, 16)?;
+
+        Ok(PgLsn(u64::from(high) << 32 | u64::from(low)))
+    }
+}
+
+impl Type<Postgres> for PgLsn {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::PG_LSN
+    }
+}
+
+impl Type<Postgres> for [PgLsn] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::PG_LSN_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<PgLsn> {
+    fn type_info() -> PgTypeInfo {
+        <[PgLsn] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for PgLsn {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&self.0.to_be_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl Decode<'_, Postgres> for PgLsn {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => Ok(PgLsn(BigEndian::read_u64(value.as_bytes()?))),
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+#[test]
+fn test_parse_lsn() {
+    let lsn: PgLsn = "16/B374D848".parse().unwrap();
+
+    assert_eq!(lsn, PgLsn(0x16_B374_D848));
+    assert_eq!(lsn.to_string(), "16/B374D848");
+    assert_eq!(PgLsn(0).to_string(), "0/0");
+
+    assert!("16B374D848".parse::<PgLsn>().is_err());
+    assert!("16/".parse::<PgLsn>().is_err());
+}
+
+#[test]
+fn test_lsn_arithmetic() {
+    let lsn = PgLsn(0xFFFF_FFFF);
+
+    assert_eq!((lsn + 1).to_string(), "1/0");
+    assert_eq!(lsn + 1 - lsn, 1);
+    assert_eq!(lsn - (lsn + 1), -1);
+    assert_eq!(lsn.checked_sub(lsn.0 + 1), None);
+    assert_eq!(PgLsn(u64::MAX).checked_add(1), None);
+}
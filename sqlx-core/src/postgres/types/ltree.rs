@@ -0,0 +1,201 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/ltree.html
+
+// `ltree` and `lquery` are defined by an extension, so their OIDs are looked up by name;
+// their binary format is a version number followed by the text format
+const VERSION: u8 = 1;
+
+/// An error when parsing a label path.
+#[derive(Debug, thiserror::Error)]
+pub enum PgLTreeParseError {
+    /// A label was empty or contained a character other than letters, digits, `_` and `-`.
+    #[error("invalid ltree label {0:?}")]
+    InvalidLabel(String),
+}
+
+/// A path of labels in a tree, the `ltree` type of the `ltree` extension, such as
+/// `Top.Science.Astronomy`.
+///
+/// Requires Postgres 13 or later, whose `ltree` extension has a binary format.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PgLTree {
+    labels: Vec<String>,
+}
+
+impl PgLTree {
+    /// Creates an empty path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a path from its labels.
+    pub fn from_labels<I, S>(labels: I) -> Result<Self, PgLTreeParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut tree = Self::new();
+
+        for label in labels {
+            tree.push(label)?;
+        }
+
+        Ok(tree)
+    }
+
+    /// Appends a label to the path.
+    pub fn push(&mut self, label: impl Into<String>) -> Result<(), PgLTreeParseError> {
+        let label = label.into();
+
+        let is_valid = !label.is_empty()
+            && label
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+
+        if !is_valid {
+            return Err(PgLTreeParseError::InvalidLabel(label));
+        }
+
+        self.labels.push(label);
+
+        Ok(())
+    }
+
+    /// Removes the last label of the path and returns it.
+    pub fn pop(&mut self) -> Option<String> {
+        self.labels.pop()
+    }
+
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+}
+
+impl FromStr for PgLTree {
+    type Err = PgLTreeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::new());
+        }
+
+        Self::from_labels(s.split('.'))
+    }
+}
+
+impl Display for PgLTree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.labels.join("."))
+    }
+}
+
+/// A pattern for matching label paths, the `lquery` type of the `ltree` extension, such as
+/// `*.Astronomy.*`.
+///
+/// The pattern is validated by Postgres.
+///
+/// Requires Postgres 13 or later, whose `ltree` extension has a binary format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PgLQuery(String);
+
+impl PgLQuery {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for PgLQuery {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("an lquery can not be empty".into());
+        }
+
+        Ok(PgLQuery(s.to_owned()))
+    }
+}
+
+impl Display for PgLQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Type<Postgres> for PgLTree {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("ltree")
+    }
+}
+
+impl Type<Postgres> for PgLQuery {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("lquery")
+    }
+}
+
+impl Encode<'_, Postgres> for PgLTree {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.push(VERSION);
+        buf.extend(self.to_string().as_bytes());
+
+        IsNull::No
+    }
+}
+
+impl Encode<'_, Postgres> for PgLQuery {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.push(VERSION);
+        buf.extend(self.0.as_bytes());
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Postgres> for PgLTree {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(decode_versioned_text(value)?.parse()?)
+    }
+}
+
+impl Decode<'_, Postgres> for PgLQuery {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        decode_versioned_text(value)?.parse()
+    }
+}
+
+fn decode_versioned_text(value: PgValueRef<'_>) -> Result<&str, BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => {
+            let buf = value.as_bytes()?;
+
+            match buf.split_first() {
+                Some((&VERSION, text)) => Ok(std::str::from_utf8(text)?),
+                Some((version, _)) => Err(format!("unsupported ltree version {}", version).into()),
+                None => Err("unexpected empty ltree value".into()),
+            }
+        }
+
+        PgValueFormat::Text => value.as_str(),
+    }
+}
+
+#[test]
+fn test_parse_ltree() {
+    let tree: PgLTree = "Top.Science.Astronomy".parse().unwrap();
+
+    assert_eq!(tree.labels(), ["Top", "Science", "Astronomy"]);
+    assert_eq!(tree.to_string(), "Top.Science.Astronomy");
+
+    assert!("".parse::<PgLTree>().unwrap().labels().is_empty());
+    assert!("Top..Science".parse::<PgLTree>().is_err());
+    assert!("Top.Sci ence".parse::<PgLTree>().is_err());
+}
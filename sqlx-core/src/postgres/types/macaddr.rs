@@ -0,0 +1,220 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/datatype-net-types.html#DATATYPE-MACADDR
+
+/// A 6-byte MAC address, the Postgres `MACADDR` type.
+///
+/// It is written and parsed as six pairs of hex digits separated by colons, like
+/// `08:00:2b:01:02:03`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PgMacAddr(pub [u8; 6]);
+
+/// An 8-byte MAC address in EUI-64 format, the Postgres `MACADDR8` type.
+///
+/// It is written and parsed as eight pairs of hex digits separated by colons, like
+/// `08:00:2b:01:02:03:04:05`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PgMacAddr8(pub [u8; 8]);
+
+impl From<PgMacAddr> for PgMacAddr8 {
+    /// Converts a 6-byte address to EUI-64 by inserting `ff:fe` in the middle, like
+    /// Postgres does when casting `macaddr` to `macaddr8`.
+    fn from(addr: PgMacAddr) -> Self {
+        let [a, b, c, d, e, f] = addr.0;
+
+        PgMacAddr8([a, b, c, 0xff, 0xfe, d, e, f])
+    }
+}
+
+impl Display for PgMacAddr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_hex_pairs(&self.0, f)
+    }
+}
+
+impl Display for PgMacAddr8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_hex_pairs(&self.0, f)
+    }
+}
+
+impl FromStr for PgMacAddr {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut addr = [0; 6];
+        parse_hex_pairs(s, &mut addr)?;
+
+        Ok(PgMacAddr(addr))
+    }
+}
+
+impl FromStr for PgMacAddr8 {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut addr = [0; 8];
+        parse_hex_pairs(s, &mut addr)?;
+
+        Ok(PgMacAddr8(addr))
+    }
+}
+
+impl Type<Postgres> for PgMacAddr {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::MACADDR
+    }
+}
+
+impl Type<Postgres> for [PgMacAddr] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::MACADDR_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<PgMacAddr> {
+    fn type_info() -> PgTypeInfo {
+        <[PgMacAddr] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Type<Postgres> for PgMacAddr8 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::MACADDR8
+    }
+}
+
+impl Type<Postgres> for [PgMacAddr8] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::MACADDR8_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<PgMacAddr8> {
+    fn type_info() -> PgTypeInfo {
+        <[PgMacAddr8] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for PgMacAddr {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&self.0);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        6
+    }
+}
+
+impl Decode<'_, Postgres> for PgMacAddr {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut addr = [0; 6];
+                copy_bytes(value.as_bytes()?, &mut addr)?;
+
+                Ok(PgMacAddr(addr))
+            }
+
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+impl Encode<'_, Postgres> for PgMacAddr8 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&self.0);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl Decode<'_, Postgres> for PgMacAddr8 {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut addr = [0; 8];
+                copy_bytes(value.as_bytes()?, &mut addr)?;
+
+                Ok(PgMacAddr8(addr))
+            }
+
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+fn copy_bytes(bytes: &[u8], addr: &mut [u8]) -> Result<(), BoxDynError> {
+    if bytes.len() != addr.len() {
+        return Err(format!(
+            "expected a MAC address of {} bytes, got {} bytes",
+            addr.len(),
+            bytes.len()
+        )
+        .into());
+    }
+
+    addr.copy_from_slice(bytes);
+
+    Ok(())
+}
+
+fn fmt_hex_pairs(addr: &[u8], f: &mut Formatter<'_>) -> fmt::Result {
+    for (i, byte) in addr.iter().enumerate() {
+        if i > 0 {
+            f.write_str(":")?;
+        }
+
+        write!(f, "{:02x}", byte)?;
+    }
+
+    Ok(())
+}
+
+fn parse_hex_pairs(s: &str, addr: &mut [u8]) -> Result<(), BoxDynError> {
+    let mut parts = s.split(':');
+
+    for byte in addr.iter_mut() {
+        let part = parts
+            .next()
+            .filter(|part| part.len() == 2)
+            .ok_or_else(|| format!("invalid MAC address {:?}", s))?;
+
+        *byte = u8::from_str_radix(part, 16)?;
+    }
+
+    if parts.next().is_some() {
+        return Err(format!("invalid MAC address {:?}", s).into());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_macaddr() {
+    let addr: PgMacAddr = "08:00:2b:01:02:03".parse().unwrap();
+
+    assert_eq!(addr, PgMacAddr([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03]));
+    assert_eq!(addr.to_string(), "08:00:2b:01:02:03");
+    assert_eq!(
+        PgMacAddr8::from(addr).to_string(),
+        "08:00:2b:ff:fe:01:02:03"
+    );
+
+    assert!("08:00:2b:01:02".parse::<PgMacAddr>().is_err());
+    assert!("08:00:2b:01:02:03:04".parse::<PgMacAddr>().is_err());
+    assert!("08:00:2b:01:02:zz".parse::<PgMacAddr>().is_err());
+}
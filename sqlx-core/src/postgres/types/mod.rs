@@ -9,17 +9,65 @@
 //! | `i16`                                 | SMALLINT, SMALLSERIAL, INT2                          |
 //! | `i32`                                 | INT, SERIAL, INT4                                    |
 //! | `i64`                                 | BIGINT, BIGSERIAL, INT8                              |
+//! | `u32`, [`Oid`]                        | OID                                                  |
 //! | `f32`                                 | REAL, FLOAT4                                         |
 //! | `f64`                                 | DOUBLE PRECISION, FLOAT8                             |
 //! | `&str`, `String`                      | VARCHAR, CHAR(N), TEXT, NAME                         |
 //! | `&[u8]`, `Vec<u8>`                    | BYTEA                                                |
 //! | [`PgInterval`]                        | INTERVAL                                             |
 //! | [`PgRange<T>`]                        | INT8RANGE, INT4RANGE, TSRANGE, TSTZTRANGE, DATERANGE, NUMRANGE |
+//! | [`PgMultirange<T>`]                   | INT8MULTIRANGE, INT4MULTIRANGE, TSMULTIRANGE, TSTZMULTIRANGE, DATEMULTIRANGE, NUMMULTIRANGE |
 //! | [`PgMoney`]                           | MONEY                                                |
+//! | [`PgPoint`]                           | POINT                                                |
+//! | [`PgLine`]                            | LINE                                                 |
+//! | [`PgLseg`]                            | LSEG                                                 |
+//! | [`PgBox`]                             | BOX                                                  |
+//! | [`PgPath`]                            | PATH                                                 |
+//! | [`PgPolygon`]                         | POLYGON                                              |
+//! | [`PgCircle`]                          | CIRCLE                                               |
+//! | [`PgTsVector`]                        | TSVECTOR                                             |
+//! | [`PgTsQuery`]                         | TSQUERY                                              |
+//! | [`PgMacAddr`]                         | MACADDR                                              |
+//! | [`PgMacAddr8`]                        | MACADDR8                                             |
+//! | [`PgLsn`]                             | PG_LSN                                               |
+//! | [`PgXml`]                             | XML                                                  |
+//!
+//! # Extension types
+//!
+//! Types of the extensions included with Postgres. Their OIDs differ between databases, so
+//! they are looked up by name when first used and cached by the connection.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `HashMap<String, Option<String>>`     | HSTORE                                               |
+//! | [`PgLTree`]                           | LTREE                                                |
+//! | [`PgLQuery`]                          | LQUERY                                               |
+//! | `&str`, `String`                      | CITEXT                                               |
+//!
+//! `LTREE` and `LQUERY` are sent in their binary format, which requires version 1.2 of the
+//! `ltree` extension, included with Postgres 13 or later.
+//!
+//! [`PgLTree`]: struct.PgLTree.html
+//! [`PgLQuery`]: struct.PgLQuery.html
 //!
 //! [`PgInterval`]: struct.PgInterval.html
 //! [`PgRange<T>`]: struct.PgRange.html
+//! [`PgMultirange<T>`]: struct.PgMultirange.html
 //! [`PgMoney`]: struct.PgMoney.html
+//! [`PgPoint`]: struct.PgPoint.html
+//! [`PgLine`]: struct.PgLine.html
+//! [`PgLseg`]: struct.PgLseg.html
+//! [`PgBox`]: struct.PgBox.html
+//! [`PgPath`]: struct.PgPath.html
+//! [`PgPolygon`]: struct.PgPolygon.html
+//! [`PgCircle`]: struct.PgCircle.html
+//! [`PgTsVector`]: struct.PgTsVector.html
+//! [`PgTsQuery`]: struct.PgTsQuery.html
+//! [`PgMacAddr`]: struct.PgMacAddr.html
+//! [`PgMacAddr8`]: struct.PgMacAddr8.html
+//! [`PgLsn`]: struct.PgLsn.html
+//! [`PgXml`]: struct.PgXml.html
+//! [`Oid`]: struct.Oid.html
 //!
 //! ### [`bigdecimal`](https://crates.io/crates/bigdecimal)
 //! Requires the `bigdecimal` Cargo feature flag.
@@ -58,6 +106,7 @@
 //! | `time::OffsetDateTime`                | TIMESTAMPTZ                                          |
 //! | `time::Date`                          | DATE                                                 |
 //! | `time::Time`                          | TIME                                                 |
+//! | `time::Duration`                      | INTERVAL                                             |
 //! | [`PgTimeTz`]                          | TIMETZ                                               |
 //!
 //! [`PgTimeTz`]: struct.PgTimeTz.html
@@ -86,6 +135,38 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `bit_vec::BitVec`                     | BIT, VARBIT                                          |
 //!
+//! ### [`geo-types`](https://crates.io/crates/geo-types)
+//!
+//! Requires the `geo-types` Cargo feature flag.
+//!
+//! The geometric types convert to and from their counterparts in `geo_types`:
+//!
+//! | Postgres type                         | `geo_types` type                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | [`PgPoint`]                           | `Point<f64>`, `Coordinate<f64>`                      |
+//! | [`PgLseg`]                            | `Line<f64>`                                          |
+//! | [`PgBox`]                             | `Rect<f64>`                                          |
+//! | [`PgPath`]                            | `LineString<f64>`                                    |
+//! | [`PgPolygon`]                         | `Polygon<f64>` (without interior rings)              |
+//!
+//! ### [PostGIS](https://postgis.net)
+//!
+//! Requires the `postgis` Cargo feature flag and the `postgis` extension in the database.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `geo_types::Geometry<f64>`            | GEOMETRY, GEOGRAPHY                                  |
+//! | `geo_types::Point<f64>`, etc.         | GEOMETRY, GEOGRAPHY                                  |
+//! | [`PgGeometry<T>`]                     | GEOMETRY                                             |
+//! | [`PgGeography<T>`]                    | GEOGRAPHY                                            |
+//!
+//! The geometries of `geo_types` are `Point`, `LineString`, `Polygon`, `MultiPoint`,
+//! `MultiLineString`, `MultiPolygon` and `GeometryCollection`. Their SRID is kept by
+//! [`PgGeometry<T>`] and [`PgGeography<T>`], where `T` is one of them.
+//!
+//! [`PgGeometry<T>`]: struct.PgGeometry.html
+//! [`PgGeography<T>`]: struct.PgGeography.html
+//!
 //! ### [`json`](https://crates.io/crates/serde_json)
 //!
 //! Requires the `json` Cargo feature flag.
@@ -125,9 +206,36 @@
 //! }
 //! ```
 //!
+//! A field may be another derived composite or enumeration, a `Vec` of them, or an `Option` for
+//! an attribute that can be `NULL`.
+//!
 //! Anonymous composite types are represented as tuples. Note that anonymous composites may only
 //! be returned and not sent to Postgres (this is a limitation of postgres).
 //!
+//! # [Range types](https://www.postgresql.org/docs/current/rangetypes.html)
+//!
+//! [`PgRange<T>`] and [`PgMultirange<T>`] on their own only map to the built-in range and
+//! multirange types listed above. Resolving a user-defined range type for a bare `PgRange<T>` is
+//! not supported, as the type carries no name to look up.
+//!
+//! Instead, derive `Type` on a newtype of [`PgRange<T>`] or [`PgMultirange<T>`] and name the
+//! Postgres type. Its OID is then looked up by name when first used, the same as for any other
+//! user-defined type.
+//!
+//! ```text
+//! CREATE TYPE float_range AS RANGE (subtype = float8);
+//! ```
+//!
+//! ```rust,ignore
+//! #[derive(sqlx::Type)]
+//! #[sqlx(rename = "float_range")]
+//! struct FloatRange(PgRange<f64>);
+//!
+//! #[derive(sqlx::Type)]
+//! #[sqlx(rename = "float_multirange")]
+//! struct FloatMultirange(PgMultirange<f64>);
+//! ```
+//!
 //! # Arrays
 //!
 //! One-dimensional arrays are supported as `Vec<T>` or `&[T]` where `T` implements `Type`.
@@ -165,14 +273,23 @@ mod array;
 mod bool;
 mod bytes;
 mod float;
+mod geometry;
+mod hstore;
 mod int;
 mod interval;
+mod lsn;
+mod ltree;
+mod macaddr;
 mod money;
+mod multirange;
+mod oid;
 mod range;
 mod record;
 mod str;
+mod text_search;
 mod tuple;
 mod void;
+mod xml;
 
 #[cfg(any(feature = "chrono", feature = "time"))]
 mod time_tz;
@@ -204,23 +321,38 @@ mod ipnetwork;
 #[cfg(feature = "bit-vec")]
 mod bit_vec;
 
+#[cfg(feature = "postgis")]
+mod postgis;
+
+pub use array::PgHasArrayType;
+pub use geometry::{PgBox, PgCircle, PgLine, PgLseg, PgPath, PgPoint, PgPolygon};
 pub use interval::PgInterval;
+pub use lsn::PgLsn;
+pub use ltree::{PgLQuery, PgLTree, PgLTreeParseError};
+pub use macaddr::{PgMacAddr, PgMacAddr8};
 pub use money::PgMoney;
+pub use multirange::PgMultirange;
+pub use oid::Oid;
 pub use range::PgRange;
+pub use text_search::{PgTsLexeme, PgTsPosition, PgTsQuery, PgTsQueryNode, PgTsVector, PgTsWeight};
+pub use xml::PgXml;
 
 #[cfg(any(feature = "chrono", feature = "time"))]
 pub use time_tz::PgTimeTz;
 
+#[cfg(feature = "postgis")]
+pub use postgis::{PgGeography, PgGeometry};
+
 // used in derive(Type) for `struct`
 // but the interface is not considered part of the public API
 #[doc(hidden)]
 pub use record::{PgRecordDecoder, PgRecordEncoder};
 
 // Type::compatible impl appropriate for arrays
-fn array_compatible<E: Type<Postgres>>(ty: &PgTypeInfo) -> bool {
+fn array_compatible<E: Type<Postgres> + ?Sized>(ty: &PgTypeInfo) -> bool {
     // we require the declared type to be an _array_ with an
     // element type that is acceptable
-    if let PgTypeKind::Array(element) = &ty.kind() {
+    if let PgTypeKind::Array(element) = ty.base_type().kind() {
         return E::compatible(&element);
     }
 
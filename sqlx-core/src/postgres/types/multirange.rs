@@ -0,0 +1,381 @@
+use std::fmt::{self, Display, Formatter};
+
+use bytes::Buf;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::type_info::PgTypeKind;
+use crate::postgres::types::range::range_compatible;
+use crate::postgres::types::PgRange;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+/// A set of ranges, the Postgres [multirange] types added in Postgres 14.
+///
+/// Postgres sorts the ranges of a multirange and merges those that overlap or touch, so the
+/// value read back may not be the one that was written.
+///
+/// [multirange]: https://www.postgresql.org/docs/current/rangetypes.html
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PgMultirange<T> {
+    pub ranges: Vec<PgRange<T>>,
+}
+
+impl<T> Default for PgMultirange<T> {
+    fn default() -> Self {
+        PgMultirange { ranges: Vec::new() }
+    }
+}
+
+impl<T> From<Vec<PgRange<T>>> for PgMultirange<T> {
+    fn from(ranges: Vec<PgRange<T>>) -> Self {
+        PgMultirange { ranges }
+    }
+}
+
+impl Type<Postgres> for PgMultirange<i32> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT4_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<i32>(ty)
+    }
+}
+
+impl Type<Postgres> for PgMultirange<i64> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT8_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<i64>(ty)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl Type<Postgres> for PgMultirange<bigdecimal::BigDecimal> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUM_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<bigdecimal::BigDecimal>(ty)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for PgMultirange<chrono::NaiveDate> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<chrono::NaiveDate>(ty)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for PgMultirange<chrono::NaiveDateTime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<chrono::NaiveDateTime>(ty)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Type<Postgres> for PgMultirange<chrono::DateTime<Tz>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<chrono::DateTime<Tz>>(ty)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for PgMultirange<time::Date> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<time::Date>(ty)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for PgMultirange<time::PrimitiveDateTime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<time::PrimitiveDateTime>(ty)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for PgMultirange<time::OffsetDateTime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<time::OffsetDateTime>(ty)
+    }
+}
+
+impl Type<Postgres> for [PgMultirange<i32>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT4_MULTIRANGE_ARRAY
+    }
+}
+
+impl Type<Postgres> for [PgMultirange<i64>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT8_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl Type<Postgres> for [PgMultirange<bigdecimal::BigDecimal>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUM_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for [PgMultirange<chrono::NaiveDate>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for [PgMultirange<chrono::NaiveDateTime>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Type<Postgres> for [PgMultirange<chrono::DateTime<Tz>>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for [PgMultirange<time::Date>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for [PgMultirange<time::PrimitiveDateTime>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for [PgMultirange<time::OffsetDateTime>] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<PgMultirange<i32>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT4_MULTIRANGE_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<PgMultirange<i64>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT8_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl Type<Postgres> for Vec<PgMultirange<bigdecimal::BigDecimal>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUM_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for Vec<PgMultirange<chrono::NaiveDate>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for Vec<PgMultirange<chrono::NaiveDateTime>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Type<Postgres> for Vec<PgMultirange<chrono::DateTime<Tz>>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for Vec<PgMultirange<time::Date>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for Vec<PgMultirange<time::PrimitiveDateTime>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for Vec<PgMultirange<time::OffsetDateTime>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE_ARRAY
+    }
+}
+
+impl<'q, T> Encode<'q, Postgres> for PgMultirange<T>
+where
+    T: Encode<'q, Postgres>,
+{
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // https://github.com/postgres/postgres/blob/REL_14_0/src/backend/utils/adt/multirangetypes.c
+
+        buf.extend(&(self.ranges.len() as i32).to_be_bytes());
+
+        for range in &self.ranges {
+            buf.encode(range);
+        }
+
+        IsNull::No
+    }
+}
+
+impl<'r, T> Decode<'r, Postgres> for PgMultirange<T>
+where
+    T: Type<Postgres> + for<'a> Decode<'a, Postgres>,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.format {
+            PgValueFormat::Binary => {
+                let range_ty =
+                    if let PgTypeKind::Multirange(range) = value.type_info.base_type().kind() {
+                        range.clone()
+                    } else {
+                        return Err(
+                            format!("unexpected non-multirange type {}", value.type_info).into(),
+                        );
+                    };
+
+                let mut buf = value.as_bytes()?;
+
+                let len = buf.get_i32();
+                if len < 0 {
+                    return Err(format!("invalid number of ranges: {}", len).into());
+                }
+
+                let mut ranges = Vec::with_capacity(len as usize);
+
+                for _ in 0..len {
+                    ranges.push(PgRange::decode(PgValueRef::get(
+                        &mut buf,
+                        value.format,
+                        range_ty.clone(),
+                    ))?);
+                }
+
+                Ok(PgMultirange { ranges })
+            }
+
+            PgValueFormat::Text => {
+                let s = value.as_str()?;
+
+                let inner = s
+                    .strip_prefix('{')
+                    .and_then(|s| s.strip_suffix('}'))
+                    .ok_or_else(|| format!("invalid multirange literal {:?}", s))?;
+
+                let mut ranges = Vec::new();
+
+                // each range runs from its opening to its closing bracket; a bracket inside
+                // quotes is part of a bound
+                let mut start = None;
+                let mut in_quotes = false;
+                let mut in_escape = false;
+
+                for (i, ch) in inner.char_indices() {
+                    match ch {
+                        _ if in_escape => in_escape = false,
+                        '\\' => in_escape = true,
+                        '"' => in_quotes = !in_quotes,
+                        _ if in_quotes => {}
+                        '[' | '(' if start.is_none() => start = Some(i),
+
+                        ']' | ')' => {
+                            if let Some(start) = start.take() {
+                                ranges.push(PgRange::decode(PgValueRef {
+                                    type_info: value.type_info.clone(),
+                                    format: PgValueFormat::Text,
+                                    value: Some(&inner.as_bytes()[start..=i]),
+                                    row: None,
+                                })?);
+                            }
+                        }
+
+                        _ => {}
+                    }
+                }
+
+                Ok(PgMultirange { ranges })
+            }
+        }
+    }
+}
+
+impl<T> Display for PgMultirange<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+
+            Display::fmt(range, f)?;
+        }
+
+        f.write_str("}")
+    }
+}
+
+fn multirange_compatible<E: Type<Postgres>>(ty: &PgTypeInfo) -> bool {
+    // we require the declared type to be a _multirange_ of a range with an
+    // element type that is acceptable
+    if let PgTypeKind::Multirange(range) = ty.base_type().kind() {
+        return range_compatible::<E>(range);
+    }
+
+    false
+}
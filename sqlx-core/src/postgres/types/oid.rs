@@ -0,0 +1,71 @@
+use std::fmt::{self, Display, Formatter};
+
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+/// An object identifier, the Postgres [`OID`] type.
+///
+/// Postgres uses object identifiers as the keys of its system tables, such as the `oid` of
+/// `pg_type`. Unlike `u32`, which also maps to `OID`, this type is not mistaken for a number.
+///
+/// [`OID`]: https://www.postgresql.org/docs/current/datatype-oid.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Oid(pub u32);
+
+impl From<u32> for Oid {
+    fn from(oid: u32) -> Self {
+        Oid(oid)
+    }
+}
+
+impl From<Oid> for u32 {
+    fn from(oid: Oid) -> Self {
+        oid.0
+    }
+}
+
+impl Display for Oid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Type<Postgres> for Oid {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::OID
+    }
+}
+
+impl Type<Postgres> for [Oid] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::OID_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<Oid> {
+    fn type_info() -> PgTypeInfo {
+        <[Oid] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for Oid {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&self.0.to_be_bytes());
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Postgres> for Oid {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(Oid(match value.format() {
+            PgValueFormat::Binary => BigEndian::read_u32(value.as_bytes()?),
+            PgValueFormat::Text => value.as_str()?.parse()?,
+        }))
+    }
+}
@@ -0,0 +1,157 @@
+use geo_types::{
+    Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+use std::convert::TryFrom;
+
+use crate::common::wkb;
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://postgis.net/docs/using_postgis_dbmanagement.html#EWKB_EWKT
+
+// PostGIS sends and receives geometries as extended WKB, which is written as hex in the text
+// format; `geometry` and `geography` are defined by the extension, so their OIDs are looked
+// up by name
+
+/// A PostGIS `geometry` with its spatial reference system identifier (SRID).
+///
+/// `T` is `Geometry<f64>` or one of the geometries of `geo_types`, such as `Point<f64>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgGeometry<T = Geometry<f64>> {
+    pub geometry: T,
+    pub srid: Option<u32>,
+}
+
+/// A PostGIS `geography` with its spatial reference system identifier (SRID).
+///
+/// PostGIS assumes WGS 84 (SRID 4326) for a geography without an SRID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgGeography<T = Geometry<f64>> {
+    pub geography: T,
+    pub srid: Option<u32>,
+}
+
+fn encode_ewkb<T: wkb::Encode>(
+    geometry: &T,
+    srid: Option<u32>,
+    buf: &mut PgArgumentBuffer,
+) -> IsNull {
+    wkb::encode(geometry, srid, buf);
+
+    IsNull::No
+}
+
+fn decode_ewkb(value: PgValueRef<'_>) -> Result<(Geometry<f64>, Option<u32>), BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => wkb::decode(value.as_bytes()?),
+        PgValueFormat::Text => wkb::decode(&hex::decode(value.as_str()?)?),
+    }
+}
+
+fn geometry_collection(geometry: Geometry<f64>) -> Result<GeometryCollection<f64>, Geometry<f64>> {
+    match geometry {
+        Geometry::GeometryCollection(collection) => Ok(collection),
+        geometry => Err(geometry),
+    }
+}
+
+macro_rules! impl_postgis {
+    ($ty:ident, $from_geometry:expr) => {
+        impl Type<Postgres> for $ty<f64> {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::with_name("geometry")
+            }
+
+            fn compatible(ty: &PgTypeInfo) -> bool {
+                *ty == PgTypeInfo::with_name("geometry") || *ty == PgTypeInfo::with_name("geography")
+            }
+        }
+
+        impl Encode<'_, Postgres> for $ty<f64> {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+                encode_ewkb(self, None, buf)
+            }
+        }
+
+        impl Decode<'_, Postgres> for $ty<f64> {
+            fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+                let (geometry, _) = decode_ewkb(value)?;
+
+                $from_geometry(geometry).map_err(|_| {
+                    concat!("PostGIS value is not a ", stringify!($ty)).into()
+                })
+            }
+        }
+
+        impl Type<Postgres> for PgGeometry<$ty<f64>> {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::with_name("geometry")
+            }
+        }
+
+        impl Encode<'_, Postgres> for PgGeometry<$ty<f64>> {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+                encode_ewkb(&self.geometry, self.srid, buf)
+            }
+        }
+
+        impl Decode<'_, Postgres> for PgGeometry<$ty<f64>> {
+            fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+                let (geometry, srid) = decode_ewkb(value)?;
+
+                let geometry = $from_geometry(geometry).map_err(|_| {
+                    concat!("PostGIS geometry is not a ", stringify!($ty))
+                })?;
+
+                Ok(PgGeometry { geometry, srid })
+            }
+        }
+
+        impl Type<Postgres> for PgGeography<$ty<f64>> {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::with_name("geography")
+            }
+        }
+
+        impl Encode<'_, Postgres> for PgGeography<$ty<f64>> {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+                encode_ewkb(&self.geography, self.srid, buf)
+            }
+        }
+
+        impl Decode<'_, Postgres> for PgGeography<$ty<f64>> {
+            fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+                let (geometry, srid) = decode_ewkb(value)?;
+
+                let geography = $from_geometry(geometry).map_err(|_| {
+                    concat!("PostGIS geography is not a ", stringify!($ty))
+                })?;
+
+                Ok(PgGeography { geography, srid })
+            }
+        }
+    };
+
+    ($($ty:ident),*) => {
+        $(
+            impl_postgis!($ty, $ty::try_from);
+        )*
+    };
+}
+
+impl_postgis!(Geometry, Ok::<_, Geometry<f64>>);
+
+impl_postgis!(GeometryCollection, geometry_collection);
+
+impl_postgis!(
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon
+);
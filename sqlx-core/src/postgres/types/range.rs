@@ -373,11 +373,12 @@ where
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
         match value.format {
             PgValueFormat::Binary => {
-                let element_ty = if let PgTypeKind::Range(element) = &value.type_info.0.kind() {
-                    element
-                } else {
-                    return Err(format!("unexpected non-range type {}", value.type_info).into());
-                };
+                let element_ty =
+                    if let PgTypeKind::Range(element) = value.type_info.base_type().kind() {
+                        element
+                    } else {
+                        return Err(format!("unexpected non-range type {}", value.type_info).into());
+                    };
 
                 let mut buf = value.as_bytes()?;
 
@@ -435,15 +436,17 @@ where
 
                 let mut element = String::new();
                 let mut done = false;
-                let mut quoted = false;
                 let mut in_quotes = false;
                 let mut in_escape = false;
-                let mut prev_ch = '\0';
                 let mut count = 0;
 
                 while !done {
                     element.clear();
 
+                    // quotes are tracked per bound
+                    let mut quoted = false;
+                    let mut prev_ch = '\0';
+
                     loop {
                         match chars.next() {
                             Some(ch) => {
@@ -554,10 +557,10 @@ where
     }
 }
 
-fn range_compatible<E: Type<Postgres>>(ty: &PgTypeInfo) -> bool {
+pub(super) fn range_compatible<E: Type<Postgres>>(ty: &PgTypeInfo) -> bool {
     // we require the declared type to be a _range_ with an
     // element type that is acceptable
-    if let PgTypeKind::Range(element) = &ty.kind() {
+    if let PgTypeKind::Range(element) = ty.base_type().kind() {
         return E::compatible(&element);
     }
 
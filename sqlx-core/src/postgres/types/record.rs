@@ -77,8 +77,9 @@ impl<'r> PgRecordDecoder<'r> {
             }
 
             PgValueFormat::Text => {
-                // remove the enclosing `(` .. `)`
-                buf = &buf[1..(buf.len() - 1)];
+                // remove the opening `(`; the closing `)` ends the last field, which tells an
+                // empty (NULL) last field apart from the end of the record
+                buf = &buf[1..];
             }
         }
 
@@ -102,13 +103,8 @@ impl<'r> PgRecordDecoder<'r> {
         match self.fmt {
             PgValueFormat::Binary => {
                 let element_type_oid = self.buf.get_u32();
-                let element_type_opt = match self.typ.0.kind() {
-                    PgTypeKind::Simple if self.typ.0 == PgType::Record => {
-                        PgTypeInfo::try_from_oid(element_type_oid)
-                    }
-
-                    PgTypeKind::Composite(fields) => {
-                        let ty = fields[self.ind].1.clone();
+                let element_type_opt = match self.field_type()? {
+                    Some(ty) => {
                         if ty.0.oid() != element_type_oid {
                             return Err("unexpected mismatch of composite type information".into());
                         }
@@ -116,12 +112,7 @@ impl<'r> PgRecordDecoder<'r> {
                         Some(ty)
                     }
 
-                    _ => {
-                        return Err(
-                            "unexpected non-composite type being decoded as a composite type"
-                                .into(),
-                        );
-                    }
+                    None => PgTypeInfo::try_from_oid(element_type_oid),
                 };
 
                 self.ind += 1;
@@ -139,6 +130,14 @@ impl<'r> PgRecordDecoder<'r> {
             }
 
             PgValueFormat::Text => {
+                let element_type = match self.field_type() {
+                    Ok(Some(ty)) => ty,
+
+                    // NOTE: We pass `0` as the type ID because we don't have a reasonable value
+                    //       we could use.
+                    _ => PgTypeInfo::with_oid(0),
+                };
+
                 let mut element = String::new();
                 let mut quoted = false;
                 let mut in_quotes = false;
@@ -170,7 +169,7 @@ impl<'r> PgRecordDecoder<'r> {
                             in_escape = true;
                         }
 
-                        ',' if !in_quotes => break,
+                        ',' | ')' if !in_quotes => break,
 
                         _ => {
                             element.push(ch);
@@ -179,6 +178,8 @@ impl<'r> PgRecordDecoder<'r> {
                     prev_ch = ch;
                 }
 
+                self.ind += 1;
+
                 let buf = if element.is_empty() && !quoted {
                     // completely empty input means NULL
                     None
@@ -190,9 +191,7 @@ impl<'r> PgRecordDecoder<'r> {
                 //       TEXT sequences are not strongly typed
 
                 T::decode(PgValueRef {
-                    // NOTE: We pass `0` as the type ID because we don't have a reasonable value
-                    //       we could use.
-                    type_info: PgTypeInfo::with_oid(0),
+                    type_info: element_type,
                     format: self.fmt,
                     value: buf,
                     row: None,
@@ -200,4 +199,24 @@ impl<'r> PgRecordDecoder<'r> {
             }
         }
     }
+
+    // the type of the next field of a composite type, as looked up from `pg_attribute` when the
+    // connection resolved the type; anonymous records and unresolved types only have the OIDs
+    // sent with each field
+    fn field_type(&self) -> Result<Option<PgTypeInfo>, BoxDynError> {
+        match &self.typ.base_type().0 {
+            PgType::Custom(ty) => match &ty.kind {
+                PgTypeKind::Composite(fields) => match fields.get(self.ind) {
+                    Some((_, ty)) => Ok(Some(ty.clone())),
+                    None => Err(format!("no field `{0}` found on record", self.ind).into()),
+                },
+
+                _ => Err("unexpected non-composite type being decoded as a composite type".into()),
+            },
+
+            PgType::Record | PgType::DeclareWithOid(_) | PgType::DeclareWithName(_) => Ok(None),
+
+            _ => Err("unexpected non-composite type being decoded as a composite type".into()),
+        }
+    }
 }
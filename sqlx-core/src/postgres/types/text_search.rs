@@ -0,0 +1,876 @@
+use std::fmt::{self, Display, Formatter, Write};
+use std::iter::Peekable;
+use std::str::{self, Chars, FromStr};
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/datatype-textsearch.html
+// https://github.com/postgres/postgres/blob/REL_13_STABLE/src/backend/utils/adt/tsvector.c
+// https://github.com/postgres/postgres/blob/REL_13_STABLE/src/backend/utils/adt/tsquery.c
+
+// the largest position of a lexeme in a document
+const MAX_POSITION: u16 = (1 << 14) - 1;
+
+// the kinds of items in the binary format of a `tsquery`
+const QUERY_VALUE: i8 = 1;
+const QUERY_OPERATOR: i8 = 2;
+
+// the operators in the binary format of a `tsquery`
+const OPERATOR_NOT: i8 = 1;
+const OPERATOR_AND: i8 = 2;
+const OPERATOR_OR: i8 = 3;
+const OPERATOR_PHRASE: i8 = 4;
+
+/// The weight of a lexeme, which marks the part of a document it came from.
+///
+/// `A` is the most important and `D`, the least important, is used when none is given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PgTsWeight {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl PgTsWeight {
+    const ALL: [PgTsWeight; 4] = [PgTsWeight::A, PgTsWeight::B, PgTsWeight::C, PgTsWeight::D];
+
+    // the two high bits of a position in a `tsvector`
+    fn to_position_bits(self) -> u16 {
+        match self {
+            PgTsWeight::A => 3,
+            PgTsWeight::B => 2,
+            PgTsWeight::C => 1,
+            PgTsWeight::D => 0,
+        }
+    }
+
+    fn from_position_bits(bits: u16) -> Self {
+        match bits & 3 {
+            3 => PgTsWeight::A,
+            2 => PgTsWeight::B,
+            1 => PgTsWeight::C,
+            _ => PgTsWeight::D,
+        }
+    }
+
+    // the bit in the weight mask of a lexeme in a `tsquery`
+    fn to_mask(self) -> u8 {
+        1 << self.to_position_bits()
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'A' => Some(PgTsWeight::A),
+            'B' => Some(PgTsWeight::B),
+            'C' => Some(PgTsWeight::C),
+            'D' => Some(PgTsWeight::D),
+            _ => None,
+        }
+    }
+
+    fn as_char(self) -> char {
+        match self {
+            PgTsWeight::A => 'A',
+            PgTsWeight::B => 'B',
+            PgTsWeight::C => 'C',
+            PgTsWeight::D => 'D',
+        }
+    }
+}
+
+/// A position of a lexeme in a document, along with its weight.
+///
+/// Postgres stores positions from 1 up to 16383; larger positions are stored as 16383.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PgTsPosition {
+    pub position: u16,
+    pub weight: PgTsWeight,
+}
+
+/// A normalized word of a document with its positions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgTsLexeme {
+    pub lexeme: String,
+    pub positions: Vec<PgTsPosition>,
+}
+
+/// A document prepared for full-text search, the Postgres `TSVECTOR` type.
+///
+/// Postgres sorts the lexemes and merges duplicates, so a `tsvector` read from the database
+/// may differ from the one that was written.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgTsVector {
+    pub lexemes: Vec<PgTsLexeme>,
+}
+
+/// A full-text search query, the Postgres `TSQUERY` type.
+///
+/// A query without lexemes, such as one made only of stop words, has no `root`. Such a query
+/// can be read from Postgres but not bound as a parameter, as Postgres does not accept it in
+/// the binary format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgTsQuery {
+    pub root: Option<PgTsQueryNode>,
+}
+
+/// A node in the operator tree of a [`PgTsQuery`].
+///
+/// [`PgTsQuery`]: struct.PgTsQuery.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PgTsQueryNode {
+    /// A lexeme to match, like `'cat':*AB`.
+    ///
+    /// A `prefix` lexeme matches any lexeme that starts with it. A lexeme with `weights`
+    /// only matches lexemes with one of those weights.
+    Lexeme {
+        lexeme: String,
+        prefix: bool,
+        weights: Vec<PgTsWeight>,
+    },
+
+    /// `!query`
+    Not(Box<PgTsQueryNode>),
+
+    /// `left & right`
+    And(Box<PgTsQueryNode>, Box<PgTsQueryNode>),
+
+    /// `left | right`
+    Or(Box<PgTsQueryNode>, Box<PgTsQueryNode>),
+
+    /// `left <distance> right`, where `right` follows `left` by exactly `distance` positions.
+    ///
+    /// `<->` is a distance of 1.
+    Phrase {
+        left: Box<PgTsQueryNode>,
+        right: Box<PgTsQueryNode>,
+        distance: u16,
+    },
+}
+
+impl PgTsQueryNode {
+    /// Creates a node which matches a lexeme exactly, with any weight.
+    pub fn lexeme(lexeme: impl Into<String>) -> Self {
+        PgTsQueryNode::Lexeme {
+            lexeme: lexeme.into(),
+            prefix: false,
+            weights: Vec::new(),
+        }
+    }
+
+    // the binding strength of the operator, as in `tsquery_op_priorities`
+    fn priority(&self) -> u8 {
+        match self {
+            PgTsQueryNode::Lexeme { .. } | PgTsQueryNode::Not(_) => 4,
+            PgTsQueryNode::Phrase { .. } => 3,
+            PgTsQueryNode::And(..) => 2,
+            PgTsQueryNode::Or(..) => 1,
+        }
+    }
+
+    fn count(&self) -> usize {
+        match self {
+            PgTsQueryNode::Lexeme { .. } => 1,
+            PgTsQueryNode::Not(node) => 1 + node.count(),
+
+            PgTsQueryNode::And(left, right)
+            | PgTsQueryNode::Or(left, right)
+            | PgTsQueryNode::Phrase { left, right, .. } => 1 + left.count() + right.count(),
+        }
+    }
+}
+
+impl From<PgTsQueryNode> for PgTsQuery {
+    fn from(root: PgTsQueryNode) -> Self {
+        PgTsQuery { root: Some(root) }
+    }
+}
+
+macro_rules! impl_type {
+    ($ty:ty, $type_info:ident, $array_type_info:ident) => {
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::$type_info
+            }
+        }
+
+        impl Type<Postgres> for [$ty] {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::$array_type_info
+            }
+        }
+
+        impl Type<Postgres> for Vec<$ty> {
+            fn type_info() -> PgTypeInfo {
+                <[$ty] as Type<Postgres>>::type_info()
+            }
+        }
+    };
+}
+
+impl_type!(PgTsVector, TSVECTOR, TSVECTOR_ARRAY);
+impl_type!(PgTsQuery, TSQUERY, TSQUERY_ARRAY);
+
+impl Encode<'_, Postgres> for PgTsVector {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&(self.lexemes.len() as i32).to_be_bytes());
+
+        for lexeme in &self.lexemes {
+            write_cstr(&lexeme.lexeme, buf);
+
+            // Postgres rejects positions that are out of order
+            let mut positions: Vec<_> = lexeme
+                .positions
+                .iter()
+                .map(|p| (p.position.min(MAX_POSITION), p.weight))
+                .collect();
+
+            positions.sort_by_key(|&(position, _)| position);
+            positions.dedup_by_key(|&mut (position, _)| position);
+
+            buf.extend(&(positions.len() as u16).to_be_bytes());
+
+            for (position, weight) in positions {
+                buf.extend(&(weight.to_position_bits() << 14 | position).to_be_bytes());
+            }
+        }
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Postgres> for PgTsVector {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+                let len = buf.read_i32::<NetworkEndian>()?;
+
+                if len < 0 {
+                    return Err(format!("invalid number of tsvector lexemes: {}", len).into());
+                }
+
+                let mut lexemes = Vec::with_capacity(len as usize);
+
+                for _ in 0..len {
+                    let lexeme = read_cstr(&mut buf)?.to_owned();
+                    let len = buf.read_u16::<NetworkEndian>()?;
+                    let mut positions = Vec::with_capacity(len as usize);
+
+                    for _ in 0..len {
+                        let bits = buf.read_u16::<NetworkEndian>()?;
+
+                        positions.push(PgTsPosition {
+                            position: bits & MAX_POSITION,
+                            weight: PgTsWeight::from_position_bits(bits >> 14),
+                        });
+                    }
+
+                    lexemes.push(PgTsLexeme { lexeme, positions });
+                }
+
+                Ok(PgTsVector { lexemes })
+            }
+
+            // 'a':1A,2 'cat':3
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+impl Encode<'_, Postgres> for PgTsQuery {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        match &self.root {
+            Some(root) => {
+                buf.extend(&(root.count() as i32).to_be_bytes());
+                write_query_node(root, buf);
+            }
+
+            None => {
+                buf.extend(&0_i32.to_be_bytes());
+            }
+        }
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Postgres> for PgTsQuery {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+                let len = buf.read_i32::<NetworkEndian>()?;
+
+                if len < 0 {
+                    return Err(format!("invalid number of tsquery items: {}", len).into());
+                }
+
+                if len == 0 {
+                    return Ok(PgTsQuery::default());
+                }
+
+                read_query_node(&mut buf).map(PgTsQuery::from)
+            }
+
+            // 'fat' & ( 'rat' | !'cat' ) <-> 'x':*AB
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+// the items of a `tsquery` are in prefix order, with the right operand of an operator
+// before its left operand
+fn write_query_node(node: &PgTsQueryNode, buf: &mut PgArgumentBuffer) {
+    match node {
+        PgTsQueryNode::Lexeme {
+            lexeme,
+            prefix,
+            weights,
+        } => {
+            let mask = weights
+                .iter()
+                .fold(0, |mask, weight| mask | weight.to_mask());
+
+            buf.push(QUERY_VALUE as u8);
+            buf.push(mask);
+            buf.push(*prefix as u8);
+            write_cstr(lexeme, buf);
+        }
+
+        PgTsQueryNode::Not(node) => {
+            buf.push(QUERY_OPERATOR as u8);
+            buf.push(OPERATOR_NOT as u8);
+            write_query_node(node, buf);
+        }
+
+        PgTsQueryNode::And(left, right) => {
+            buf.push(QUERY_OPERATOR as u8);
+            buf.push(OPERATOR_AND as u8);
+            write_query_node(right, buf);
+            write_query_node(left, buf);
+        }
+
+        PgTsQueryNode::Or(left, right) => {
+            buf.push(QUERY_OPERATOR as u8);
+            buf.push(OPERATOR_OR as u8);
+            write_query_node(right, buf);
+            write_query_node(left, buf);
+        }
+
+        PgTsQueryNode::Phrase {
+            left,
+            right,
+            distance,
+        } => {
+            buf.push(QUERY_OPERATOR as u8);
+            buf.push(OPERATOR_PHRASE as u8);
+            buf.extend(&(*distance as i16).to_be_bytes());
+            write_query_node(right, buf);
+            write_query_node(left, buf);
+        }
+    }
+}
+
+fn read_query_node(buf: &mut &[u8]) -> Result<PgTsQueryNode, BoxDynError> {
+    match buf.read_i8()? {
+        QUERY_VALUE => {
+            let mask = buf.read_u8()?;
+            let prefix = buf.read_u8()? != 0;
+            let lexeme = read_cstr(buf)?.to_owned();
+
+            let weights = PgTsWeight::ALL
+                .iter()
+                .copied()
+                .filter(|weight| mask & weight.to_mask() != 0)
+                .collect();
+
+            Ok(PgTsQueryNode::Lexeme {
+                lexeme,
+                prefix,
+                weights,
+            })
+        }
+
+        QUERY_OPERATOR => match buf.read_i8()? {
+            OPERATOR_NOT => Ok(PgTsQueryNode::Not(Box::new(read_query_node(buf)?))),
+
+            OPERATOR_AND => {
+                let right = Box::new(read_query_node(buf)?);
+                let left = Box::new(read_query_node(buf)?);
+
+                Ok(PgTsQueryNode::And(left, right))
+            }
+
+            OPERATOR_OR => {
+                let right = Box::new(read_query_node(buf)?);
+                let left = Box::new(read_query_node(buf)?);
+
+                Ok(PgTsQueryNode::Or(left, right))
+            }
+
+            OPERATOR_PHRASE => {
+                let distance = buf.read_i16::<NetworkEndian>()? as u16;
+                let right = Box::new(read_query_node(buf)?);
+                let left = Box::new(read_query_node(buf)?);
+
+                Ok(PgTsQueryNode::Phrase {
+                    left,
+                    right,
+                    distance,
+                })
+            }
+
+            operator => Err(format!("unknown tsquery operator {}", operator).into()),
+        },
+
+        kind => Err(format!("unknown tsquery item {}", kind).into()),
+    }
+}
+
+fn write_cstr(s: &str, buf: &mut PgArgumentBuffer) {
+    buf.extend(s.as_bytes());
+    buf.push(0);
+}
+
+fn read_cstr<'a>(buf: &mut &'a [u8]) -> Result<&'a str, BoxDynError> {
+    let end = buf
+        .iter()
+        .position(|&b| b == 0)
+        .ok_or("expected a NUL-terminated string")?;
+
+    let s = str::from_utf8(&buf[..end])?;
+    *buf = &buf[end + 1..];
+
+    Ok(s)
+}
+
+impl FromStr for PgTsVector {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars().peekable();
+        let mut lexemes = Vec::new();
+
+        loop {
+            skip_whitespace(&mut chars);
+
+            if chars.peek().is_none() {
+                break;
+            }
+
+            let lexeme = parse_lexeme(&mut chars)?;
+            let mut positions = Vec::new();
+
+            if chars.peek() == Some(&':') {
+                chars.next();
+
+                loop {
+                    let mut digits = String::new();
+
+                    while let Some(c) = chars.peek().copied().filter(char::is_ascii_digit) {
+                        digits.push(c);
+                        chars.next();
+                    }
+
+                    let position = digits
+                        .parse::<u16>()
+                        .map_err(|_| format!("invalid position of tsvector lexeme {:?}", lexeme))?;
+
+                    let weight = match chars.peek().copied().and_then(PgTsWeight::from_char) {
+                        Some(weight) => {
+                            chars.next();
+                            weight
+                        }
+
+                        None => PgTsWeight::D,
+                    };
+
+                    positions.push(PgTsPosition { position, weight });
+
+                    if chars.peek() != Some(&',') {
+                        break;
+                    }
+
+                    chars.next();
+                }
+            }
+
+            lexemes.push(PgTsLexeme { lexeme, positions });
+        }
+
+        Ok(PgTsVector { lexemes })
+    }
+}
+
+impl Display for PgTsVector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, lexeme) in self.lexemes.iter().enumerate() {
+            if i > 0 {
+                f.write_char(' ')?;
+            }
+
+            write_lexeme(&lexeme.lexeme, f)?;
+
+            for (i, position) in lexeme.positions.iter().enumerate() {
+                f.write_char(if i == 0 { ':' } else { ',' })?;
+
+                write!(f, "{}", position.position)?;
+
+                if position.weight != PgTsWeight::D {
+                    f.write_char(position.weight.as_char())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for PgTsQuery {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars().peekable();
+
+        skip_whitespace(&mut chars);
+
+        if chars.peek().is_none() {
+            return Ok(PgTsQuery::default());
+        }
+
+        let root = parse_query(&mut chars, 0)?;
+
+        skip_whitespace(&mut chars);
+
+        if let Some(c) = chars.next() {
+            return Err(format!("unexpected {:?} in tsquery", c).into());
+        }
+
+        Ok(root.into())
+    }
+}
+
+impl Display for PgTsQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.root {
+            Some(root) => root.fmt(f),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Display for PgTsQueryNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // operands are grouped when their operator binds less tightly than the parent; a phrase
+        // on the right of a phrase is grouped too, as phrases are not associative
+        fn write_operand(
+            f: &mut Formatter<'_>,
+            node: &PgTsQueryNode,
+            parent: &PgTsQueryNode,
+            right: bool,
+        ) -> fmt::Result {
+            let group = node.priority() < parent.priority()
+                || (right
+                    && matches!(parent, PgTsQueryNode::Phrase { .. })
+                    && matches!(node, PgTsQueryNode::Phrase { .. }));
+
+            if group {
+                write!(f, "( {} )", node)
+            } else {
+                node.fmt(f)
+            }
+        }
+
+        match self {
+            PgTsQueryNode::Lexeme {
+                lexeme,
+                prefix,
+                weights,
+            } => {
+                write_lexeme(lexeme, f)?;
+
+                if *prefix || !weights.is_empty() {
+                    f.write_char(':')?;
+                }
+
+                if *prefix {
+                    f.write_char('*')?;
+                }
+
+                for weight in &PgTsWeight::ALL {
+                    if weights.contains(weight) {
+                        f.write_char(weight.as_char())?;
+                    }
+                }
+
+                Ok(())
+            }
+
+            PgTsQueryNode::Not(node) => {
+                f.write_char('!')?;
+                write_operand(f, node, self, false)
+            }
+
+            PgTsQueryNode::And(left, right)
+            | PgTsQueryNode::Or(left, right)
+            | PgTsQueryNode::Phrase { left, right, .. } => {
+                write_operand(f, left, self, false)?;
+
+                match self {
+                    PgTsQueryNode::And(..) => f.write_str(" & ")?,
+                    PgTsQueryNode::Or(..) => f.write_str(" | ")?,
+                    PgTsQueryNode::Phrase { distance: 1, .. } => f.write_str(" <-> ")?,
+                    PgTsQueryNode::Phrase { distance, .. } => write!(f, " <{}> ", distance)?,
+                    PgTsQueryNode::Lexeme { .. } | PgTsQueryNode::Not(_) => unreachable!(),
+                }
+
+                write_operand(f, right, self, true)
+            }
+        }
+    }
+}
+
+// parses operators that bind at least as tightly as `priority`, from left to right
+fn parse_query(
+    chars: &mut Peekable<Chars<'_>>,
+    priority: u8,
+) -> Result<PgTsQueryNode, BoxDynError> {
+    let mut left = parse_query_operand(chars)?;
+
+    loop {
+        skip_whitespace(chars);
+
+        let operator_priority = match chars.peek() {
+            Some('|') => 1,
+            Some('&') => 2,
+            Some('<') => 3,
+            _ => break,
+        };
+
+        if operator_priority < priority {
+            break;
+        }
+
+        let distance = match chars.next() {
+            Some('<') => Some(parse_distance(chars)?),
+            _ => None,
+        };
+
+        let right = Box::new(parse_query(chars, operator_priority + 1)?);
+        let left_node = Box::new(left);
+
+        left = match (operator_priority, distance) {
+            (1, _) => PgTsQueryNode::Or(left_node, right),
+            (2, _) => PgTsQueryNode::And(left_node, right),
+
+            (_, distance) => PgTsQueryNode::Phrase {
+                left: left_node,
+                right,
+                distance: distance.unwrap_or(1),
+            },
+        };
+    }
+
+    Ok(left)
+}
+
+fn parse_query_operand(chars: &mut Peekable<Chars<'_>>) -> Result<PgTsQueryNode, BoxDynError> {
+    skip_whitespace(chars);
+
+    match chars.peek() {
+        Some('!') => {
+            chars.next();
+
+            Ok(PgTsQueryNode::Not(Box::new(parse_query_operand(chars)?)))
+        }
+
+        Some('(') => {
+            chars.next();
+
+            let node = parse_query(chars, 0)?;
+
+            skip_whitespace(chars);
+
+            if chars.next() != Some(')') {
+                return Err("expected `)` in tsquery".into());
+            }
+
+            Ok(node)
+        }
+
+        _ => {
+            let lexeme = parse_lexeme(chars)?;
+            let mut prefix = false;
+            let mut weights = Vec::new();
+
+            if chars.peek() == Some(&':') {
+                chars.next();
+
+                while let Some(&c) = chars.peek() {
+                    if c == '*' {
+                        prefix = true;
+                    } else if let Some(weight) = PgTsWeight::from_char(c) {
+                        weights.push(weight);
+                    } else {
+                        break;
+                    }
+
+                    chars.next();
+                }
+            }
+
+            Ok(PgTsQueryNode::Lexeme {
+                lexeme,
+                prefix,
+                weights,
+            })
+        }
+    }
+}
+
+// the rest of `<->` or `<N>`, after the `<`
+fn parse_distance(chars: &mut Peekable<Chars<'_>>) -> Result<u16, BoxDynError> {
+    let mut inner = String::new();
+
+    loop {
+        match chars.next() {
+            Some('>') => break,
+            Some(c) => inner.push(c),
+            None => return Err("unexpected end of tsquery".into()),
+        }
+    }
+
+    if inner == "-" {
+        return Ok(1);
+    }
+
+    inner
+        .parse()
+        .map_err(|_| format!("invalid tsquery phrase distance {:?}", inner).into())
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars<'_>>) {
+    while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+// a lexeme is quoted with `'` or ends before whitespace or an operator; within it, `''` is
+// a quote and a backslash escapes the next character
+fn parse_lexeme(chars: &mut Peekable<Chars<'_>>) -> Result<String, BoxDynError> {
+    let mut s = String::new();
+
+    if chars.peek() == Some(&'\'') {
+        chars.next();
+
+        loop {
+            match chars.next() {
+                Some('\'') if chars.peek() == Some(&'\'') => {
+                    chars.next();
+                    s.push('\'');
+                }
+
+                Some('\'') => break,
+                Some('\\') => s.push(chars.next().ok_or("unexpected end of lexeme")?),
+                Some(c) => s.push(c),
+                None => return Err("unexpected end of lexeme".into()),
+            }
+        }
+    } else {
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() || ":&|!()<".contains(c) {
+                break;
+            }
+
+            chars.next();
+
+            if c == '\\' {
+                s.push(chars.next().ok_or("unexpected end of lexeme")?);
+            } else {
+                s.push(c);
+            }
+        }
+    }
+
+    if s.is_empty() {
+        return Err("expected a lexeme".into());
+    }
+
+    Ok(s)
+}
+
+fn write_lexeme(lexeme: &str, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_char('\'')?;
+
+    for c in lexeme.chars() {
+        if c == '\'' || c == '\\' {
+            f.write_char(c)?;
+        }
+
+        f.write_char(c)?;
+    }
+
+    f.write_char('\'')
+}
+
+#[test]
+fn test_parse_tsvector() {
+    let vector: PgTsVector = r"'a':1A,2 'cat':3 'it''s' 'back\\slash':5C"
+        .parse()
+        .unwrap();
+
+    assert_eq!(vector.lexemes.len(), 4);
+    assert_eq!(vector.lexemes[0].lexeme, "a");
+    assert_eq!(
+        vector.lexemes[0].positions,
+        [
+            PgTsPosition {
+                position: 1,
+                weight: PgTsWeight::A
+            },
+            PgTsPosition {
+                position: 2,
+                weight: PgTsWeight::D
+            }
+        ]
+    );
+    assert_eq!(vector.lexemes[2].lexeme, "it's");
+    assert!(vector.lexemes[2].positions.is_empty());
+    assert_eq!(vector.lexemes[3].lexeme, "back\\slash");
+
+    assert_eq!(
+        vector.to_string(),
+        r"'a':1A,2 'cat':3 'it''s' 'back\\slash':5C"
+    );
+}
+
+#[test]
+fn test_parse_tsquery() {
+    let s = "'fat' & ( 'rat' | !'cat' ) <-> 'x':*AB & 'y' <3> 'z'";
+    let query: PgTsQuery = s.parse().unwrap();
+
+    assert_eq!(query.to_string(), s);
+
+    match &query.root {
+        Some(PgTsQueryNode::And(left, right)) => {
+            assert!(matches!(**left, PgTsQueryNode::And(..)));
+            assert!(matches!(**right, PgTsQueryNode::Phrase { distance: 3, .. }));
+        }
+
+        root => panic!("unexpected tsquery {:?}", root),
+    }
+
+    let query: PgTsQuery = "a <-> ( b <-> c )".parse().unwrap();
+    assert_eq!(query.to_string(), "'a' <-> ( 'b' <-> 'c' )");
+
+    assert_eq!("".parse::<PgTsQuery>().unwrap(), PgTsQuery::default());
+    assert!("'a' &".parse::<PgTsQuery>().is_err());
+    assert!("( 'a'".parse::<PgTsQuery>().is_err());
+}
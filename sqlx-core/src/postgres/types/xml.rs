@@ -0,0 +1,81 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use crate::types::Type;
+
+/// An XML document or content fragment, the Postgres [`XML`] type.
+///
+/// The XML is not parsed here; Postgres checks that it is well-formed when it is written.
+///
+/// [`XML`]: https://www.postgresql.org/docs/current/datatype-xml.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgXml(String);
+
+impl PgXml {
+    pub fn new(xml: impl Into<String>) -> Self {
+        PgXml(xml.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for PgXml {
+    fn from(xml: String) -> Self {
+        PgXml(xml)
+    }
+}
+
+impl From<PgXml> for String {
+    fn from(xml: PgXml) -> Self {
+        xml.0
+    }
+}
+
+impl Display for PgXml {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Type<Postgres> for PgXml {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::XML
+    }
+}
+
+impl Type<Postgres> for [PgXml] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::XML_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<PgXml> {
+    fn type_info() -> PgTypeInfo {
+        <[PgXml] as Type<Postgres>>::type_info()
+    }
+}
+
+// the binary format of `xml` is its text in the client encoding, which is always UTF-8 for
+// SQLx
+impl Encode<'_, Postgres> for PgXml {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(self.0.as_bytes());
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Postgres> for PgXml {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(PgXml(value.as_str()?.to_owned()))
+    }
+}
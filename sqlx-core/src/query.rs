@@ -5,7 +5,7 @@ use futures_core::stream::BoxStream;
 use futures_util::{future, StreamExt, TryFutureExt, TryStreamExt};
 
 use crate::arguments::{Arguments, IntoArguments};
-use crate::database::{Database, HasArguments, HasStatement, HasStatementCache};
+use crate::database::{Database, HasArguments, HasFetchSize, HasStatement, HasStatementCache};
 use crate::encode::Encode;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
@@ -19,6 +19,7 @@ pub struct Query<'q, DB: Database, A> {
     pub(crate) arguments: Option<A>,
     pub(crate) database: PhantomData<DB>,
     pub(crate) persistent: bool,
+    pub(crate) fetch_size: Option<u32>,
 }
 
 /// SQL query that will map its results to owned Rust types.
@@ -64,6 +65,11 @@ where
     fn persistent(&self) -> bool {
         self.persistent
     }
+
+    #[inline]
+    fn fetch_size(&self) -> Option<u32> {
+        self.fetch_size
+    }
 }
 
 impl<'q, DB: Database> Query<'q, DB, <DB as HasArguments<'q>>::Arguments> {
@@ -102,6 +108,23 @@ where
     }
 }
 
+impl<'q, DB, A> Query<'q, DB, A>
+where
+    DB: Database + HasFetchSize,
+{
+    /// Fetch the rows of a prepared statement in batches of `n` rows, with a cursor on the
+    /// server, instead of receiving all of them at once.
+    ///
+    /// The memory used is then bounded by the size of a batch, no matter how many rows the
+    /// query returns. Queries without arguments are not prepared and ignore this.
+    ///
+    /// Default: all rows at once.
+    pub fn fetch_size(mut self, n: u32) -> Self {
+        self.fetch_size = Some(n.max(1));
+        self
+    }
+}
+
 impl<'q, DB, A: Send> Query<'q, DB, A>
 where
     DB: Database,
@@ -246,6 +269,11 @@ where
     fn persistent(&self) -> bool {
         self.inner.arguments.is_some()
     }
+
+    #[inline]
+    fn fetch_size(&self) -> Option<u32> {
+        self.inner.fetch_size
+    }
 }
 
 impl<'q, DB, F, O, A> Map<'q, DB, F, A>
@@ -397,6 +425,7 @@ where
         arguments: Some(Default::default()),
         statement: Either::Right(statement),
         persistent: true,
+        fetch_size: None,
     }
 }
 
@@ -414,6 +443,7 @@ where
         arguments: Some(arguments),
         statement: Either::Right(statement),
         persistent: true,
+        fetch_size: None,
     }
 }
 
@@ -427,6 +457,7 @@ where
         arguments: Some(Default::default()),
         statement: Either::Left(sql),
         persistent: true,
+        fetch_size: None,
     }
 }
 
@@ -441,6 +472,7 @@ where
         arguments: Some(arguments),
         statement: Either::Left(sql),
         persistent: true,
+        fetch_size: None,
     }
 }
 
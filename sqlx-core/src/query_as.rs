@@ -5,7 +5,7 @@ use futures_core::stream::BoxStream;
 use futures_util::{StreamExt, TryStreamExt};
 
 use crate::arguments::IntoArguments;
-use crate::database::{Database, HasArguments, HasStatement};
+use crate::database::{Database, HasArguments, HasFetchSize, HasStatement};
 use crate::encode::Encode;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
@@ -45,6 +45,11 @@ where
     fn persistent(&self) -> bool {
         self.inner.persistent()
     }
+
+    #[inline]
+    fn fetch_size(&self) -> Option<u32> {
+        self.inner.fetch_size()
+    }
 }
 
 impl<'q, DB: Database, O> QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments> {
@@ -57,6 +62,19 @@ impl<'q, DB: Database, O> QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments
     }
 }
 
+impl<'q, DB, O, A> QueryAs<'q, DB, O, A>
+where
+    DB: Database + HasFetchSize,
+{
+    /// Fetch the rows in batches of `n` rows.
+    ///
+    /// See [`Query::fetch_size`](crate::query::Query::fetch_size).
+    pub fn fetch_size(mut self, n: u32) -> Self {
+        self.inner = self.inner.fetch_size(n);
+        self
+    }
+}
+
 // FIXME: This is very close, nearly 1:1 with `Map`
 // noinspection DuplicatedCode
 impl<'q, DB, O, A> QueryAs<'q, DB, O, A>
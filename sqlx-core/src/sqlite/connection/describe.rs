@@ -78,10 +78,18 @@ pub(super) fn describe<'c: 'e, 'q: 'e, 'e>(
                     ty
                 };
 
-                nullable.push(stmt.column_nullable(col)?.or_else(|| {
-                    // if we do not *know* if this is nullable, check the EXPLAIN fallback
-                    fallback_nullable.get(col).copied().and_then(identity)
-                }));
+                // the EXPLAIN output follows every path of the query, so it knows when a
+                // column comes from the outer side of a LEFT JOIN or is a rowid alias; if
+                // it does not know, fall back to the declaration of the column
+                let column_nullable = stmt.column_nullable(col)?;
+
+                nullable.push(
+                    fallback_nullable
+                        .get(col)
+                        .copied()
+                        .and_then(identity)
+                        .or(column_nullable),
+                );
 
                 columns.push(SqliteColumn {
                     name: name.into(),
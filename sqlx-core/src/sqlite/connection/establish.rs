@@ -6,11 +6,13 @@ use crate::{
     sqlite::{SqliteConnectOptions, SqliteConnection, SqliteError},
 };
 use libsqlite3_sys::{
-    sqlite3_busy_timeout, sqlite3_extended_result_codes, sqlite3_open_v2, SQLITE_OK,
-    SQLITE_OPEN_CREATE, SQLITE_OPEN_MEMORY, SQLITE_OPEN_NOMUTEX, SQLITE_OPEN_PRIVATECACHE,
-    SQLITE_OPEN_READONLY, SQLITE_OPEN_READWRITE, SQLITE_OPEN_SHAREDCACHE,
+    sqlite3_busy_timeout, sqlite3_enable_load_extension, sqlite3_extended_result_codes,
+    sqlite3_free, sqlite3_load_extension, sqlite3_open_v2, SQLITE_OK, SQLITE_OPEN_CREATE,
+    SQLITE_OPEN_MEMORY, SQLITE_OPEN_NOMUTEX, SQLITE_OPEN_PRIVATECACHE, SQLITE_OPEN_READONLY,
+    SQLITE_OPEN_READWRITE, SQLITE_OPEN_SHAREDCACHE, SQLITE_OPEN_URI,
 };
 use sqlx_rt::blocking;
+use std::ffi::{CStr, CString};
 use std::io;
 use std::{
     convert::TryFrom,
@@ -55,8 +57,38 @@ pub(crate) async fn establish(options: &SqliteConnectOptions) -> Result<SqliteCo
         SQLITE_OPEN_PRIVATECACHE
     };
 
+    // Interpret filenames of the form `file:name` as URIs; this is also required
+    // for the URI filenames we use to attach databases as read-only
+    // <https://www.sqlite.org/uri.html>
+    if filename.starts_with("file:") || !options.attachments.is_empty() {
+        flags |= SQLITE_OPEN_URI;
+    }
+
     let busy_timeout = options.busy_timeout;
 
+    let extensions = options
+        .extensions
+        .iter()
+        .map(|extension| {
+            let path = extension.path.to_str().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "path of an extension passed to SQLite must be valid UTF-8",
+                )
+            })?;
+
+            let path = CString::new(path).map_err(Error::config)?;
+            let entry_point = extension
+                .entry_point
+                .as_deref()
+                .map(CString::new)
+                .transpose()
+                .map_err(Error::config)?;
+
+            Ok((path, entry_point))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
     let handle = blocking!({
         let mut handle = null_mut();
 
@@ -105,6 +137,30 @@ pub(crate) async fn establish(options: &SqliteConnectOptions) -> Result<SqliteCo
             return Err(Error::Database(Box::new(SqliteError::new(handle.as_ptr()))));
         }
 
+        // Load extensions
+        // Loading is enabled only while we load them so that SQL can never call
+        // `load_extension()` on this connection
+        // https://www.sqlite.org/c3ref/enable_load_extension.html
+        if !extensions.is_empty() {
+            status = unsafe { sqlite3_enable_load_extension(handle.as_ptr(), 1) };
+
+            if status != SQLITE_OK {
+                return Err(Error::Database(Box::new(SqliteError::new(handle.as_ptr()))));
+            }
+
+            let loaded = extensions
+                .iter()
+                .try_for_each(|(path, entry_point)| load_extension(&handle, path, entry_point));
+
+            status = unsafe { sqlite3_enable_load_extension(handle.as_ptr(), 0) };
+
+            loaded?;
+
+            if status != SQLITE_OK {
+                return Err(Error::Database(Box::new(SqliteError::new(handle.as_ptr()))));
+            }
+        }
+
         Ok(handle)
     })?;
 
@@ -117,3 +173,43 @@ pub(crate) async fn establish(options: &SqliteConnectOptions) -> Result<SqliteCo
         log_settings: options.log_settings.clone(),
     })
 }
+
+fn load_extension(
+    handle: &ConnectionHandle,
+    path: &CStr,
+    entry_point: &Option<CString>,
+) -> Result<(), Error> {
+    let mut message = null_mut();
+
+    // https://www.sqlite.org/c3ref/load_extension.html
+    let status = unsafe {
+        sqlite3_load_extension(
+            handle.as_ptr(),
+            path.as_ptr(),
+            entry_point.as_ref().map_or(null(), |entry| entry.as_ptr()),
+            &mut message,
+        )
+    };
+
+    if status == SQLITE_OK {
+        return Ok(());
+    }
+
+    if message.is_null() {
+        return Err(Error::Database(Box::new(SqliteError::new(handle.as_ptr()))));
+    }
+
+    // the message is allocated by SQLite and must be released with [sqlite3_free]
+    let error = unsafe {
+        let error = SqliteError::with_message(
+            status,
+            CStr::from_ptr(message).to_string_lossy().into_owned(),
+        );
+
+        sqlite3_free(message as *mut _);
+
+        error
+    };
+
+    Err(Error::Database(Box::new(error)))
+}
@@ -16,14 +16,45 @@ const SQLITE_AFF_REAL: u8 = 0x45; /* 'E' */
 // opcodes
 const OP_INIT: &str = "Init";
 const OP_GOTO: &str = "Goto";
+const OP_HALT: &str = "Halt";
+const OP_GOSUB: &str = "Gosub";
+const OP_RETURN: &str = "Return";
+const OP_INIT_COROUTINE: &str = "InitCoroutine";
+const OP_YIELD: &str = "Yield";
+const OP_END_COROUTINE: &str = "EndCoroutine";
+const OP_JUMP: &str = "Jump";
+const OP_IF: &str = "If";
+const OP_IF_NOT: &str = "IfNot";
+const OP_IF_POS: &str = "IfPos";
+const OP_IF_NOT_ZERO: &str = "IfNotZero";
+const OP_IS_NULL: &str = "IsNull";
+const OP_NOT_NULL: &str = "NotNull";
+const OP_IF_NULL_ROW: &str = "IfNullRow";
+const OP_OPEN_READ: &str = "OpenRead";
+const OP_REOPEN_IDX: &str = "ReopenIdx";
+const OP_OPEN_DUP: &str = "OpenDup";
+const OP_OPEN_PSEUDO: &str = "OpenPseudo";
+const OP_NULL_ROW: &str = "NullRow";
+const OP_MAKE_RECORD: &str = "MakeRecord";
+const OP_SORTER_INSERT: &str = "SorterInsert";
+const OP_IDX_INSERT: &str = "IdxInsert";
+const OP_SORTER_DATA: &str = "SorterData";
+const OP_SORTER_SORT: &str = "SorterSort";
 const OP_COLUMN: &str = "Column";
 const OP_AGG_STEP: &str = "AggStep";
+const OP_AGG_FINAL: &str = "AggFinal";
 const OP_FUNCTION: &str = "Function";
+const OP_FUNCTION0: &str = "Function0";
+const OP_PURE_FUNC: &str = "PureFunc";
+const OP_PURE_FUNC0: &str = "PureFunc0";
 const OP_MOVE: &str = "Move";
 const OP_COPY: &str = "Copy";
 const OP_SCOPY: &str = "SCopy";
 const OP_INT_COPY: &str = "IntCopy";
 const OP_CAST: &str = "Cast";
+const OP_COLL_SEQ: &str = "CollSeq";
+const OP_NULL: &str = "Null";
+const OP_STRING: &str = "String";
 const OP_STRING8: &str = "String8";
 const OP_INT64: &str = "Int64";
 const OP_INTEGER: &str = "Integer";
@@ -33,6 +64,7 @@ const OP_BLOB: &str = "Blob";
 const OP_VARIABLE: &str = "Variable";
 const OP_COUNT: &str = "Count";
 const OP_ROWID: &str = "Rowid";
+const OP_IDX_ROWID: &str = "IdxRowid";
 const OP_OR: &str = "Or";
 const OP_AND: &str = "And";
 const OP_BIT_AND: &str = "BitAnd";
@@ -47,6 +79,82 @@ const OP_REMAINDER: &str = "Remainder";
 const OP_CONCAT: &str = "Concat";
 const OP_RESULT_ROW: &str = "ResultRow";
 
+// opcodes that jump to <p2> depending on a condition we do not evaluate;
+// both branches are followed
+const OP_BRANCHES: &[&str] = &[
+    "Eq",
+    "Ne",
+    "Lt",
+    "Le",
+    "Gt",
+    "Ge",
+    "ElseNotEq",
+    "DecrJumpZero",
+    "MustBeInt",
+    "Rewind",
+    "Last",
+    "Next",
+    "Prev",
+    "Sort",
+    "SorterSort",
+    "SorterNext",
+    "SeekGE",
+    "SeekGT",
+    "SeekLE",
+    "SeekLT",
+    "SeekRowid",
+    "NotExists",
+    "NotFound",
+    "Found",
+    "NoConflict",
+    "IfNoHope",
+    "IdxGE",
+    "IdxGT",
+    "IdxLE",
+    "IdxLT",
+    "IfSmaller",
+    "RowSetRead",
+    "RowSetTest",
+    "SequenceTest",
+    "VFilter",
+    "VNext",
+];
+
+// operations we do not follow that write a register, by the operand naming it;
+// the register is unknown afterwards
+//
+// https://www.sqlite.org/opcode.html
+const OP_WRITES_P1: &[&str] = &["AddImm", "RealAffinity", "SoftNull", "MemMax"];
+
+const OP_WRITES_P2: &[&str] = &[
+    "Sequence",
+    "NewRowid",
+    "RowData",
+    "Param",
+    "BitNot",
+    "IsTrue",
+    "OffsetLimit",
+    "ZeroOrNull",
+    "BeginSubrtn",
+    "GetSubtype",
+    "VRowid",
+    "Pagecount",
+    "MaxPgcnt",
+    "JournalMode",
+];
+
+const OP_WRITES_P3: &[&str] = &["Offset", "VColumn", "AggValue", "RowSetRead"];
+
+// the registers p1 .. p1 + p2
+const OP_AFFINITY: &str = "Affinity";
+
+// the number of times a single branch may execute the same instruction, this
+// allows a subroutine to be called more than once while still terminating loops
+const MAX_VISITS: u8 = 2;
+
+// the number of instructions we are willing to execute across all branches
+const MAX_INSTRUCTIONS: usize = 25_000;
+
 #[allow(clippy::wildcard_in_or_patterns)]
 fn affinity_to_type(affinity: u8) -> DataType {
     match affinity {
@@ -67,172 +175,816 @@ fn opcode_to_type(op: &str) -> DataType {
         OP_BLOB => DataType::Blob,
         OP_AND | OP_OR => DataType::Bool,
         OP_ROWID | OP_COUNT | OP_INT64 | OP_INTEGER => DataType::Int64,
-        OP_STRING8 => DataType::Text,
+        OP_STRING | OP_STRING8 => DataType::Text,
         OP_COLUMN | _ => DataType::Null,
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct ColumnType {
+    datatype: DataType,
+
+    // `None` if we do not know
+    nullable: Option<bool>,
+}
+
+impl ColumnType {
+    const UNKNOWN: Self = ColumnType {
+        datatype: DataType::Null,
+        nullable: None,
+    };
+
+    const NULL: Self = ColumnType {
+        datatype: DataType::Null,
+        nullable: Some(true),
+    };
+
+    fn not_null(datatype: DataType) -> Self {
+        ColumnType {
+            datatype,
+            nullable: Some(false),
+        }
+    }
+
+    fn nullable(datatype: DataType) -> Self {
+        ColumnType {
+            datatype,
+            nullable: Some(true),
+        }
+    }
+
+    // the type of a value that could come from either `self` or `other`
+    fn merge(self, other: Self) -> Self {
+        ColumnType {
+            datatype: if self.datatype == DataType::Null {
+                other.datatype
+            } else {
+                self.datatype
+            },
+
+            // if either side is unknown, so is the result
+            nullable: match (self.nullable, other.nullable) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum RegDataType {
+    Single(ColumnType),
+
+    // a record built by `MakeRecord`
+    Record(Vec<ColumnType>),
+
+    // an integer constant, which decides `If`, `IfPos` and friends
+    Int(i64),
+
+    // a program address, stored by `Gosub` and coroutines
+    Address(usize),
+}
+
+#[derive(Debug, Clone)]
+enum CursorDataType {
+    // a table, index, sorter or ephemeral table with the given columns
+    Normal {
+        columns: Vec<ColumnType>,
+        is_null_row: bool,
+    },
+
+    // reads the columns of the record in the given register
+    Pseudo(i64),
+}
+
+#[derive(Debug, Clone)]
+struct QueryState {
+    visited: Vec<u8>,
+    program_i: usize,
+    r: HashMap<i64, RegDataType>,
+    p: HashMap<i64, CursorDataType>,
+}
+
+impl QueryState {
+    fn column_type(&self, register: i64) -> ColumnType {
+        match self.r.get(&register) {
+            Some(RegDataType::Single(ty)) => *ty,
+            Some(RegDataType::Int(_)) => ColumnType::not_null(DataType::Int64),
+
+            _ => ColumnType::UNKNOWN,
+        }
+    }
+
+    fn int(&self, register: i64) -> Option<i64> {
+        match self.r.get(&register) {
+            Some(RegDataType::Int(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn set(&mut self, register: i64, ty: ColumnType) {
+        self.r.insert(register, RegDataType::Single(ty));
+    }
+
+    fn set_nullable(&mut self, register: i64, nullable: bool) {
+        let mut ty = self.column_type(register);
+        ty.nullable = Some(nullable);
+
+        self.set(register, ty);
+    }
+
+    fn cursor_column(&self, cursor: i64, column: i64) -> ColumnType {
+        match self.p.get(&cursor) {
+            Some(CursorDataType::Normal {
+                columns,
+                is_null_row,
+            }) => {
+                let ty = columns
+                    .get(column as usize)
+                    .copied()
+                    .unwrap_or(ColumnType::UNKNOWN);
+
+                if *is_null_row {
+                    // e.g., the outer side of a LEFT JOIN without a match
+                    ColumnType::NULL.merge(ty)
+                } else {
+                    ty
+                }
+            }
+
+            Some(CursorDataType::Pseudo(register)) => match self.r.get(register) {
+                Some(RegDataType::Record(columns)) => columns
+                    .get(column as usize)
+                    .copied()
+                    .unwrap_or(ColumnType::UNKNOWN),
+
+                _ => ColumnType::UNKNOWN,
+            },
+
+            None => ColumnType::UNKNOWN,
+        }
+    }
+
+    fn is_null_row(&self, cursor: i64) -> bool {
+        matches!(
+            self.p.get(&cursor),
+            Some(CursorDataType::Normal {
+                is_null_row: true,
+                ..
+            })
+        )
+    }
+
+    fn branch(&self, program_i: i64) -> Self {
+        let mut state = self.clone();
+        state.program_i = program_i as usize;
+        state
+    }
+}
+
+// infers the type of a scalar function from its name and arguments
+fn function_type(name: &str, args: &[ColumnType]) -> ColumnType {
+    let first = args.first().copied().unwrap_or(ColumnType::UNKNOWN);
+
+    // the function returns NULL if (and only if) any argument is NULL
+    let propagate = |datatype: DataType| ColumnType {
+        datatype,
+        nullable: if args.iter().any(|arg| arg.nullable == Some(true)) {
+            Some(true)
+        } else if args.iter().all(|arg| arg.nullable == Some(false)) {
+            Some(false)
+        } else {
+            None
+        },
+    };
+
+    match name {
+        "last_insert_rowid" | "changes" | "total_changes" | "random" => {
+            ColumnType::not_null(DataType::Int64)
+        }
+
+        "typeof" | "quote" | "sqlite_version" => ColumnType::not_null(DataType::Text),
+        "randomblob" | "zeroblob" => ColumnType::not_null(DataType::Blob),
+
+        "length" | "instr" | "unicode" => propagate(DataType::Int64),
+        "round" => propagate(DataType::Float),
+
+        "lower" | "upper" | "trim" | "ltrim" | "rtrim" | "replace" | "substr" | "hex" => {
+            propagate(DataType::Text)
+        }
+
+        "abs" | "max" | "min" => propagate(first.datatype),
+
+        // may return NULL for arguments that are not NULL (e.g., an invalid date)
+        "date" | "time" | "datetime" | "strftime" | "printf" | "format" | "char" => ColumnType {
+            datatype: DataType::Text,
+            nullable: None,
+        },
+
+        "julianday" => ColumnType {
+            datatype: DataType::Float,
+            nullable: None,
+        },
+
+        _ => ColumnType::UNKNOWN,
+    }
+}
+
+// infers the result of an aggregate function from its name and the type of its
+// argument, or of its accumulator
+fn aggregate_type(name: &str, arg: ColumnType) -> ColumnType {
+    match name {
+        // count(_) over zero rows is 0
+        "count" => ColumnType::not_null(DataType::Int64),
+
+        // total(_) over zero rows is 0.0
+        "total" => ColumnType::not_null(DataType::Float),
+
+        // the rest are NULL over zero rows, or when every value is NULL
+        "avg" => ColumnType::nullable(DataType::Float),
+        "group_concat" => ColumnType::nullable(DataType::Text),
+        "sum" | "min" | "max" => ColumnType::nullable(arg.datatype),
+
+        _ => ColumnType::UNKNOWN,
+    }
+}
+
+// splits the name and number of arguments of a function from `<name>(<args>)`
+fn parse_function(p4: &[u8]) -> Result<(&str, i64), Error> {
+    let p4 = from_utf8(p4).map_err(Error::protocol)?;
+    let mut name_and_args = p4.splitn(2, '(');
+
+    let name = name_and_args.next().unwrap_or_default();
+    let args = name_and_args
+        .next()
+        .and_then(|args| args.trim_end_matches(')').parse().ok())
+        .unwrap_or(0);
+
+    Ok((name, args))
+}
+
+// the columns of the table or index at <root_page> in the database <db>
+async fn root_block_columns(
+    conn: &mut SqliteConnection,
+    db: i64,
+    root_page: i64,
+) -> Result<Option<Vec<ColumnType>>, Error> {
+    let schema: Option<(String,)> =
+        query_as("SELECT name FROM pragma_database_list WHERE seq = ?1")
+            .bind(db)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+    let schema = match schema {
+        Some((schema,)) => schema,
+        None => return Ok(None),
+    };
+
+    let object: Option<(String, String, String, Option<String>)> = query_as(&format!(
+        "SELECT type, name, tbl_name, sql FROM \"{}\".sqlite_master WHERE rootpage = ?1",
+        schema.replace('"', "\"\"")
+    ))
+    .bind(root_page)
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    let (kind, name, table, sql) = match object {
+        Some(object) => object,
+
+        // e.g., the root page of `sqlite_master` itself
+        None => return Ok(None),
+    };
+
+    let without_rowid =
+        matches!(sql, Some(sql) if sql.to_ascii_uppercase().contains("WITHOUT ROWID"));
+
+    let (columns, primary_key) = table_columns(conn, &schema, &table, without_rowid).await?;
+
+    if kind == "index" {
+        // the columns of the index; for a table with a rowid, this ends with the rowid
+        let index: Vec<(i64,)> =
+            query_as("SELECT cid FROM pragma_index_xinfo(?1, ?2) ORDER BY seqno")
+                .bind(&name)
+                .bind(&schema)
+                .fetch_all(&mut *conn)
+                .await?;
+
+        return Ok(Some(
+            index
+                .into_iter()
+                .map(|(cid,)| match cid {
+                    -1 => ColumnType::not_null(DataType::Int64),
+                    cid => columns
+                        .get(cid as usize)
+                        .copied()
+                        .unwrap_or(ColumnType::UNKNOWN),
+                })
+                .collect(),
+        ));
+    }
+
+    if without_rowid {
+        // a table without a rowid is stored as an index on its primary key, which
+        // puts the primary key columns first
+        let mut stored: Vec<_> = primary_key.iter().map(|&cid| columns[cid]).collect();
+
+        stored.extend(
+            columns
+                .iter()
+                .enumerate()
+                .filter(|(cid, _)| !primary_key.contains(cid))
+                .map(|(_, ty)| *ty),
+        );
+
+        return Ok(Some(stored));
+    }
+
+    Ok(Some(columns))
+}
+
+// the columns of <table>, ordered by their declaration, and the indices of the
+// columns of its primary key
+async fn table_columns(
+    conn: &mut SqliteConnection,
+    schema: &str,
+    table: &str,
+    without_rowid: bool,
+) -> Result<(Vec<ColumnType>, Vec<usize>), Error> {
+    let info: Vec<(i64, String, bool, i64)> =
+        query_as("SELECT cid, type, \"notnull\", pk FROM pragma_table_info(?1, ?2) ORDER BY cid")
+            .bind(table)
+            .bind(schema)
+            .fetch_all(&mut *conn)
+            .await?;
+
+    let mut primary_key: Vec<_> = info.iter().filter(|(.., pk)| *pk > 0).collect();
+    primary_key.sort_by_key(|(.., pk)| *pk);
+
+    let primary_key: Vec<usize> = primary_key.iter().map(|(cid, ..)| *cid as usize).collect();
+
+    // an `INTEGER PRIMARY KEY` is an alias for the rowid
+    let rowid_alias = !without_rowid
+        && primary_key.len() == 1
+        && info[primary_key[0]].1.eq_ignore_ascii_case("integer");
+
+    let columns = info
+        .iter()
+        .map(|(_, declared, not_null, pk)| {
+            // NOTE: SQLite allows NULL in a PRIMARY KEY unless it is enforced by
+            //       the rowid or a table WITHOUT ROWID
+            let not_null = *not_null || (*pk > 0 && (rowid_alias || without_rowid));
+
+            ColumnType {
+                datatype: declared.parse().unwrap_or(DataType::Null),
+                nullable: Some(!not_null),
+            }
+        })
+        .collect();
+
+    Ok((columns, primary_key))
+}
+
 pub(super) async fn explain(
     conn: &mut SqliteConnection,
     query: &str,
 ) -> Result<(Vec<SqliteTypeInfo>, Vec<Option<bool>>), Error> {
-    let mut r = HashMap::<i64, DataType>::with_capacity(6);
-    let mut n = HashMap::<i64, bool>::with_capacity(6);
-
     let program =
         query_as::<_, (i64, String, i64, i64, i64, Vec<u8>)>(&*format!("EXPLAIN {}", query))
             .fetch_all(&mut *conn)
             .await?;
 
-    let mut program_i = 0;
     let program_size = program.len();
 
-    while program_i < program_size {
-        let (_, ref opcode, p1, p2, p3, ref p4) = program[program_i];
-
-        match &**opcode {
-            OP_INIT => {
-                // start at <p2>
-                program_i = p2 as usize;
-                continue;
+    // the columns behind each root page, by (database, root page)
+    let mut root_blocks = HashMap::<(i64, i64), Option<Vec<ColumnType>>>::default();
+
+    // we follow every branch of the program and merge the types of the rows they produce
+    let mut states = vec![QueryState {
+        visited: vec![0; program_size],
+        program_i: 0,
+        r: HashMap::default(),
+        p: HashMap::default(),
+    }];
+
+    let mut result: Option<Vec<ColumnType>> = None;
+    let mut instructions = 0;
+    let mut exhausted = false;
+
+    'states: while let Some(mut state) = states.pop() {
+        while state.program_i < program_size {
+            if state.visited[state.program_i] >= MAX_VISITS {
+                // we are stuck in a loop on this branch
+                break;
             }
 
-            OP_GOTO => {
-                // goto <p2>
-                program_i = p2 as usize;
-                continue;
-            }
+            instructions += 1;
 
-            OP_COLUMN => {
-                // r[p3] = <value of column>
-                r.insert(p3, DataType::Null);
-                n.insert(p3, true);
+            if instructions > MAX_INSTRUCTIONS {
+                // the paths we did not explore could produce NULL anywhere
+                exhausted = true;
+                break 'states;
             }
 
-            OP_VARIABLE => {
-                // r[p2] = <value of variable>
-                r.insert(p2, DataType::Null);
-                n.insert(p3, true);
-            }
+            state.visited[state.program_i] += 1;
+
+            let (_, ref opcode, p1, p2, p3, ref p4) = program[state.program_i];
 
-            OP_FUNCTION => {
-                // r[p1] = func( _ )
-                if from_utf8(p4).map_err(Error::protocol)? == "last_insert_rowid(0)" {
-                    // last_insert_rowid() -> INTEGER
-                    r.insert(p3, DataType::Int64);
-                    n.insert(p3, false);
+            if OP_WRITES_P1.contains(&&**opcode) {
+                state.r.remove(&p1);
+            } else if OP_WRITES_P2.contains(&&**opcode) {
+                state.r.remove(&p2);
+            } else if OP_WRITES_P3.contains(&&**opcode) {
+                state.r.remove(&p3);
+            } else if opcode == OP_AFFINITY {
+                for register in p1..p1 + p2 {
+                    state.r.remove(&register);
                 }
             }
 
-            OP_AGG_STEP => {
-                let p4 = from_utf8(p4).map_err(Error::protocol)?;
+            match &**opcode {
+                OP_INIT | OP_GOTO => {
+                    // goto <p2>
+                    state.program_i = p2 as usize;
+                    continue;
+                }
 
-                if p4.starts_with("count(") {
-                    // count(_) -> INTEGER
-                    r.insert(p3, DataType::Int64);
-                    n.insert(p3, false);
-                } else if let Some(v) = r.get(&p2).copied() {
-                    // r[p3] = AGG ( r[p2] )
-                    r.insert(p3, v);
-                    let val = n.get(&p2).copied().unwrap_or(true);
-                    n.insert(p3, val);
+                OP_HALT => {
+                    break;
                 }
-            }
 
-            OP_CAST => {
-                // affinity(r[p1])
-                if let Some(v) = r.get_mut(&p1) {
-                    *v = affinity_to_type(p2 as u8);
+                OP_GOSUB => {
+                    // r[p1] = <return address>; goto <p2>
+                    state
+                        .r
+                        .insert(p1, RegDataType::Address(state.program_i + 1));
+
+                    state.program_i = p2 as usize;
+                    continue;
                 }
-            }
 
-            OP_COPY | OP_MOVE | OP_SCOPY | OP_INT_COPY => {
-                // r[p2] = r[p1]
-                if let Some(v) = r.get(&p1).copied() {
-                    r.insert(p2, v);
-                    let val = n.get(&p1).copied().unwrap_or(true);
-                    n.insert(p2, val);
+                OP_RETURN => {
+                    // goto r[p1]
+                    if let Some(RegDataType::Address(i)) = state.r.get(&p1) {
+                        state.program_i = *i;
+                        continue;
+                    }
+
+                    break;
                 }
-            }
 
-            OP_OR | OP_AND | OP_BLOB | OP_COUNT | OP_REAL | OP_STRING8 | OP_INTEGER | OP_ROWID => {
-                // r[p2] = <value of constant>
-                r.insert(p2, opcode_to_type(&opcode));
-                n.insert(p2, false);
-            }
+                OP_INIT_COROUTINE => {
+                    // r[p1] = <p3>; goto <p2> (if not 0)
+                    state.r.insert(p1, RegDataType::Address(p3 as usize));
 
-            OP_NOT => {
-                // r[p2] = NOT r[p1]
-                if let Some(a) = r.get(&p1).copied() {
-                    r.insert(p2, a);
-                    let val = n.get(&p1).copied().unwrap_or(true);
-                    n.insert(p2, val);
+                    if p2 != 0 {
+                        state.program_i = p2 as usize;
+                        continue;
+                    }
                 }
-            }
 
-            OP_BIT_AND | OP_BIT_OR | OP_SHIFT_LEFT | OP_SHIFT_RIGHT | OP_ADD | OP_SUBTRACT
-            | OP_MULTIPLY | OP_DIVIDE | OP_REMAINDER | OP_CONCAT => {
-                // r[p3] = r[p1] + r[p2]
-                match (r.get(&p1).copied(), r.get(&p2).copied()) {
-                    (Some(a), Some(b)) => {
-                        r.insert(p3, if matches!(a, DataType::Null) { b } else { a });
+                OP_YIELD => {
+                    // swap the program counter with r[p1]
+                    if let Some(RegDataType::Address(i)) = state.r.get(&p1).cloned() {
+                        state
+                            .r
+                            .insert(p1, RegDataType::Address(state.program_i + 1));
+
+                        state.program_i = i;
+                        continue;
                     }
 
-                    (Some(v), None) => {
-                        r.insert(p3, v);
+                    break;
+                }
+
+                OP_END_COROUTINE => {
+                    // r[p1] points just past the `Yield` that resumed this coroutine;
+                    // goto <p2> of that `Yield`
+                    if let Some(RegDataType::Address(i)) = state.r.get(&p1) {
+                        if let Some((_, _, _, yield_p2, ..)) = i.checked_sub(1).map(|i| &program[i])
+                        {
+                            state.program_i = *yield_p2 as usize;
+                            continue;
+                        }
                     }
 
-                    (None, Some(v)) => {
-                        r.insert(p3, v);
+                    break;
+                }
+
+                OP_JUMP => {
+                    // goto <p1>, <p2> or <p3>
+                    states.push(state.branch(p1));
+                    states.push(state.branch(p2));
+
+                    state.program_i = p3 as usize;
+                    continue;
+                }
+
+                OP_IF | OP_IF_NOT | OP_IF_POS | OP_IF_NOT_ZERO => {
+                    // jump to <p2> depending on the integer in r[p1]
+                    match state.int(p1) {
+                        Some(value) => {
+                            let jump = match &**opcode {
+                                OP_IF => value != 0,
+                                OP_IF_NOT => value == 0,
+                                OP_IF_POS => value > 0,
+                                _ => value != 0,
+                            };
+
+                            if jump {
+                                state.program_i = p2 as usize;
+                                continue;
+                            }
+                        }
+
+                        None => {
+                            states.push(state.branch(p2));
+                        }
                     }
+                }
+
+                OP_NOT_NULL => {
+                    // if r[p1] is not NULL goto <p2>
+                    let mut branch = state.branch(p2);
+                    branch.set_nullable(p1, false);
+                    states.push(branch);
+
+                    state.set(p1, ColumnType::NULL);
+                }
+
+                OP_IS_NULL => {
+                    // if r[p1] is NULL goto <p2>
+                    let mut branch = state.branch(p2);
+                    branch.set(p1, ColumnType::NULL);
+                    states.push(branch);
+
+                    state.set_nullable(p1, false);
+                }
+
+                OP_IF_NULL_ROW if state.is_null_row(p1) => {
+                    // the cursor <p1> is at a NULL row; r[p3] = NULL and goto <p2>
+                    state.set(p3, ColumnType::NULL);
+                    state.program_i = p2 as usize;
+                    continue;
+                }
 
-                    _ => {}
+                OP_SORTER_SORT if !state.p.contains_key(&p1) => {
+                    // nothing was inserted into the sorter <p1> on this branch, so it is
+                    // empty; goto <p2>
+                    state.program_i = p2 as usize;
+                    continue;
                 }
 
-                match (n.get(&p1).copied(), n.get(&p2).copied()) {
-                    (Some(a), Some(b)) => {
-                        n.insert(p3, a || b);
+                _ if OP_BRANCHES.contains(&&**opcode) => {
+                    // maybe goto <p2>
+                    states.push(state.branch(p2));
+                }
+
+                OP_OPEN_READ | OP_REOPEN_IDX => {
+                    // cursor <p1> reads the table or index at root page <p2> of database <p3>
+                    let columns = match root_blocks.get(&(p3, p2)) {
+                        Some(columns) => columns.clone(),
+
+                        None => {
+                            let columns = root_block_columns(conn, p3, p2).await?;
+                            root_blocks.insert((p3, p2), columns.clone());
+                            columns
+                        }
+                    };
+
+                    state.p.insert(
+                        p1,
+                        CursorDataType::Normal {
+                            columns: columns.unwrap_or_default(),
+                            is_null_row: false,
+                        },
+                    );
+                }
+
+                OP_OPEN_DUP => {
+                    // cursor <p1> = cursor <p2>
+                    if let Some(cursor) = state.p.get(&p2).cloned() {
+                        state.p.insert(p1, cursor);
                     }
+                }
+
+                OP_OPEN_PSEUDO => {
+                    // cursor <p1> reads the record in r[p2]
+                    state.p.insert(p1, CursorDataType::Pseudo(p2));
+                }
 
-                    (None, Some(b)) => {
-                        n.insert(p3, b);
+                OP_NULL_ROW => {
+                    // cursor <p1> is at a row where every column is NULL
+                    match state.p.get_mut(&p1) {
+                        Some(CursorDataType::Normal { is_null_row, .. }) => {
+                            *is_null_row = true;
+                        }
+
+                        _ => {
+                            state.p.insert(
+                                p1,
+                                CursorDataType::Normal {
+                                    columns: Vec::new(),
+                                    is_null_row: true,
+                                },
+                            );
+                        }
                     }
+                }
 
-                    (Some(a), None) => {
-                        n.insert(p3, a);
+                OP_MAKE_RECORD => {
+                    // r[p3] = <record of r[p1 .. p1 + p2]>
+                    let columns = (p1..p1 + p2).map(|i| state.column_type(i)).collect();
+                    state.r.insert(p3, RegDataType::Record(columns));
+                }
+
+                OP_SORTER_INSERT | OP_IDX_INSERT => {
+                    // cursor <p1> stores the record in r[p2]
+                    if let Some(RegDataType::Record(columns)) = state.r.get(&p2).cloned() {
+                        state.p.insert(
+                            p1,
+                            CursorDataType::Normal {
+                                columns,
+                                is_null_row: false,
+                            },
+                        );
                     }
+                }
 
-                    _ => {}
+                OP_SORTER_DATA => {
+                    // r[p2] = <record of the current row of cursor p1>
+                    if let Some(CursorDataType::Normal { columns, .. }) = state.p.get(&p1) {
+                        let columns = columns.clone();
+                        state.r.insert(p2, RegDataType::Record(columns));
+                    }
                 }
-            }
 
-            OP_RESULT_ROW => {
-                // output = r[p1 .. p1 + p2]
-                let mut output = Vec::with_capacity(p2 as usize);
-                let mut nullable = Vec::with_capacity(p2 as usize);
+                OP_COLUMN => {
+                    // r[p3] = <value of column p2 of cursor p1>
+                    let ty = state.cursor_column(p1, p2);
+                    state.set(p3, ty);
+                }
 
-                for i in p1..p1 + p2 {
-                    output.push(SqliteTypeInfo(r.remove(&i).unwrap_or(DataType::Null)));
+                OP_ROWID | OP_IDX_ROWID => {
+                    // r[p2] = <rowid of cursor p1>
+                    let nullable = state.is_null_row(p1);
+
+                    state.set(
+                        p2,
+                        ColumnType {
+                            datatype: DataType::Int64,
+                            nullable: Some(nullable),
+                        },
+                    );
+                }
+
+                OP_NULL => {
+                    // r[p2 ..= p3] = NULL
+                    for i in p2..=p2.max(p3) {
+                        state.set(i, ColumnType::NULL);
+                    }
+                }
+
+                OP_VARIABLE => {
+                    // r[p2] = <value of variable>
+                    state.set(p2, ColumnType::UNKNOWN);
+                }
+
+                OP_FUNCTION | OP_FUNCTION0 | OP_PURE_FUNC | OP_PURE_FUNC0 => {
+                    // r[p3] = func( r[p2 .. p2 + <args>] )
+                    let (name, args) = parse_function(p4)?;
+                    let args: Vec<_> = (p2..p2 + args).map(|i| state.column_type(i)).collect();
+
+                    state.set(p3, function_type(name, &args));
+                }
+
+                OP_AGG_STEP => {
+                    // r[p3] = AGG ( r[p2 .. ] )
+                    let (name, _) = parse_function(p4)?;
+                    let ty = aggregate_type(name, state.column_type(p2));
+
+                    state.set(p3, ty);
+                }
+
+                OP_AGG_FINAL => {
+                    // r[p1] = AGG ( r[p1] )
+                    let (name, _) = parse_function(p4)?;
+                    let ty = aggregate_type(name, state.column_type(p1));
+
+                    state.set(p1, ty);
+                }
+
+                OP_CAST => {
+                    // affinity(r[p1])
+                    let mut ty = state.column_type(p1);
+                    ty.datatype = affinity_to_type(p2 as u8);
+                    state.set(p1, ty);
+                }
+
+                OP_COPY | OP_MOVE => {
+                    // r[p2 .. p2 + n] = r[p1 .. p1 + n]
+                    // where n is p3 + 1 for `Copy` and p3 for `Move`
+                    let n = if &**opcode == OP_COPY { p3 + 1 } else { p3 };
+
+                    for i in 0..n {
+                        if let Some(v) = state.r.get(&(p1 + i)).cloned() {
+                            state.r.insert(p2 + i, v);
+                        }
+                    }
+                }
+
+                OP_SCOPY | OP_INT_COPY => {
+                    // r[p2] = r[p1]
+                    if let Some(v) = state.r.get(&p1).cloned() {
+                        state.r.insert(p2, v);
+                    }
+                }
+
+                OP_COLL_SEQ if p1 != 0 => {
+                    // r[p1] = 0; a following `min` or `max` sets it to 1 if the row
+                    // does not replace the current extreme, which can not happen
+                    // for the first row of a group
+                    state.r.insert(p1, RegDataType::Int(0));
+                }
+
+                OP_INTEGER => {
+                    // r[p2] = p1
+                    state.r.insert(p2, RegDataType::Int(p1));
+                }
+
+                OP_BLOB | OP_COUNT | OP_REAL | OP_STRING | OP_STRING8 | OP_INT64 => {
+                    // r[p2] = <value of constant>
+                    state.set(p2, ColumnType::not_null(opcode_to_type(opcode)));
+                }
+
+                OP_NOT => {
+                    // r[p2] = NOT r[p1]
+                    let ty = state.column_type(p1);
+                    state.set(p2, ty);
+                }
 
-                    nullable.push(if n.remove(&i).unwrap_or(true) {
-                        None
-                    } else {
-                        Some(false)
+                OP_OR | OP_AND => {
+                    // r[p3] = r[p1] AND r[p2]
+                    let ty = state.column_type(p1).merge(state.column_type(p2));
+
+                    state.set(
+                        p3,
+                        ColumnType {
+                            datatype: opcode_to_type(opcode),
+                            nullable: ty.nullable,
+                        },
+                    );
+                }
+
+                OP_DIVIDE | OP_REMAINDER => {
+                    // r[p3] = r[p2] / r[p1], which is NULL when dividing by zero
+                    let ty = state.column_type(p1).merge(state.column_type(p2));
+                    state.set(p3, ColumnType::nullable(ty.datatype));
+                }
+
+                OP_BIT_AND | OP_BIT_OR | OP_SHIFT_LEFT | OP_SHIFT_RIGHT | OP_ADD | OP_SUBTRACT
+                | OP_MULTIPLY | OP_CONCAT => {
+                    // r[p3] = r[p1] + r[p2]
+                    let ty = state.column_type(p1).merge(state.column_type(p2));
+                    state.set(p3, ty);
+                }
+
+                OP_RESULT_ROW => {
+                    // output = r[p1 .. p1 + p2]
+                    let row: Vec<_> = (p1..p1 + p2).map(|i| state.column_type(i)).collect();
+
+                    result = Some(match result {
+                        Some(result) => result
+                            .into_iter()
+                            .zip(row)
+                            .map(|(a, b)| a.merge(b))
+                            .collect(),
+
+                        None => row,
                     });
                 }
 
-                return Ok((output, nullable));
+                _ => {
+                    // ignore unsupported operations
+                    // if we fail to find an r later, we just give up
+                }
             }
 
-            _ => {
-                // ignore unsupported operations
-                // if we fail to find an r later, we just give up
-            }
+            state.program_i += 1;
         }
-
-        program_i += 1;
     }
 
-    // no rows
-    Ok((vec![], vec![]))
+    let result = result.unwrap_or_default();
+
+    let output = result
+        .iter()
+        .map(|ty| SqliteTypeInfo(ty.datatype))
+        .collect();
+
+    let nullable = result
+        .iter()
+        .map(|ty| if exhausted { None } else { ty.nullable })
+        .collect();
+
+    Ok((output, nullable))
 }
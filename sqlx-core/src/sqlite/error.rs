@@ -35,6 +35,15 @@ impl SqliteError {
             message: message.to_owned(),
         }
     }
+
+    /// Creates an error from a result code and a message that was not reported
+    /// through [sqlite3_errmsg], such as the error message of [sqlite3_load_extension].
+    pub(crate) fn with_message(code: c_int, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
 }
 
 impl Display for SqliteError {
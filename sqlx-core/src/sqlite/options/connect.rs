@@ -2,11 +2,19 @@ use crate::connection::ConnectOptions;
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::sqlite::connection::establish::establish;
+use crate::sqlite::options::SqliteAttachment;
 use crate::sqlite::{SqliteConnectOptions, SqliteConnection};
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use std::fmt::Write;
+use std::io;
 use std::time::Duration;
 
+// characters with a special meaning in the path of a SQLite URI
+// <https://www.sqlite.org/uri.html>
+const URI_PATH: &AsciiSet = &CONTROLS.add(b'?').add(b'#').add(b'%');
+
 impl ConnectOptions for SqliteConnectOptions {
     type Connection = SqliteConnection;
 
@@ -18,12 +26,16 @@ impl ConnectOptions for SqliteConnectOptions {
             let mut conn = establish(self).await?;
 
             // send an initial sql statement comprised of options
-            let init = format!(
+            let mut init = format!(
                 "PRAGMA journal_mode = {}; PRAGMA foreign_keys = {};",
                 self.journal_mode.as_str(),
                 if self.foreign_keys { "ON" } else { "OFF" }
             );
 
+            for attachment in &self.attachments {
+                write_attach(&mut init, attachment)?;
+            }
+
             conn.execute(&*init).await?;
 
             Ok(conn)
@@ -40,3 +52,30 @@ impl ConnectOptions for SqliteConnectOptions {
         self
     }
 }
+
+fn write_attach(sql: &mut String, attachment: &SqliteAttachment) -> Result<(), Error> {
+    let path = attachment.path.to_str().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "path of an attached database must be valid UTF-8",
+        )
+    })?;
+
+    // a read-only attachment must be opened through a URI filename, which is
+    // possible as long as the connection was opened with [SQLITE_OPEN_URI]
+    let filename = if attachment.read_only {
+        format!("file:{}?mode=ro", utf8_percent_encode(path, URI_PATH))
+    } else {
+        path.to_owned()
+    };
+
+    // NOTE: `fmt::Write` for `String` is infallible
+    let _ = write!(
+        sql,
+        " ATTACH DATABASE '{}' AS \"{}\";",
+        filename.replace('\'', "''"),
+        attachment.schema_name.replace('"', "\"\"")
+    );
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 mod connect;
 mod journal_mode;
@@ -16,10 +16,13 @@ use std::{borrow::Cow, time::Duration};
 /// | URI | Description |
 /// | -- | -- |
 /// `sqlite::memory:` | Open an in-memory database. |
+/// `sqlite:file:name?mode=memory&cache=shared` | Open the in-memory database `name`, shared by every connection that opens it. |
 /// `sqlite:data.db` | Open the file `data.db` in the current directory. |
 /// `sqlite://data.db` | Open the file `data.db` in the current directory. |
 /// `sqlite:///data.db` | Open the file `data.db` from the root (`/`) directory. |
 /// `sqlite://data.db?mode=ro` | Open the file `data.db` for read-only access. |
+/// `sqlite://data.db?attach=archive:archive.db` | Open the file `data.db` and attach `archive.db` as the schema `archive`. |
+/// `sqlite://data.db?attach_ro=archive:archive.db` | As above, but `archive.db` is attached for read-only access. |
 ///
 /// # Example
 ///
@@ -53,6 +56,23 @@ pub struct SqliteConnectOptions {
     pub(crate) statement_cache_capacity: usize,
     pub(crate) busy_timeout: Duration,
     pub(crate) log_settings: LogSettings,
+    pub(crate) attachments: Vec<SqliteAttachment>,
+    pub(crate) extensions: Vec<SqliteExtension>,
+}
+
+/// A database to be attached to every connection, see [`SqliteConnectOptions::attach`].
+#[derive(Clone, Debug)]
+pub(crate) struct SqliteAttachment {
+    pub(crate) path: PathBuf,
+    pub(crate) schema_name: String,
+    pub(crate) read_only: bool,
+}
+
+/// An extension to be loaded into every connection, see [`SqliteConnectOptions::extension`].
+#[derive(Clone, Debug)]
+pub(crate) struct SqliteExtension {
+    pub(crate) path: PathBuf,
+    pub(crate) entry_point: Option<String>,
 }
 
 impl Default for SqliteConnectOptions {
@@ -74,6 +94,8 @@ impl SqliteConnectOptions {
             journal_mode: SqliteJournalMode::Wal,
             busy_timeout: Duration::from_secs(5),
             log_settings: Default::default(),
+            attachments: Vec::new(),
+            extensions: Vec::new(),
         }
     }
 
@@ -83,6 +105,20 @@ impl SqliteConnectOptions {
         self
     }
 
+    /// Sets the database to a named, in-memory database.
+    ///
+    /// Unlike `:memory:`, which gives every connection its own private database, every
+    /// connection opened with these options (e.g., every connection in a `Pool`) sees the same
+    /// database. This enables the [shared cache](https://www.sqlite.org/sharedcache.html).
+    ///
+    /// The database is destroyed once the last connection to it is closed.
+    pub fn in_memory_named(mut self, name: &str) -> Self {
+        self.filename = Cow::Owned(PathBuf::from(format!("file:{}", name)));
+        self.in_memory = true;
+        self.shared_cache = true;
+        self
+    }
+
     /// Set the enforcement of [foreign key constriants](https://www.sqlite.org/pragma.html#pragma_foreign_keys).
     ///
     /// By default, this is enabled.
@@ -116,6 +152,56 @@ impl SqliteConnectOptions {
         self
     }
 
+    /// Sets the [shared cache](https://www.sqlite.org/sharedcache.html) mode for the connection.
+    ///
+    /// A shared cache is required to persist data across connections to a
+    /// named in-memory database.
+    ///
+    /// By default, this is disabled.
+    pub fn shared_cache(mut self, on: bool) -> Self {
+        self.shared_cache = on;
+        self
+    }
+
+    /// Attaches the database file at `path` as the schema `schema_name` with
+    /// [`ATTACH DATABASE`](https://www.sqlite.org/lang_attach.html).
+    ///
+    /// The database is attached to every connection opened with these options, including
+    /// every connection in a `Pool`. Tables in the attached database are addressed as
+    /// `schema_name.table`.
+    pub fn attach(
+        mut self,
+        path: impl AsRef<Path>,
+        schema_name: impl Into<String>,
+        read_only: bool,
+    ) -> Self {
+        self.attachments.push(SqliteAttachment {
+            path: path.as_ref().to_owned(),
+            schema_name: schema_name.into(),
+            read_only,
+        });
+
+        self
+    }
+
+    /// Loads the [extension](https://www.sqlite.org/loadext.html) at `path` into every
+    /// connection opened with these options.
+    ///
+    /// The `entry_point` is the name of the extension's initialization function. If `None`,
+    /// SQLite derives it from the filename of the extension.
+    ///
+    /// Loading extensions is enabled only for as long as it takes to load them and is
+    /// disabled again before the connection is returned, so `load_extension()` is never
+    /// available to SQL.
+    pub fn extension(mut self, path: impl AsRef<Path>, entry_point: Option<&str>) -> Self {
+        self.extensions.push(SqliteExtension {
+            path: path.as_ref().to_owned(),
+            entry_point: entry_point.map(ToOwned::to_owned),
+        });
+
+        self
+    }
+
     /// Sets the capacity of the connection's statement cache in a number of stored
     /// distinct statements. Caching is handled using LRU, meaning when the
     /// amount of queries hits the defined limit, the oldest statement will get
@@ -94,6 +94,30 @@ impl FromStr for SqliteConnectOptions {
                         }
                     },
 
+                    // Attach the database file at <path> as the schema <name>, given as
+                    // `attach=<name>:<path>`. With `attach_ro`, the database is attached
+                    // for read-only access.
+                    "attach" | "attach_ro" => {
+                        let mut name_and_path = value.splitn(2, ':');
+                        let name = name_and_path.next().unwrap_or_default();
+
+                        let path = match name_and_path.next() {
+                            Some(path) if !name.is_empty() => path,
+
+                            _ => {
+                                return Err(Error::Configuration(
+                                    format!(
+                                        "expected `<name>:<path>` for `{}`, got {:?}",
+                                        key, value
+                                    )
+                                    .into(),
+                                ));
+                            }
+                        };
+
+                        options = options.attach(path, name, key == "attach_ro");
+                    }
+
                     _ => {
                         return Err(Error::Configuration(
                             format!(
@@ -149,3 +173,37 @@ fn test_parse_shared_in_memory() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_parse_named_in_memory() -> Result<(), Error> {
+    let options: SqliteConnectOptions = "sqlite:file:shared-db?mode=memory&cache=shared".parse()?;
+    assert!(options.in_memory);
+    assert!(options.shared_cache);
+    assert_eq!(&*options.filename.to_string_lossy(), "file:shared-db");
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_attach() -> Result<(), Error> {
+    let options: SqliteConnectOptions =
+        "sqlite://a.db?attach=b:b.db&attach_ro=archive:data/archive.db".parse()?;
+    assert_eq!(options.attachments.len(), 2);
+
+    assert_eq!(options.attachments[0].schema_name, "b");
+    assert_eq!(&*options.attachments[0].path.to_string_lossy(), "b.db");
+    assert!(!options.attachments[0].read_only);
+
+    assert_eq!(options.attachments[1].schema_name, "archive");
+    assert_eq!(
+        &*options.attachments[1].path.to_string_lossy(),
+        "data/archive.db"
+    );
+    assert!(options.attachments[1].read_only);
+
+    assert!("sqlite://a.db?attach=b.db"
+        .parse::<SqliteConnectOptions>()
+        .is_err());
+
+    Ok(())
+}
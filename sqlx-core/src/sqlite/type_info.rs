@@ -15,9 +15,6 @@ pub(crate) enum DataType {
     Float,
     Text,
     Blob,
-
-    // TODO: Support NUMERIC
-    #[allow(dead_code)]
     Numeric,
 
     // non-standard extensions
@@ -102,6 +99,8 @@ impl FromStr for DataType {
 
             _ if s.contains("real") || s.contains("floa") || s.contains("doub") => DataType::Float,
 
+            _ if s.contains("numeric") || s.contains("decimal") => DataType::Numeric,
+
             _ => {
                 return Err(format!("unknown type: `{}`", s).into());
             }
@@ -141,6 +140,9 @@ fn test_data_type_from_str() -> Result<(), BoxDynError> {
     assert_eq!(DataType::Float, "FLOAT".parse()?);
     assert_eq!(DataType::Float, "DOUBLE PRECISION".parse()?);
 
+    assert_eq!(DataType::Numeric, "NUMERIC".parse()?);
+    assert_eq!(DataType::Numeric, "DECIMAL(10,5)".parse()?);
+
     assert_eq!(DataType::Bool, "BOOLEAN".parse()?);
     assert_eq!(DataType::Bool, "BOOL".parse()?);
 
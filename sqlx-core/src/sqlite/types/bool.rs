@@ -4,6 +4,7 @@ use crate::error::BoxDynError;
 use crate::sqlite::type_info::DataType;
 use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
 use crate::types::Type;
+use crate::value::ValueRef;
 
 impl Type<Sqlite> for bool {
     fn type_info() -> SqliteTypeInfo {
@@ -11,7 +12,10 @@ impl Type<Sqlite> for bool {
     }
 
     fn compatible(ty: &SqliteTypeInfo) -> bool {
-        matches!(ty.0, DataType::Bool | DataType::Int | DataType::Int64)
+        matches!(
+            ty.0,
+            DataType::Bool | DataType::Int | DataType::Int64 | DataType::Text
+        )
     }
 }
 
@@ -25,6 +29,19 @@ impl<'q> Encode<'q, Sqlite> for bool {
 
 impl<'r> Decode<'r, Sqlite> for bool {
     fn decode(value: SqliteValueRef<'r>) -> Result<bool, BoxDynError> {
-        Ok(value.int() != 0)
+        if value.type_info().0 != DataType::Text {
+            return Ok(value.int() != 0);
+        }
+
+        // a boolean stored as TEXT; SQLite itself has no boolean literals
+        // beyond the integers 0 and 1
+        let text = value.text()?;
+
+        match &*text.to_ascii_lowercase() {
+            "1" | "t" | "true" => Ok(true),
+            "0" | "f" | "false" => Ok(false),
+
+            _ => Err(format!("invalid boolean: {:?}", text).into()),
+        }
     }
 }
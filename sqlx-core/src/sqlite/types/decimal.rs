@@ -0,0 +1,34 @@
+use rust_decimal::Decimal;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::sqlite::type_info::DataType;
+use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+use crate::types::Type;
+
+impl Type<Sqlite> for Decimal {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo(DataType::Numeric)
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(
+            ty.0,
+            DataType::Numeric | DataType::Text | DataType::Int | DataType::Int64 | DataType::Float
+        )
+    }
+}
+
+impl Encode<'_, Sqlite> for Decimal {
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'_>>) -> IsNull {
+        Encode::<Sqlite>::encode(self.to_string(), args)
+    }
+}
+
+impl Decode<'_, Sqlite> for Decimal {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        // SQLite renders INTEGER and REAL values as text for us
+        Ok(value.text()?.parse()?)
+    }
+}
@@ -9,6 +9,11 @@ impl Type<Sqlite> for f32 {
     fn type_info() -> SqliteTypeInfo {
         SqliteTypeInfo(DataType::Float)
     }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        // SQLite reports whole numbers in a column without REAL affinity as INTEGER
+        matches!(ty.0, DataType::Float | DataType::Int | DataType::Int64)
+    }
 }
 
 impl<'q> Encode<'q, Sqlite> for f32 {
@@ -29,6 +34,15 @@ impl Type<Sqlite> for f64 {
     fn type_info() -> SqliteTypeInfo {
         SqliteTypeInfo(DataType::Float)
     }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        // SQLite reports whole numbers in a column without REAL affinity as INTEGER;
+        // a declared NUMERIC column is best described by `f64` without a decimal type
+        matches!(
+            ty.0,
+            DataType::Float | DataType::Numeric | DataType::Int | DataType::Int64
+        )
+    }
 }
 
 impl<'q> Encode<'q, Sqlite> for f64 {
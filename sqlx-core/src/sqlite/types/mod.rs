@@ -13,6 +13,8 @@
 //! | `&str`, `String`                      | TEXT                                                 |
 //! | `&[u8]`, `Vec<u8>`                    | BLOB                                                 |
 //!
+//! A `bool` can also be decoded from TEXT holding `true`, `false`, `t`, `f`, `1` or `0`.
+//!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
 //! Requires the `chrono` Cargo feature flag.
@@ -22,6 +24,48 @@
 //! | `chrono::NaiveDateTime`               | DATETIME                                             |
 //! | `chrono::DateTime<Utc>`               | DATETIME                                             |
 //! | `chrono::DateTime<Local>`             | DATETIME                                             |
+//! | `chrono::NaiveDate`                   | DATE                                                 |
+//! | `chrono::NaiveTime`                   | TIME                                                 |
+//!
+//! ### [`time`](https://crates.io/crates/time)
+//!
+//! Requires the `time` Cargo feature flag.
+//!
+//! | Rust type                             | Sqlite type(s)                                       |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `time::PrimitiveDateTime`             | DATETIME                                             |
+//! | `time::OffsetDateTime`                | DATETIME                                             |
+//! | `time::Date`                          | DATE                                                 |
+//! | `time::Time`                          | TIME                                                 |
+//!
+//! Date and time values (from either crate) are stored as TEXT in the formats of the SQLite
+//! [date and time functions](https://www.sqlite.org/lang_datefunc.html): `YYYY-MM-DD`,
+//! `HH:MM:SS.SSS` and `YYYY-MM-DD HH:MM:SS.SSS` in UTC. Date-times can also be decoded from
+//! a Unix timestamp (INTEGER) or a Julian day number (REAL).
+//!
+//! ### [`uuid`](https://crates.io/crates/uuid)
+//!
+//! Requires the `uuid` Cargo feature flag.
+//!
+//! | Rust type                             | Sqlite type(s)                                       |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `uuid::Uuid`                          | BLOB, TEXT                                           |
+//! | `uuid::adapter::Hyphenated`           | TEXT                                                 |
+//!
+//! A `Uuid` is stored as a 16-byte BLOB and can be decoded from either a BLOB or TEXT
+//! in the hyphenated form. Use `Hyphenated` to store it as TEXT.
+//!
+//! ### [`rust_decimal`](https://crates.io/crates/rust_decimal) and [`bigdecimal`](https://crates.io/crates/bigdecimal)
+//!
+//! Requires the `decimal` or `bigdecimal` Cargo feature flag.
+//!
+//! | Rust type                             | Sqlite type(s)                                       |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `rust_decimal::Decimal`               | NUMERIC, TEXT                                        |
+//! | `bigdecimal::BigDecimal`              | NUMERIC, TEXT                                        |
+//!
+//! Decimals are stored as TEXT. Note that a column with NUMERIC affinity converts such text
+//! to an INTEGER or a (lossy) REAL; declare the column as TEXT to preserve the exact value.
 //!
 //! # Nullable
 //!
@@ -29,12 +73,20 @@
 //! a potentially `NULL` value from SQLite.
 //!
 
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 mod bool;
 mod bytes;
 #[cfg(feature = "chrono")]
 mod chrono;
+#[cfg(feature = "decimal")]
+mod decimal;
 mod float;
 mod int;
 #[cfg(feature = "json")]
 mod json;
 mod str;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "uuid")]
+mod uuid;
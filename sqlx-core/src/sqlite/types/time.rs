@@ -0,0 +1,280 @@
+use crate::common::date::date_from_unix_days;
+use crate::value::ValueRef;
+use crate::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    sqlite::{type_info::DataType, Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef},
+    types::Type,
+};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+// the Julian day of 1970-01-01
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+impl Type<Sqlite> for OffsetDateTime {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo(DataType::Datetime)
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <PrimitiveDateTime as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl Type<Sqlite> for PrimitiveDateTime {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo(DataType::Datetime)
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(
+            ty.0,
+            DataType::Datetime | DataType::Text | DataType::Int64 | DataType::Int | DataType::Float
+        )
+    }
+}
+
+impl Type<Sqlite> for Date {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo(DataType::Date)
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(ty.0, DataType::Date | DataType::Text)
+    }
+}
+
+impl Type<Sqlite> for Time {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo(DataType::Time)
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(ty.0, DataType::Time | DataType::Text)
+    }
+}
+
+impl Encode<'_, Sqlite> for OffsetDateTime {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'_>>) -> IsNull {
+        let utc = self.to_offset(UtcOffset::UTC);
+
+        Encode::<Sqlite>::encode(PrimitiveDateTime::new(utc.date(), utc.time()), buf)
+    }
+}
+
+impl Encode<'_, Sqlite> for PrimitiveDateTime {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'_>>) -> IsNull {
+        Encode::<Sqlite>::encode(
+            format!("{} {}", self.date().format("%F"), format_time(self.time())),
+            buf,
+        )
+    }
+}
+
+impl Encode<'_, Sqlite> for Date {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'_>>) -> IsNull {
+        Encode::<Sqlite>::encode(self.format("%F"), buf)
+    }
+}
+
+impl Encode<'_, Sqlite> for Time {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'_>>) -> IsNull {
+        Encode::<Sqlite>::encode(format_time(*self), buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for OffsetDateTime {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        let (dt, offset) = decode_datetime(value)?;
+
+        assume_offset(dt, offset).ok_or_else(|| format!("datetime out of range: {}", dt).into())
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for PrimitiveDateTime {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        // an offset is accepted but ignored, as for `chrono::NaiveDateTime`
+        Ok(decode_datetime(value)?.0)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for Date {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(Date::parse(value.text()?, "%F")?)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for Time {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        let value = value.text()?;
+
+        // an offset is accepted but ignored, as for `chrono::NaiveTime`
+        split_offset(value)
+            .and_then(|(time, _)| decode_time_from_text(time))
+            .ok_or_else(|| format!("invalid time: {}", value).into())
+    }
+}
+
+// formats a time as `HH:MM:SS`, followed by the fractional seconds (if any)
+fn format_time(time: Time) -> String {
+    let mut s = format!(
+        "{:02}:{:02}:{:02}",
+        time.hour(),
+        time.minute(),
+        time.second()
+    );
+
+    if time.nanosecond() != 0 {
+        let nanos = format!("{:09}", time.nanosecond());
+
+        s.push('.');
+        s.push_str(nanos.trim_end_matches('0'));
+    }
+
+    s
+}
+
+// decodes the date and time in its offset, and the offset
+fn decode_datetime(
+    value: SqliteValueRef<'_>,
+) -> Result<(PrimitiveDateTime, UtcOffset), BoxDynError> {
+    let dt = match value.type_info().0 {
+        DataType::Text => decode_datetime_from_text(value.text()?),
+        DataType::Int | DataType::Int64 => from_unix_timestamp(value.int64(), 0),
+        DataType::Float => decode_datetime_from_float(value.double()),
+
+        _ => None,
+    };
+
+    if let Some(dt) = dt {
+        Ok(dt)
+    } else {
+        Err(format!("invalid datetime: {}", value.text()?).into())
+    }
+}
+
+// accepts the formats of the SQLite date and time functions
+// <https://www.sqlite.org/lang_datefunc.html>
+fn decode_datetime_from_text(value: &str) -> Option<(PrimitiveDateTime, UtcOffset)> {
+    // `YYYY-MM-DD`, separated from the time by a space or a `T`
+    let date = Date::parse(value.get(..10)?, "%F").ok()?;
+
+    if !matches!(value.as_bytes().get(10), Some(b' ') | Some(b'T')) {
+        return None;
+    }
+
+    let (time, offset) = split_offset(value.get(11..)?)?;
+    let time = decode_time_from_text(time)?;
+
+    Some((PrimitiveDateTime::new(date, time), offset))
+}
+
+// splits a trailing `Z` or `[+-]HH:MM` from a time; no offset means UTC
+fn split_offset(value: &str) -> Option<(&str, UtcOffset)> {
+    if let Some(time) = value.strip_suffix('Z') {
+        return Some((time, UtcOffset::UTC));
+    }
+
+    let at = value.len().saturating_sub(6);
+    let (time, offset) = match (value.get(..at), value.get(at..)) {
+        (Some(time), Some(offset)) if offset.len() == 6 && offset.starts_with(&['+', '-'][..]) => {
+            (time, offset)
+        }
+
+        _ => return Some((value, UtcOffset::UTC)),
+    };
+
+    if offset.as_bytes()[3] != b':' {
+        return None;
+    }
+
+    let hours: i16 = offset.get(1..3)?.parse().ok()?;
+    let minutes: i16 = offset.get(4..)?.parse().ok()?;
+    let minutes = hours * 60 + minutes;
+
+    Some((
+        time,
+        UtcOffset::minutes(if offset.starts_with('-') {
+            -minutes
+        } else {
+            minutes
+        }),
+    ))
+}
+
+// `HH:MM`, `HH:MM:SS` or `HH:MM:SS.SSS` with up to 9 digits of fractional seconds
+fn decode_time_from_text(value: &str) -> Option<Time> {
+    let mut parts = value.splitn(3, ':');
+
+    let hour = parts.next()?.parse().ok()?;
+    let minute = parts.next()?.parse().ok()?;
+
+    let (second, nanosecond) = match parts.next() {
+        Some(seconds) => {
+            let mut seconds = seconds.splitn(2, '.');
+            let second = seconds.next()?.parse().ok()?;
+
+            let nanosecond = match seconds.next() {
+                Some(fraction) if fraction.len() <= 9 => {
+                    format!("{:0<9}", fraction).parse().ok()?
+                }
+                Some(_) => return None,
+                None => 0,
+            };
+
+            (second, nanosecond)
+        }
+
+        None => (0, 0),
+    };
+
+    Time::try_from_hms_nano(hour, minute, second, nanosecond).ok()
+}
+
+fn decode_datetime_from_float(value: f64) -> Option<(PrimitiveDateTime, UtcOffset)> {
+    // the value is a Julian day number
+    let epoch_in_julian_days = 2_440_587.5;
+    let seconds_in_day = 86400.0;
+    let timestamp = ((value - epoch_in_julian_days) * seconds_in_day).floor();
+
+    if !timestamp.is_finite() || timestamp.abs() >= i64::MAX as f64 {
+        return None;
+    }
+
+    let nanos = ((value - epoch_in_julian_days) * seconds_in_day - timestamp) * 1E9;
+
+    from_unix_timestamp(timestamp as i64, (nanos as u32).min(999_999_999))
+}
+
+// the date and time in UTC at `seconds` and `nanos` after the Unix epoch, where
+// `OffsetDateTime::from_unix_timestamp` would panic when out of range
+fn from_unix_timestamp(seconds: i64, nanos: u32) -> Option<(PrimitiveDateTime, UtcOffset)> {
+    let date = date_from_unix_days(seconds.div_euclid(86_400))?;
+    let seconds = seconds.rem_euclid(86_400);
+
+    let time = Time::try_from_hms_nano(
+        (seconds / 3600) as u8,
+        (seconds / 60 % 60) as u8,
+        (seconds % 60) as u8,
+        nanos,
+    )
+    .ok()?;
+
+    Some((PrimitiveDateTime::new(date, time), UtcOffset::UTC))
+}
+
+// the instant of a date and time in `offset`, where `PrimitiveDateTime::assume_offset`
+// would panic when out of range
+fn assume_offset(dt: PrimitiveDateTime, offset: UtcOffset) -> Option<OffsetDateTime> {
+    let time = dt.time();
+    let seconds = (dt.date().julian_day() - UNIX_EPOCH_JULIAN_DAY) * 86_400
+        + i64::from(time.hour()) * 3600
+        + i64::from(time.minute()) * 60
+        + i64::from(time.second())
+        - i64::from(offset.as_seconds());
+
+    let (utc, _) = from_unix_timestamp(seconds, time.nanosecond())?;
+
+    Some(utc.assume_utc().to_offset(offset))
+}
@@ -0,0 +1,64 @@
+use std::borrow::Cow;
+
+use uuid::{adapter::Hyphenated, Uuid};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::sqlite::type_info::DataType;
+use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+use crate::types::Type;
+use crate::value::ValueRef;
+
+impl Type<Sqlite> for Uuid {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo(DataType::Blob)
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(ty.0, DataType::Blob | DataType::Text)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for Uuid {
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        args.push(SqliteArgumentValue::Blob(Cow::Owned(
+            self.as_bytes().to_vec(),
+        )));
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Sqlite> for Uuid {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.type_info().0 {
+            // a UUID stored as TEXT, e.g., by [Hyphenated]
+            DataType::Text => Uuid::parse_str(value.text()?).map_err(Into::into),
+
+            // construct a Uuid from the 16 bytes of the BLOB
+            _ => Uuid::from_slice(value.blob()).map_err(Into::into),
+        }
+    }
+}
+
+impl Type<Sqlite> for Hyphenated {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo(DataType::Text)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for Hyphenated {
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        args.push(SqliteArgumentValue::Text(Cow::Owned(self.to_string())));
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Sqlite> for Hyphenated {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        // parse a UUID from the text
+        Ok(Uuid::parse_str(value.text()?)?.to_hyphenated())
+    }
+}
@@ -40,10 +40,14 @@ pub mod chrono {
 #[cfg_attr(docsrs, doc(cfg(feature = "bit-vec")))]
 pub use bit_vec::BitVec;
 
+#[cfg(feature = "geo-types")]
+#[cfg_attr(docsrs, doc(cfg(feature = "geo-types")))]
+pub use geo_types;
+
 #[cfg(feature = "time")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
 pub mod time {
-    pub use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+    pub use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 }
 
 #[cfg(feature = "bigdecimal")]
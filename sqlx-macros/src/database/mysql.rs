@@ -52,6 +52,9 @@ impl_database_ext! {
 
         #[cfg(feature = "json")]
         serde_json::Value,
+
+        #[cfg(feature = "geo-types")]
+        sqlx::types::geo_types::Geometry<f64>,
     },
     ParamChecking::Weak,
     feature-types: info => info.__type_feature_gate(),
@@ -16,6 +16,30 @@ impl_database_ext! {
 
         sqlx::postgres::types::PgInterval,
 
+        sqlx::postgres::types::PgPoint,
+        sqlx::postgres::types::PgLine,
+        sqlx::postgres::types::PgLseg,
+        sqlx::postgres::types::PgBox,
+        sqlx::postgres::types::PgPath,
+        sqlx::postgres::types::PgPolygon,
+        sqlx::postgres::types::PgCircle,
+
+        sqlx::postgres::types::PgTsVector,
+        sqlx::postgres::types::PgTsQuery,
+
+        sqlx::postgres::types::PgMacAddr,
+        sqlx::postgres::types::PgMacAddr8,
+        sqlx::postgres::types::PgLsn,
+        sqlx::postgres::types::PgXml,
+
+        // OID columns infer `u32`, which comes first, but also accept `Oid`
+        sqlx::postgres::types::Oid,
+
+        sqlx::postgres::types::PgLTree,
+        sqlx::postgres::types::PgLQuery,
+
+        std::collections::HashMap<String, Option<String>>,
+
         #[cfg(feature = "uuid")]
         sqlx::types::Uuid,
 
@@ -76,6 +100,23 @@ impl_database_ext! {
         Vec<f32> | &[f32],
         Vec<f64> | &[f64],
 
+        Vec<sqlx::postgres::types::PgPoint> | &[sqlx::postgres::types::PgPoint],
+        Vec<sqlx::postgres::types::PgLine> | &[sqlx::postgres::types::PgLine],
+        Vec<sqlx::postgres::types::PgLseg> | &[sqlx::postgres::types::PgLseg],
+        Vec<sqlx::postgres::types::PgBox> | &[sqlx::postgres::types::PgBox],
+        Vec<sqlx::postgres::types::PgPath> | &[sqlx::postgres::types::PgPath],
+        Vec<sqlx::postgres::types::PgPolygon> | &[sqlx::postgres::types::PgPolygon],
+        Vec<sqlx::postgres::types::PgCircle> | &[sqlx::postgres::types::PgCircle],
+
+        Vec<sqlx::postgres::types::PgTsVector> | &[sqlx::postgres::types::PgTsVector],
+        Vec<sqlx::postgres::types::PgTsQuery> | &[sqlx::postgres::types::PgTsQuery],
+
+        Vec<sqlx::postgres::types::PgMacAddr> | &[sqlx::postgres::types::PgMacAddr],
+        Vec<sqlx::postgres::types::PgMacAddr8> | &[sqlx::postgres::types::PgMacAddr8],
+        Vec<sqlx::postgres::types::PgLsn> | &[sqlx::postgres::types::PgLsn],
+        Vec<sqlx::postgres::types::PgXml> | &[sqlx::postgres::types::PgXml],
+        Vec<sqlx::postgres::types::Oid> | &[sqlx::postgres::types::Oid],
+
         #[cfg(feature = "uuid")]
         Vec<sqlx::types::Uuid> | &[sqlx::types::Uuid],
 
@@ -139,6 +180,33 @@ impl_database_ext! {
         #[cfg(feature = "time")]
         sqlx::postgres::types::PgRange<sqlx::types::time::OffsetDateTime>,
 
+        // Multiranges
+
+        sqlx::postgres::types::PgMultirange<i32>,
+        sqlx::postgres::types::PgMultirange<i64>,
+
+        #[cfg(feature = "bigdecimal")]
+        sqlx::postgres::types::PgMultirange<sqlx::types::BigDecimal>,
+
+        #[cfg(feature = "chrono")]
+        sqlx::postgres::types::PgMultirange<sqlx::types::chrono::NaiveDate>,
+
+        #[cfg(feature = "chrono")]
+        sqlx::postgres::types::PgMultirange<sqlx::types::chrono::NaiveDateTime>,
+
+        #[cfg(feature = "chrono")]
+        sqlx::postgres::types::PgMultirange<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>> |
+            sqlx::postgres::types::PgMultirange<sqlx::types::chrono::DateTime<_>>,
+
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgMultirange<sqlx::types::time::Date>,
+
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgMultirange<sqlx::types::time::PrimitiveDateTime>,
+
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgMultirange<sqlx::types::time::OffsetDateTime>,
+
         // Range arrays
 
         Vec<sqlx::postgres::types::PgRange<i32>> | &[sqlx::postgres::types::PgRange<i32>],
@@ -40,6 +40,7 @@ pub struct SqlxContainerAttributes {
     pub rename: Option<String>,
     pub rename_all: Option<RenameAll>,
     pub repr: Option<Ident>,
+    pub array_type: Option<String>,
 }
 
 pub struct SqlxChildAttributes {
@@ -52,6 +53,7 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
     let mut repr = None;
     let mut rename = None;
     let mut rename_all = None;
+    let mut array_type = None;
 
     for attr in input {
         let meta = attr
@@ -90,6 +92,14 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                                 ..
                             }) if path.is_ident("rename") => try_set!(rename, val.value(), value),
 
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("array_type") => {
+                                try_set!(array_type, val.value(), value)
+                            }
+
                             u => fail!(u, "unexpected attribute"),
                         },
                         u => fail!(u, "unexpected attribute"),
@@ -116,6 +126,7 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
         repr,
         rename,
         rename_all,
+        array_type,
     })
 }
 
@@ -163,6 +174,12 @@ pub fn check_transparent_attributes(
 
     assert_attribute!(attributes.repr.is_none(), "unexpected #[repr(..)]", input);
 
+    assert_attribute!(
+        !(attributes.transparent && attributes.array_type.is_some()),
+        "unexpected #[sqlx(array_type = ..)]",
+        input
+    );
+
     let ch_attributes = parse_child_attributes(&field.attrs)?;
 
     assert_attribute!(
@@ -200,6 +217,12 @@ pub fn check_weak_enum_attributes(
         input
     );
 
+    assert_attribute!(
+        attributes.array_type.is_none(),
+        "unexpected #[sqlx(array_type = ..)]",
+        input
+    );
+
     for variant in variants {
         let attributes = parse_child_attributes(&variant.attrs)?;
 
@@ -84,6 +84,7 @@ fn expand_derive_has_sql_type_transparent(
 
     if cfg!(feature = "postgres") {
         let ty_name = attr.rename.unwrap_or_else(|| ident.to_string());
+        let array_ty_name = attr.array_type.unwrap_or_else(|| format!("_{}", ty_name));
 
         tts.extend(quote!(
             impl sqlx::Type< sqlx::postgres::Postgres > for #ident #ty_generics {
@@ -91,6 +92,12 @@ fn expand_derive_has_sql_type_transparent(
                     sqlx::postgres::PgTypeInfo::with_name(#ty_name)
                 }
             }
+
+            impl sqlx::postgres::types::PgHasArrayType for #ident #ty_generics {
+                fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+                    sqlx::postgres::PgTypeInfo::with_name(#array_ty_name)
+                }
+            }
         ));
     }
 
@@ -143,6 +150,9 @@ fn expand_derive_has_sql_type_strong_enum(
 
     if cfg!(feature = "postgres") {
         let ty_name = attributes.rename.unwrap_or_else(|| ident.to_string());
+        let array_ty_name = attributes
+            .array_type
+            .unwrap_or_else(|| format!("_{}", ty_name));
 
         tts.extend(quote!(
             impl sqlx::Type< sqlx::Postgres > for #ident {
@@ -150,6 +160,12 @@ fn expand_derive_has_sql_type_strong_enum(
                     sqlx::postgres::PgTypeInfo::with_name(#ty_name)
                 }
             }
+
+            impl sqlx::postgres::types::PgHasArrayType for #ident {
+                fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+                    sqlx::postgres::PgTypeInfo::with_name(#array_ty_name)
+                }
+            }
         ));
     }
 
@@ -181,6 +197,9 @@ fn expand_derive_has_sql_type_struct(
 
     if cfg!(feature = "postgres") {
         let ty_name = attributes.rename.unwrap_or_else(|| ident.to_string());
+        let array_ty_name = attributes
+            .array_type
+            .unwrap_or_else(|| format!("_{}", ty_name));
 
         tts.extend(quote!(
             impl sqlx::Type< sqlx::Postgres > for #ident {
@@ -188,6 +207,12 @@ fn expand_derive_has_sql_type_struct(
                     sqlx::postgres::PgTypeInfo::with_name(#ty_name)
                 }
             }
+
+            impl sqlx::postgres::types::PgHasArrayType for #ident {
+                fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+                    sqlx::postgres::PgTypeInfo::with_name(#array_ty_name)
+                }
+            }
         ));
     }
 
@@ -37,60 +37,62 @@ where
 }
 
 // Test type encoding and decoding
+//
+// `Postgres >= 140000` skips the test when `server_version_num` is lower
 #[macro_export]
 macro_rules! test_type {
-    ($name:ident<$ty:ty>($db:ident, $sql:literal, $($text:literal == $value:expr),+ $(,)?)) => {
-        $crate::__test_prepared_type!($name<$ty>($db, $sql, $($text == $value),+));
-        $crate::test_unprepared_type!($name<$ty>($db, $($text == $value),+));
+    ($name:ident<$ty:ty>($db:ident $(>= $min:literal)?, $sql:literal, $($text:literal == $value:expr),+ $(,)?)) => {
+        $crate::__test_prepared_type!($name<$ty>($db $(>= $min)?, $sql, $($text == $value),+));
+        $crate::test_unprepared_type!($name<$ty>($db $(>= $min)?, $($text == $value),+));
     };
 
-    ($name:ident<$ty:ty>($db:ident, $($text:literal == $value:expr),+ $(,)?)) => {
+    ($name:ident<$ty:ty>($db:ident $(>= $min:literal)?, $($text:literal == $value:expr),+ $(,)?)) => {
         paste::item! {
-            $crate::__test_prepared_type!($name<$ty>($db, $crate::[< $db _query_for_test_prepared_type >]!(), $($text == $value),+));
-            $crate::test_unprepared_type!($name<$ty>($db, $($text == $value),+));
+            $crate::__test_prepared_type!($name<$ty>($db $(>= $min)?, $crate::[< $db _query_for_test_prepared_type >]!(), $($text == $value),+));
+            $crate::test_unprepared_type!($name<$ty>($db $(>= $min)?, $($text == $value),+));
         }
     };
 
-    ($name:ident($db:ident, $($text:literal == $value:expr),+ $(,)?)) => {
-        $crate::test_type!($name<$name>($db, $($text == $value),+));
+    ($name:ident($db:ident $(>= $min:literal)?, $($text:literal == $value:expr),+ $(,)?)) => {
+        $crate::test_type!($name<$name>($db $(>= $min)?, $($text == $value),+));
     };
 }
 
 // Test type decoding only
 #[macro_export]
 macro_rules! test_decode_type {
-    ($name:ident<$ty:ty>($db:ident, $($text:literal == $value:expr),+ $(,)?)) => {
-        $crate::__test_prepared_decode_type!($name<$ty>($db, $($text == $value),+));
-        $crate::test_unprepared_type!($name<$ty>($db, $($text == $value),+));
+    ($name:ident<$ty:ty>($db:ident $(>= $min:literal)?, $($text:literal == $value:expr),+ $(,)?)) => {
+        $crate::__test_prepared_decode_type!($name<$ty>($db $(>= $min)?, $($text == $value),+));
+        $crate::test_unprepared_type!($name<$ty>($db $(>= $min)?, $($text == $value),+));
     };
 
-    ($name:ident($db:ident, $($text:literal == $value:expr),+ $(,)?)) => {
-        $crate::test_decode_type!($name<$name>($db, $($text == $value),+));
+    ($name:ident($db:ident $(>= $min:literal)?, $($text:literal == $value:expr),+ $(,)?)) => {
+        $crate::test_decode_type!($name<$name>($db $(>= $min)?, $($text == $value),+));
     };
 }
 
 // Test type encoding and decoding
 #[macro_export]
 macro_rules! test_prepared_type {
-    ($name:ident<$ty:ty>($db:ident, $sql:literal, $($text:literal == $value:expr),+ $(,)?)) => {
-        $crate::__test_prepared_type!($name<$ty>($db, $sql, $($text == $value),+));
+    ($name:ident<$ty:ty>($db:ident $(>= $min:literal)?, $sql:literal, $($text:literal == $value:expr),+ $(,)?)) => {
+        $crate::__test_prepared_type!($name<$ty>($db $(>= $min)?, $sql, $($text == $value),+));
     };
 
-    ($name:ident<$ty:ty>($db:ident, $($text:literal == $value:expr),+ $(,)?)) => {
+    ($name:ident<$ty:ty>($db:ident $(>= $min:literal)?, $($text:literal == $value:expr),+ $(,)?)) => {
         paste::item! {
-            $crate::__test_prepared_type!($name<$ty>($db, $crate::[< $db _query_for_test_prepared_type >]!(), $($text == $value),+));
+            $crate::__test_prepared_type!($name<$ty>($db $(>= $min)?, $crate::[< $db _query_for_test_prepared_type >]!(), $($text == $value),+));
         }
     };
 
-    ($name:ident($db:ident, $($text:literal == $value:expr),+ $(,)?)) => {
-        $crate::__test_prepared_type!($name<$name>($db, $($text == $value),+));
+    ($name:ident($db:ident $(>= $min:literal)?, $($text:literal == $value:expr),+ $(,)?)) => {
+        $crate::__test_prepared_type!($name<$name>($db $(>= $min)?, $($text == $value),+));
     };
 }
 
 // Test type decoding for the simple (unprepared) query API
 #[macro_export]
 macro_rules! test_unprepared_type {
-    ($name:ident<$ty:ty>($db:ident, $($text:literal == $value:expr),+ $(,)?)) => {
+    ($name:ident<$ty:ty>($db:ident $(>= $min:literal)?, $($text:literal == $value:expr),+ $(,)?)) => {
         paste::item! {
             #[sqlx_macros::test]
             async fn [< test_unprepared_type_ $name >] () -> anyhow::Result<()> {
@@ -99,6 +101,13 @@ macro_rules! test_unprepared_type {
 
                 let mut conn = sqlx_test::new::<$db>().await?;
 
+                $(
+                    if $crate::[< $db _server_version_num >]!(conn) < $min {
+                        eprintln!("skipped: requires server version {} or later", $min);
+                        return Ok(());
+                    }
+                )?
+
                 $(
                     let query = format!("SELECT {}", $text);
                     let mut s = conn.fetch(&*query);
@@ -119,7 +128,7 @@ macro_rules! test_unprepared_type {
 // Test type decoding only for the prepared query API
 #[macro_export]
 macro_rules! __test_prepared_decode_type {
-    ($name:ident<$ty:ty>($db:ident, $($text:literal == $value:expr),+ $(,)?)) => {
+    ($name:ident<$ty:ty>($db:ident $(>= $min:literal)?, $($text:literal == $value:expr),+ $(,)?)) => {
         paste::item! {
             #[sqlx_macros::test]
             async fn [< test_prepared_decode_type_ $name >] () -> anyhow::Result<()> {
@@ -127,6 +136,13 @@ macro_rules! __test_prepared_decode_type {
 
                 let mut conn = sqlx_test::new::<$db>().await?;
 
+                $(
+                    if $crate::[< $db _server_version_num >]!(conn) < $min {
+                        eprintln!("skipped: requires server version {} or later", $min);
+                        return Ok(());
+                    }
+                )?
+
                 $(
                     let query = format!("SELECT {}", $text);
 
@@ -148,7 +164,7 @@ macro_rules! __test_prepared_decode_type {
 // Test type encoding and decoding for the prepared query API
 #[macro_export]
 macro_rules! __test_prepared_type {
-    ($name:ident<$ty:ty>($db:ident, $sql:expr, $($text:literal == $value:expr),+ $(,)?)) => {
+    ($name:ident<$ty:ty>($db:ident $(>= $min:literal)?, $sql:expr, $($text:literal == $value:expr),+ $(,)?)) => {
         paste::item! {
             #[sqlx_macros::test]
             async fn [< test_prepared_type_ $name >] () -> anyhow::Result<()> {
@@ -156,6 +172,13 @@ macro_rules! __test_prepared_type {
 
                 let mut conn = sqlx_test::new::<$db>().await?;
 
+                $(
+                    if $crate::[< $db _server_version_num >]!(conn) < $min {
+                        eprintln!("skipped: requires server version {} or later", $min);
+                        return Ok(());
+                    }
+                )?
+
                 $(
                     let query = format!($sql, $text);
 
@@ -221,3 +244,12 @@ macro_rules! Postgres_query_for_test_prepared_type {
         "SELECT ({0} is not distinct from $1)::int4, {0}, $2"
     };
 }
+
+#[macro_export]
+macro_rules! Postgres_server_version_num {
+    ($conn:ident) => {
+        sqlx::query_scalar::<_, i32>("SELECT current_setting('server_version_num')::int4")
+            .fetch_one(&mut $conn)
+            .await?
+    };
+}
@@ -1,6 +1,9 @@
-use futures::TryStreamExt;
-use sqlx::mysql::{MySql, MySqlConnection, MySqlPool, MySqlPoolOptions, MySqlRow};
-use sqlx::{Column, Connection, Done, Executor, Row, Statement, TypeInfo};
+use futures::{stream, StreamExt, TryStreamExt};
+use sqlx::mysql::{
+    MySql, MySqlBinlogEventData, MySqlBinlogOptions, MySqlCompression, MySqlConnectOptions,
+    MySqlConnection, MySqlLocalInfile, MySqlPool, MySqlPoolOptions, MySqlRow,
+};
+use sqlx::{Column, ConnectOptions, Connection, Done, Executor, Row, Statement, TypeInfo};
 use sqlx_test::{new, setup_if_needed};
 use std::env;
 
@@ -387,3 +390,391 @@ async fn test_issue_622() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_loads_data_local_infile() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    // the server must allow `LOCAL` for the client to be asked for a file, which the
+    // servers of `tests/docker-compose.yml` are started with
+    let enabled: i64 = sqlx::query_scalar("SELECT @@GLOBAL.local_infile + 0")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_ne!(
+        enabled, 0,
+        "the server must be started with --local-infile=1"
+    );
+
+    let mut conn = env::var("DATABASE_URL")?
+        .parse::<MySqlConnectOptions>()?
+        .local_infile(true)
+        .connect()
+        .await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .await?;
+
+    let chunks: Vec<Result<_, std::io::Error>> = vec![Ok("1,alice\n2,"), Ok("bob\n")];
+    conn.register_local_infile(
+        "users.csv",
+        MySqlLocalInfile::from_stream(stream::iter(chunks)),
+    );
+
+    let done = conn
+        .execute("LOAD DATA LOCAL INFILE 'users.csv' INTO TABLE users FIELDS TERMINATED BY ','")
+        .await?;
+
+    assert_eq!(done.rows_affected(), 2);
+
+    let names: Vec<String> = sqlx::query_scalar("SELECT name FROM users ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(names, ["alice", "bob"]);
+
+    // a file must be registered for the server to read it; a registration is served once
+    let res = conn
+        .execute("LOAD DATA LOCAL INFILE 'users.csv' INTO TABLE users FIELDS TERMINATED BY ','")
+        .await;
+
+    assert!(res.is_err());
+
+    // the connection is still usable
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 2);
+
+    // a statement dropped before the server requests its file must not block the connection
+    conn.register_local_infile(
+        "users.csv",
+        MySqlLocalInfile::from_stream(stream::iter(vec![Ok::<_, std::io::Error>("3,carol\n")])),
+    );
+
+    {
+        let mut s = conn.fetch_many(
+            "LOAD DATA LOCAL INFILE 'users.csv' INTO TABLE users FIELDS TERMINATED BY ','",
+        );
+
+        let _ = futures::poll!(s.next());
+    }
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(count == 2 || count == 3);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_streams_binlog_events() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    // the binary log may be disabled, such as by default on MariaDB
+    let status = match conn.fetch_optional("SHOW MASTER STATUS").await? {
+        Some(status) => status,
+        None => return Ok(()),
+    };
+
+    let file: String = status.try_get("File")?;
+    let position: u64 = status.try_get("Position")?;
+
+    conn.execute(
+        r#"
+DROP TABLE IF EXISTS _sqlx_binlog_test;
+CREATE TABLE _sqlx_binlog_test (id INTEGER PRIMARY KEY, name TEXT);
+INSERT INTO _sqlx_binlog_test (id, name) VALUES (1, 'alice'), (2, NULL);
+DROP TABLE _sqlx_binlog_test;
+        "#,
+    )
+    .await?;
+
+    let options = MySqlBinlogOptions::new(4242)
+        .file(&file, position)
+        .non_blocking(true);
+
+    let events: Vec<_> = new::<MySql>()
+        .await?
+        .binlog(options)
+        .await?
+        .try_collect()
+        .await?;
+
+    let rows = events
+        .into_iter()
+        .find_map(|event| match event.into_data() {
+            MySqlBinlogEventData::WriteRows { table, rows }
+                if table.name() == "_sqlx_binlog_test" =>
+            {
+                Some(rows)
+            }
+
+            _ => None,
+        })
+        .expect("expected a WRITE_ROWS event");
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].try_get::<i32, _>(0)?, 1);
+    assert_eq!(
+        rows[0].try_get::<Option<String>, _>(1)?.as_deref(),
+        Some("alice")
+    );
+    assert_eq!(rows[1].try_get::<Option<String>, _>(1)?, None);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_resets_session_on_release() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let options = env::var("DATABASE_URL")?
+        .parse::<MySqlConnectOptions>()?
+        .reset_on_release(true);
+
+    let pool = MySqlPoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await?;
+
+    {
+        let mut conn = pool.acquire().await?;
+
+        conn.execute("SET @sqlx_reset = 1").await?;
+        conn.execute("CREATE TEMPORARY TABLE sqlx_reset (id INTEGER)")
+            .await?;
+
+        let value: i64 = sqlx::query_scalar("SELECT ? + 1")
+            .bind(1_i64)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(value, 2);
+        assert_eq!(conn.cached_statements_size(), 1);
+    }
+
+    let mut conn = pool.acquire().await?;
+
+    // the statement cache was cleared with the prepared statements of the session
+    assert_eq!(conn.cached_statements_size(), 0);
+
+    let value: Option<i64> = sqlx::query_scalar("SELECT @sqlx_reset")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, None);
+    assert!(conn.execute("SELECT * FROM sqlx_reset").await.is_err());
+
+    // the session was configured again
+    let time_zone: String = sqlx::query_scalar("SELECT @@time_zone")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(time_zone, "+00:00");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_connects_with_compression() -> anyhow::Result<()> {
+    let mut conn = env::var("DATABASE_URL")?
+        .parse::<MySqlConnectOptions>()?
+        .compression(MySqlCompression::Zlib)
+        .connect()
+        .await?;
+
+    let value: String = sqlx::query_scalar("SELECT REPEAT(?, 1000000)")
+        .bind("sqlx")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value.len(), 4_000_000);
+    assert!(value.starts_with("sqlxsqlx"));
+
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_long_data() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE blobs (id INTEGER PRIMARY KEY, data LONGBLOB NOT NULL)")
+        .await?;
+
+    // large enough to be sent with `COM_STMT_SEND_LONG_DATA`
+    let data: Vec<u8> = (0..3_000_000).map(|i| (i % 251) as u8).collect();
+
+    sqlx::query("INSERT INTO blobs (id, data) VALUES (?, ?)")
+        .bind(1_i32)
+        .bind(&data)
+        .execute(&mut conn)
+        .await?;
+
+    let stored: Vec<u8> = sqlx::query_scalar("SELECT data FROM blobs WHERE id = ?")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(stored, data);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_result_sets_of_procedures() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+DROP PROCEDURE IF EXISTS sqlx_result_sets;
+
+CREATE PROCEDURE sqlx_result_sets(IN a INT, OUT doubled INT)
+BEGIN
+    SELECT a;
+    SELECT a + 1 UNION ALL SELECT a + 2;
+    SET doubled = a * 2;
+END;
+    "#,
+    )
+    .await?;
+
+    // text protocol, where OUT parameters can only be read from session variables
+    let results: Vec<_> = conn
+        .fetch_many("CALL sqlx_result_sets(10, @doubled)")
+        .try_collect()
+        .await?;
+
+    let sets: Vec<_> = results
+        .iter()
+        .map(|result| {
+            result.as_ref().either(
+                |done| (done.result_set(), None),
+                |row| (row.result_set(), Some(row.get::<i64, _>(0))),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        sets,
+        vec![
+            (0, Some(10)),
+            (0, None),
+            (1, Some(11)),
+            (1, Some(12)),
+            (1, None),
+            (2, None),
+        ]
+    );
+
+    // prepared statement, where OUT parameters are returned as a result set of their own
+    let rows: Vec<MySqlRow> = sqlx::query("CALL sqlx_result_sets(?, ?)")
+        .bind(21_i32)
+        .bind(Option::<i32>::None)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(rows.len(), 4);
+    assert!(rows[..3].iter().all(|row| !row.is_out_params()));
+
+    let out = &rows[3];
+
+    assert!(out.is_out_params());
+    assert_eq!(out.result_set(), 2);
+    assert_eq!(out.get::<i32, _>("doubled"), 42);
+
+    conn.execute("DROP PROCEDURE sqlx_result_sets").await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_rows_with_a_cursor() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let rows: Vec<i64> = sqlx::query_scalar(
+        "SELECT n FROM (SELECT 1 AS n UNION ALL SELECT 2 UNION ALL SELECT 3 UNION ALL SELECT 4 UNION ALL SELECT 5) AS t WHERE n >= ? ORDER BY n",
+    )
+    .bind(1_i32)
+    .fetch_size(2)
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(rows, vec![1, 2, 3, 4, 5]);
+
+    // the connection is usable after the cursor was read to the end
+    let value: i32 = sqlx::query_scalar("SELECT ?")
+        .bind(6_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 6);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_connects_with_ed25519() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    // the `ed25519` plugin is part of MariaDB, whose PASSWORD() hashes for it since 10.4
+    let version: String = sqlx::query_scalar("SELECT VERSION()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let mut parts = version
+        .split(|c: char| !c.is_ascii_digit())
+        .map(|part| part.parse::<u32>().unwrap_or(0));
+
+    if !version.contains("MariaDB") || (parts.next(), parts.next()) < (Some(10), Some(4)) {
+        eprintln!("skipped: requires MariaDB 10.4 or later");
+        return Ok(());
+    }
+
+    let installed: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM information_schema.plugins WHERE plugin_name = 'ed25519'",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    if installed == 0 {
+        conn.execute("INSTALL SONAME 'auth_ed25519'").await?;
+    }
+
+    conn.execute(
+        "CREATE OR REPLACE USER 'sqlx_ed25519'@'%' IDENTIFIED VIA ed25519 USING PASSWORD('password')",
+    )
+    .await?;
+
+    // the user connects to the database of the test
+    let database: String = sqlx::query_scalar("SELECT DATABASE()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    conn.execute(&*format!(
+        "GRANT SELECT ON `{}`.* TO 'sqlx_ed25519'@'%'",
+        database
+    ))
+    .await?;
+
+    let mut conn = env::var("DATABASE_URL")?
+        .parse::<MySqlConnectOptions>()?
+        .username("sqlx_ed25519")
+        .password("password")
+        .connect()
+        .await?;
+
+    let user: String = sqlx::query_scalar("SELECT CURRENT_USER()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(user, "sqlx_ed25519@%");
+
+    Ok(())
+}
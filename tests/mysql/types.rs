@@ -3,6 +3,8 @@ extern crate time_ as time;
 #[cfg(feature = "decimal")]
 use std::str::FromStr;
 
+use std::collections::HashSet;
+
 use sqlx::mysql::MySql;
 use sqlx::{Executor, Row};
 use sqlx_test::{new, test_type};
@@ -309,3 +311,133 @@ CREATE TEMPORARY TABLE with_bits (
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn test_year_and_set() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE with_year_and_set (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    year YEAR NOT NULL,
+    flags SET('a', 'b', 'c') NOT NULL
+);
+    "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO with_year_and_set (year, flags) VALUES (?, ?), (?, ?)")
+        .bind(2021_u16)
+        .bind(vec!["c".to_owned(), "a".to_owned()])
+        .bind(1999_u16)
+        .bind(Vec::<String>::new())
+        .execute(&mut conn)
+        .await?;
+
+    // BINARY
+    let rows: Vec<(u16, Vec<String>)> =
+        sqlx::query_as("SELECT year, flags FROM with_year_and_set ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(rows[0], (2021, vec!["a".to_owned(), "c".to_owned()]));
+    assert_eq!(rows[1], (1999, Vec::new()));
+
+    // TEXT
+    let row = conn
+        .fetch_one("SELECT year, flags FROM with_year_and_set ORDER BY id")
+        .await?;
+
+    let year: u16 = row.try_get(0)?;
+    let flags: HashSet<String> = row.try_get(1)?;
+
+    assert_eq!(year, 2021);
+    assert_eq!(
+        flags,
+        vec!["a".to_owned(), "c".to_owned()].into_iter().collect()
+    );
+
+    // YEAR does not fit a single byte
+    assert!(row.try_get::<u8, _>(0).is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "bit-vec")]
+#[sqlx_macros::test]
+async fn test_bit_vec() -> anyhow::Result<()> {
+    use sqlx::types::BitVec;
+
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE with_bit_vec (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    value BIT(10) NOT NULL
+);
+    "#,
+    )
+    .await?;
+
+    let mut bits = BitVec::from_elem(10, false);
+    bits.set(0, true);
+    bits.set(9, true);
+
+    sqlx::query("INSERT INTO with_bit_vec (value) VALUES (?)")
+        .bind(&bits)
+        .execute(&mut conn)
+        .await?;
+
+    let (value, number): (BitVec, u16) = sqlx::query_as("SELECT value, value FROM with_bit_vec")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, bits);
+    assert_eq!(number, 0b10_0000_0001);
+
+    Ok(())
+}
+
+#[cfg(feature = "geo-types")]
+mod geo_types_tests {
+    use super::*;
+    use sqlx::types::geo_types::{line_string, point, Geometry, LineString, Point, Polygon};
+
+    #[sqlx_macros::test]
+    async fn test_geometry() -> anyhow::Result<()> {
+        let mut conn = new::<MySql>().await?;
+
+        let point: Point<f64> = sqlx::query_scalar("SELECT ST_GeomFromText('POINT(1 2)')")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(point, point!(x: 1.0, y: 2.0));
+
+        let polygon = Polygon::new(
+            line_string![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 0.0, y: 0.0)],
+            vec![],
+        );
+
+        let (text, geometry): (String, Geometry<f64>) =
+            sqlx::query_as("SELECT ST_AsText(?), ST_GeomFromText(ST_AsText(?))")
+                .bind(&polygon)
+                .bind(Geometry::Polygon(polygon.clone()))
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(text, "POLYGON((0 0,4 0,4 4,0 0))");
+        assert_eq!(geometry, Geometry::Polygon(polygon));
+
+        // a polygon is not a line string
+        let line_string: Result<LineString<f64>, _> =
+            sqlx::query_scalar("SELECT ST_GeomFromText('POLYGON((0 0,4 0,4 4,0 0))')")
+                .fetch_one(&mut conn)
+                .await;
+
+        assert!(line_string.is_err());
+
+        Ok(())
+    }
+}
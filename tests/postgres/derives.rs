@@ -1,7 +1,7 @@
 use futures::TryStreamExt;
 use sqlx::{Connection, Executor, FromRow, Postgres};
-use sqlx_core::postgres::types::PgRange;
-use sqlx_test::{new, test_type};
+use sqlx_core::postgres::types::{PgMultirange, PgRange};
+use sqlx_test::{new, test_decode_type, test_type};
 use std::fmt::Debug;
 use std::ops::Bound;
 
@@ -84,6 +84,16 @@ enum Mood {
     Sad,
 }
 
+// "Strong" enum with an array type of `_status`
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "status")]
+#[sqlx(rename_all = "lowercase")]
+enum Status {
+    New,
+    Open,
+    Closed,
+}
+
 // Records must map to a custom type
 // Note that all types are types in Postgres
 #[derive(PartialEq, Debug, sqlx::Type)]
@@ -94,11 +104,32 @@ struct InventoryItem {
     price: Option<i64>,
 }
 
+// Records may have fields of other records, arrays of records and enums
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "line_item")]
+struct LineItem {
+    item: InventoryItem,
+    quantity: i32,
+}
+
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "purchase_order")]
+struct PurchaseOrder {
+    status: Status,
+    lines: Vec<LineItem>,
+    note: Option<String>,
+}
+
 // Custom range type
 #[derive(sqlx::Type, Debug, PartialEq)]
 #[sqlx(rename = "float_range")]
 struct FloatRange(PgRange<f64>);
 
+// Multirange of a custom range type, since Postgres 14
+#[derive(sqlx::Type, Debug, PartialEq)]
+#[sqlx(rename = "float_multirange")]
+struct FloatMultirange(PgMultirange<f64>);
+
 // Custom domain type
 #[derive(sqlx::Type, Debug)]
 #[sqlx(rename = "int4rangeL0pC")]
@@ -121,10 +152,60 @@ test_type!(strong_enum<Strong>(Postgres,
     "'four'::text" == Strong::Three
 ));
 
+test_type!(strong_enum_vec<Vec<Status>>(Postgres,
+    "array['new', 'closed']::status[]" == vec![Status::New, Status::Closed],
+    "array[]::status[]" == Vec::<Status>::new(),
+));
+
+test_type!(record_vec<Vec<InventoryItem>>(Postgres,
+    "array[row('fuzzy dice', 42, 199), row('pair of \"socks\"', null, 5)]::inventory_item[]" == vec![
+        InventoryItem { name: "fuzzy dice".to_owned(), supplier_id: Some(42), price: Some(199) },
+        InventoryItem { name: "pair of \"socks\"".to_owned(), supplier_id: None, price: Some(5) },
+    ],
+));
+
+test_type!(nested_record<PurchaseOrder>(Postgres,
+    "row('open', array[row(row('fuzzy dice', 42, 199), 2)]::line_item[], null)::purchase_order"
+        == PurchaseOrder {
+            status: Status::Open,
+            lines: vec![LineItem {
+                item: InventoryItem { name: "fuzzy dice".to_owned(), supplier_id: Some(42), price: Some(199) },
+                quantity: 2,
+            }],
+            note: None,
+        },
+    "row('closed', array[]::line_item[], 'left at the \"door\"')::purchase_order"
+        == PurchaseOrder {
+            status: Status::Closed,
+            lines: vec![],
+            note: Some("left at the \"door\"".to_owned()),
+        },
+));
+
+// an anonymous record only sends the OIDs of its fields
+test_decode_type!(record_in_tuple<(i32, InventoryItem)>(Postgres,
+    "row(1, row('fuzzy dice', null, 199)::inventory_item)"
+        == (1, InventoryItem { name: "fuzzy dice".to_owned(), supplier_id: None, price: Some(199) }),
+));
+
 test_type!(floatrange<FloatRange>(Postgres,
     "'[1.234, 5.678]'::float_range" == FloatRange(PgRange::from((Bound::Included(1.234), Bound::Included(5.678)))),
 ));
 
+test_type!(floatrange_vec<Vec<FloatRange>>(Postgres,
+    "array['[1.234, 5.678]', '(,0)']::float_range[]" == vec![
+        FloatRange(PgRange::from((Bound::Included(1.234), Bound::Included(5.678)))),
+        FloatRange(PgRange::from((Bound::Unbounded, Bound::Excluded(0.0)))),
+    ],
+));
+
+test_type!(floatmultirange<FloatMultirange>(Postgres >= 140000,
+    "'{[1.234, 5.678], (10,)}'::float_multirange" == FloatMultirange(PgMultirange::from(vec![
+        PgRange::from((Bound::Included(1.234), Bound::Included(5.678))),
+        PgRange::from((Bound::Excluded(10.0), Bound::Unbounded)),
+    ])),
+));
+
 #[sqlx_macros::test]
 async fn test_enum_type() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -96,6 +96,65 @@ async fn test_void() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_text_search() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let query: sqlx::postgres::types::PgTsQuery = "cat & rat".parse().unwrap();
+
+    let row = sqlx::query!(
+        r#"select to_tsvector('simple', 'a fat cat') as "vector!", $1::tsquery as "query!""#,
+        query
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(row.vector.to_string(), "'a':1 'cat':3 'fat':2");
+    assert_eq!(row.query, query);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_domain() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let row = sqlx::query!(
+        r#"select $1::email_address as "email!", $2::positive_ints as "ints!""#,
+        "me@example.com",
+        &[1, 2, 3][..]
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(row.email, "me@example.com");
+    assert_eq!(row.ints, vec![1, 2, 3]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_oid() -> anyhow::Result<()> {
+    use sqlx::postgres::types::Oid;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // OID columns are `u32` unless overridden
+    let row = sqlx::query!(
+        r#"select 'pg_class'::regclass::oid as "oid!", $1::oid as "overridden!: Oid""#,
+        1259_u32
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    let oid: u32 = row.oid;
+
+    assert_eq!(oid, 1259);
+    assert_eq!(row.overridden, Oid(1259));
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_query_file() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -763,3 +763,36 @@ async fn test_issue_622() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_fetches_rows_in_batches() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let rows: Vec<i32> = sqlx::query_scalar("SELECT generate_series(1, $1)")
+        .bind(10_i32)
+        .fetch_size(3)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(rows, (1..=10).collect::<Vec<_>>());
+
+    // stop reading in the middle of the portal
+    {
+        let mut s = sqlx::query_scalar::<_, i32>("SELECT generate_series(1, $1)")
+            .bind(10_i32)
+            .fetch_size(3)
+            .fetch(&mut conn);
+
+        assert_eq!(s.try_next().await?, Some(1));
+        assert_eq!(s.try_next().await?, Some(2));
+    }
+
+    let value: i32 = sqlx::query_scalar("SELECT $1")
+        .bind(5_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 5);
+
+    Ok(())
+}
@@ -23,3 +23,27 @@ CREATE TYPE float_range AS RANGE
     subtype = float8,
     subtype_diff = float8mi
 );
+
+-- https://www.postgresql.org/docs/current/contrib.html
+CREATE EXTENSION IF NOT EXISTS hstore;
+CREATE EXTENSION IF NOT EXISTS ltree;
+CREATE EXTENSION IF NOT EXISTS citext;
+
+-- https://www.postgresql.org/docs/current/sql-createdomain.html
+CREATE DOMAIN email_address AS TEXT CHECK (VALUE LIKE '%@%');
+CREATE DOMAIN positive_ints AS INT[] CHECK (0 < ALL (VALUE));
+CREATE DOMAIN working_hours AS INT4RANGE CHECK (VALUE <@ '[0,24]'::int4range);
+
+-- composite types with composite, array and enum attributes
+CREATE TYPE line_item AS
+(
+    item     inventory_item,
+    quantity INT
+);
+
+CREATE TYPE purchase_order AS
+(
+    status status,
+    lines  line_item[],
+    note   TEXT
+);
@@ -4,7 +4,11 @@ use std::ops::Bound;
 #[cfg(feature = "decimal")]
 use std::str::FromStr;
 
-use sqlx::postgres::types::{PgInterval, PgMoney, PgRange};
+use sqlx::postgres::types::{
+    Oid, PgBox, PgCircle, PgInterval, PgLine, PgLseg, PgLsn, PgMacAddr, PgMacAddr8, PgMoney,
+    PgMultirange, PgPath, PgPoint, PgPolygon, PgRange, PgTsLexeme, PgTsPosition, PgTsQuery,
+    PgTsQueryNode, PgTsVector, PgTsWeight, PgXml,
+};
 use sqlx::postgres::Postgres;
 use sqlx_test::{test_decode_type, test_prepared_type, test_type};
 
@@ -251,6 +255,16 @@ mod chrono {
             ]
     ));
 
+    // multiranges require Postgres 14 or later
+    test_type!(chrono_tstzmultirange<PgMultirange<DateTime::<Utc>>>(Postgres >= 140000,
+        "'{[2019-01-02 05:10:20+00, 2019-01-03 00:00:00+00)}'::tstzmultirange"
+            == PgMultirange::from(vec![PgRange::from(
+                Utc.ymd(2019, 1, 2).and_hms(5, 10, 20)..Utc.ymd(2019, 1, 3).and_hms(0, 0, 0)
+            )]),
+        "'{[2019-01-02 05:10:20+00,)}'::tstzmultirange"
+            == PgMultirange::from(vec![PgRange::from(Utc.ymd(2019, 1, 2).and_hms(5, 10, 20)..)])
+    ));
+
     test_type!(chrono_time_tz<PgTimeTz>(Postgres,
         "TIMETZ '05:10:20.115100+00'" == PgTimeTz { time: NaiveTime::from_hms_micro(5, 10, 20, 115100), offset: FixedOffset::east(0) },
         "TIMETZ '05:10:20.115100+06:30'" == PgTimeTz { time: NaiveTime::from_hms_micro(5, 10, 20, 115100), offset: FixedOffset::east(60 * 60 * 6 + 1800) },
@@ -262,7 +276,7 @@ mod chrono {
 #[cfg(feature = "time")]
 mod time_tests {
     use super::*;
-    use sqlx::types::time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+    use sqlx::types::time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
     use time::{date, time};
 
     type PgTimeTz = sqlx::postgres::types::PgTimeTz<Time, UtcOffset>;
@@ -299,6 +313,13 @@ mod time_tests {
         "TIMETZ '05:10:20.115100-05'" == PgTimeTz { time: time!(5:10:20.115100), offset: UtcOffset::west_seconds(60 * 60 * 5) },
         "TIMETZ '05:10:20+02'" == PgTimeTz { time: time!(5:10:20), offset: UtcOffset::east_seconds(60 * 60 * 2 )}
     ));
+
+    test_type!(time_duration<Duration>(
+        Postgres,
+        "INTERVAL '1 day 01:00:00.5'"
+            == Duration::days(1) + Duration::milliseconds(3_600_500),
+        "INTERVAL '-30 minutes'" == Duration::minutes(-30)
+    ));
 }
 
 #[cfg(feature = "json")]
@@ -443,7 +464,21 @@ test_type!(int4range<PgRange<i32>>(Postgres,
     "'[1,2]'::int4range" == PgRange::from((INC1, EXC3)),
 ));
 
-test_prepared_type!(interval<PgInterval>(
+// multiranges require Postgres 14 or later
+test_type!(int4multirange<PgMultirange<i32>>(Postgres >= 140000,
+    "'{}'::int4multirange" == PgMultirange::<i32>::default(),
+    "'{[1,3), [5,7)}'::int4multirange"
+        == PgMultirange::from(vec![PgRange::from(1..3), PgRange::from(5..7)]),
+    "'{[1,3), [2,5), (,-10]}'::int4multirange"
+        == PgMultirange::from(vec![PgRange::from(..-9), PgRange::from(1..5)]),
+));
+
+test_type!(int4multirange_vec<Vec<PgMultirange<i32>>>(Postgres >= 140000,
+    "array['{[1,3)}', '{}']::int4multirange[]"
+        == vec![PgMultirange::from(vec![PgRange::from(1..3)]), PgMultirange::<i32>::default()],
+));
+
+test_type!(interval<PgInterval>(
     Postgres,
     "INTERVAL '1h'"
         == PgInterval {
@@ -469,6 +504,12 @@ test_prepared_type!(interval<PgInterval>(
             days: 0,
             microseconds: (3 * 3_600 + 10 * 60 + 20) * 1_000_000 + 116100
         },
+    "INTERVAL 'P-1Y2M-3DT4H'"
+        == PgInterval {
+            months: -10,
+            days: -3,
+            microseconds: 4 * 3_600 * 1_000_000
+        },
 ));
 
 test_prepared_type!(money<PgMoney>(Postgres, "123.45::money" == PgMoney(12345)));
@@ -476,3 +517,208 @@ test_prepared_type!(money<PgMoney>(Postgres, "123.45::money" == PgMoney(12345)))
 test_prepared_type!(money_vec<Vec<PgMoney>>(Postgres,
     "array[123.45,420.00,666.66]::money[]" == vec![PgMoney(12345), PgMoney(42000), PgMoney(66666)],
 ));
+
+// most geometric types have no equality operator, so their text representations are compared
+test_type!(point<PgPoint>(Postgres,
+    "SELECT ({0}::text = $1::text)::int4, {0}, $2",
+    "point(1.5, -2)" == PgPoint { x: 1.5, y: -2.0 },
+));
+
+test_type!(point_vec<Vec<PgPoint>>(Postgres,
+    "SELECT ({0}::text = $1::text)::int4, {0}, $2",
+    "array[point(0, 0), point(1, 2)]" == vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: 1.0, y: 2.0 }],
+));
+
+test_type!(line<PgLine>(Postgres,
+    "SELECT ({0}::text = $1::text)::int4, {0}, $2",
+    "'{1,-1,0}'::line" == PgLine { a: 1.0, b: -1.0, c: 0.0 },
+));
+
+test_type!(lseg<PgLseg>(Postgres,
+    "SELECT ({0}::text = $1::text)::int4, {0}, $2",
+    "'[(0,0),(3,4)]'::lseg" == PgLseg { start: PgPoint { x: 0.0, y: 0.0 }, end: PgPoint { x: 3.0, y: 4.0 } },
+));
+
+test_type!(box_<PgBox>(Postgres,
+    "SELECT ({0}::text = $1::text)::int4, {0}, $2",
+    "'(0,0),(2,1)'::box" == PgBox { high: PgPoint { x: 2.0, y: 1.0 }, low: PgPoint { x: 0.0, y: 0.0 } },
+));
+
+test_type!(path<PgPath>(Postgres,
+    "SELECT ({0}::text = $1::text)::int4, {0}, $2",
+    "'[(0,0),(1,1),(2,0)]'::path" == PgPath {
+        closed: false,
+        points: vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: 1.0, y: 1.0 }, PgPoint { x: 2.0, y: 0.0 }],
+    },
+    "'((0,0),(1,1),(2,0))'::path" == PgPath {
+        closed: true,
+        points: vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: 1.0, y: 1.0 }, PgPoint { x: 2.0, y: 0.0 }],
+    },
+));
+
+test_type!(polygon<PgPolygon>(Postgres,
+    "SELECT ({0}::text = $1::text)::int4, {0}, $2",
+    "'((0,0),(0,1),(1,0))'::polygon" == PgPolygon {
+        points: vec![PgPoint { x: 0.0, y: 0.0 }, PgPoint { x: 0.0, y: 1.0 }, PgPoint { x: 1.0, y: 0.0 }],
+    },
+));
+
+test_type!(circle<PgCircle>(Postgres,
+    "SELECT ({0}::text = $1::text)::int4, {0}, $2",
+    "'<(1,2),3.5>'::circle" == PgCircle { center: PgPoint { x: 1.0, y: 2.0 }, radius: 3.5 },
+));
+
+test_type!(tsvector<PgTsVector>(Postgres,
+    "'a:1A,2 cat:3'::tsvector" == PgTsVector {
+        lexemes: vec![
+            PgTsLexeme {
+                lexeme: "a".to_owned(),
+                positions: vec![
+                    PgTsPosition { position: 1, weight: PgTsWeight::A },
+                    PgTsPosition { position: 2, weight: PgTsWeight::D },
+                ],
+            },
+            PgTsLexeme {
+                lexeme: "cat".to_owned(),
+                positions: vec![PgTsPosition { position: 3, weight: PgTsWeight::D }],
+            },
+        ],
+    },
+    "''::tsvector" == PgTsVector::default(),
+));
+
+test_type!(tsvector_vec<Vec<PgTsVector>>(Postgres,
+    "array['it''s'::tsvector]" == vec![
+        PgTsVector { lexemes: vec![PgTsLexeme { lexeme: "it's".to_owned(), positions: vec![] }] },
+    ],
+));
+
+test_type!(tsquery<PgTsQuery>(Postgres,
+    "'fat & (rat | !cat)'::tsquery" == PgTsQuery::from(PgTsQueryNode::And(
+        Box::new(PgTsQueryNode::lexeme("fat")),
+        Box::new(PgTsQueryNode::Or(
+            Box::new(PgTsQueryNode::lexeme("rat")),
+            Box::new(PgTsQueryNode::Not(Box::new(PgTsQueryNode::lexeme("cat")))),
+        )),
+    )),
+));
+
+// the phrase operator requires Postgres 9.6 or later
+test_type!(tsquery_phrase<PgTsQuery>(Postgres >= 90600,
+    "'sup:*AB <2> man'::tsquery" == PgTsQuery::from(PgTsQueryNode::Phrase {
+        left: Box::new(PgTsQueryNode::Lexeme {
+            lexeme: "sup".to_owned(),
+            prefix: true,
+            weights: vec![PgTsWeight::A, PgTsWeight::B],
+        }),
+        right: Box::new(PgTsQueryNode::lexeme("man")),
+        distance: 2,
+    }),
+));
+
+// Postgres does not accept an empty query in the binary format
+test_decode_type!(tsquery_empty<PgTsQuery>(Postgres, "''::tsquery" == PgTsQuery::default()));
+
+test_type!(oid<Oid>(Postgres, "325235::oid" == Oid(325235)));
+
+test_type!(macaddr<PgMacAddr>(Postgres,
+    "'08:00:2b:01:02:03'::macaddr" == PgMacAddr([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03]),
+));
+
+test_type!(macaddr_vec<Vec<PgMacAddr>>(Postgres,
+    "array['08:00:2b:01:02:03'::macaddr]" == vec![PgMacAddr([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03])],
+));
+
+// macaddr8 requires Postgres 10 or later
+test_type!(macaddr8<PgMacAddr8>(Postgres >= 100000,
+    "'08:00:2b:01:02:03:04:05'::macaddr8" == PgMacAddr8([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03, 0x04, 0x05]),
+));
+
+test_type!(pg_lsn<PgLsn>(Postgres,
+    "'16/B374D848'::pg_lsn" == PgLsn(0x16_B374_D848),
+    "'0/0'::pg_lsn" == PgLsn(0),
+));
+
+test_type!(xml<PgXml>(Postgres,
+    "SELECT ({0}::text = $1::text)::int4, {0}, $2",
+    "'<a>1</a>'::xml" == PgXml::new("<a>1</a>"),
+));
+
+// requires the `postgis` extension in the test database, as in the `postgis_12` service of
+// `tests/docker-compose.yml`
+#[cfg(feature = "postgis")]
+mod postgis {
+    use super::*;
+    use sqlx::postgres::types::{PgGeography, PgGeometry};
+    use sqlx::types::geo_types::{line_string, point, Geometry, LineString, Point};
+
+    test_type!(postgis_point<Point<f64>>(Postgres,
+        "SELECT (ST_AsEWKT({0}) = ST_AsEWKT($1))::int4, {0}, $2",
+        "'POINT(1 2)'::geometry" == point!(x: 1.0, y: 2.0),
+    ));
+
+    test_type!(postgis_geometry<PgGeometry<Geometry<f64>>>(Postgres,
+        "SELECT (ST_AsEWKT({0}) = ST_AsEWKT($1))::int4, {0}, $2",
+        "'SRID=3857;LINESTRING(0 0,1 1)'::geometry" == PgGeometry {
+            geometry: Geometry::LineString(line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)]),
+            srid: Some(3857),
+        },
+    ));
+
+    test_type!(postgis_line_string<PgGeometry<LineString<f64>>>(Postgres,
+        "SELECT (ST_AsEWKT({0}) = ST_AsEWKT($1))::int4, {0}, $2",
+        "'LINESTRING(0 0,1 1)'::geometry" == PgGeometry {
+            geometry: line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)],
+            srid: None,
+        },
+    ));
+
+    test_type!(postgis_geography<PgGeography<Point<f64>>>(Postgres,
+        "SELECT (ST_AsEWKT({0}) = ST_AsEWKT($1))::int4, {0}, $2",
+        "'SRID=4326;POINT(13.4 52.5)'::geography" == PgGeography {
+            geography: point!(x: 13.4, y: 52.5),
+            srid: Some(4326),
+        },
+    ));
+}
+
+mod extensions {
+    use super::*;
+    use sqlx::postgres::types::{PgLQuery, PgLTree};
+    use std::collections::HashMap;
+
+    test_type!(hstore<HashMap<String, Option<String>>>(Postgres,
+        "'a=>1, \"b c\"=>NULL'::hstore" == vec![
+            ("a".to_owned(), Some("1".to_owned())),
+            ("b c".to_owned(), None),
+        ].into_iter().collect::<HashMap<_, _>>(),
+        "''::hstore" == HashMap::<String, Option<String>>::new(),
+    ));
+
+    // the binary format of ltree and lquery requires Postgres 13 or later
+    test_type!(ltree<PgLTree>(Postgres >= 130000,
+        "'Top.Science.Astronomy'::ltree" == "Top.Science.Astronomy".parse::<PgLTree>().unwrap(),
+    ));
+
+    test_type!(lquery<PgLQuery>(Postgres >= 130000,
+        "SELECT ({0}::text = $1::text)::int4, {0}, $2",
+        "'*.Astronomy.*'::lquery" == "*.Astronomy.*".parse::<PgLQuery>().unwrap(),
+    ));
+
+    test_type!(citext<String>(Postgres,
+        "'Hello'::citext" == "Hello",
+    ));
+}
+
+// domains are defined in `setup.sql` and decode as their base type
+test_type!(domain_text<String>(Postgres,
+    "'me@example.com'::email_address" == "me@example.com",
+));
+
+test_type!(domain_array<Vec<i32>>(Postgres,
+    "'{1,2,3}'::positive_ints" == vec![1_i32, 2, 3],
+));
+
+test_type!(domain_range<PgRange<i32>>(Postgres,
+    "'[9,17)'::working_hours" == PgRange::from(9..17),
+));
@@ -99,10 +99,13 @@ async fn it_describes_expression_from_empty_table() -> anyhow::Result<()> {
     assert_eq!(d.nullable(0), Some(false)); // COUNT(*)
 
     assert_eq!(d.columns()[1].type_info().name(), "INTEGER");
-    assert_eq!(d.nullable(1), None); // `a + 1` is potentially nullable but we don't know for sure currently
+    // `a + 1` is nullable, because `a` is; this was `None` while the type of `a` was unknown
+    assert_eq!(d.nullable(1), Some(true));
 
     assert_eq!(d.columns()[2].type_info().name(), "TEXT");
-    assert_eq!(d.nullable(2), Some(false)); // `name` is not nullable
+    // `name` is NOT NULL, but SQLite returns a NULL for it when there are no rows to
+    // aggregate, see `it_fetches_bare_column_from_empty_aggregate`
+    assert_eq!(d.nullable(2), Some(true));
 
     assert_eq!(d.columns()[3].type_info().name(), "REAL");
     assert_eq!(d.nullable(3), Some(false)); // literal constant
@@ -171,6 +174,207 @@ async fn it_describes_insert_with_read_only() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_describes_attached_schema() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let mut conn = SqliteConnectOptions::new()
+        .attach(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/sqlite/sqlite.db"),
+            "archive",
+            true,
+        )
+        .connect()
+        .await?;
+
+    let info = conn
+        .describe("SELECT id, text, owner_id FROM archive.tweet")
+        .await?;
+    let columns = info.columns();
+
+    assert_eq!(columns[0].type_info().name(), "INTEGER");
+    assert_eq!(columns[1].type_info().name(), "TEXT");
+    assert_eq!(columns[2].type_info().name(), "INTEGER");
+
+    assert_eq!(info.nullable(0), Some(false));
+    assert_eq!(info.nullable(1), Some(false));
+    assert_eq!(info.nullable(2), Some(true));
+
+    // the attachment is read-only
+    assert!(conn
+        .execute("INSERT INTO archive.tweet (id, text) VALUES (2, 'Hello')")
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_bare_column_from_empty_aggregate() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMP TABLE _temp_empty ( name TEXT NOT NULL )")
+        .await?;
+
+    let row: (i64, Option<String>) = sqlx::query_as("SELECT COUNT(*), name FROM _temp_empty")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row, (0, None));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_describes_unknown_nullability() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    // a bound parameter may be NULL
+    let d = conn.describe("SELECT 1 AS x UNION ALL SELECT ?").await?;
+    assert_eq!(d.nullable(0), None);
+
+    let d = conn.describe("SELECT ? + 1").await?;
+    assert_eq!(d.nullable(0), None);
+
+    // `~` reuses the register of the constant, which must not be taken as NOT NULL
+    let d = conn
+        .describe("SELECT 1 UNION ALL SELECT ~owner_id FROM tweet")
+        .await?;
+    assert_ne!(d.nullable(0), Some(false));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_describes_division() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMP TABLE _temp_div ( a INT NOT NULL, b INT NOT NULL )")
+        .await?;
+
+    let d = conn
+        .describe("SELECT a / b, a % b, a + b FROM _temp_div")
+        .await?;
+
+    // dividing by zero is NULL
+    assert_eq!(d.nullable(0), Some(true));
+    assert_eq!(d.nullable(1), Some(true));
+
+    assert_eq!(d.nullable(2), Some(false));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_describes_aggregate_results() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMP TABLE _temp_aggregate ( a INT NOT NULL )")
+        .await?;
+
+    let d = conn
+        .describe("SELECT count(a), avg(a), total(a), sum(a), min(a), max(a) FROM _temp_aggregate")
+        .await?;
+
+    assert_eq!(d.columns()[0].type_info().name(), "INTEGER");
+    assert_eq!(d.nullable(0), Some(false));
+
+    assert_eq!(d.columns()[1].type_info().name(), "REAL");
+    assert_eq!(d.nullable(1), Some(true));
+
+    assert_eq!(d.columns()[2].type_info().name(), "REAL");
+    assert_eq!(d.nullable(2), Some(false));
+
+    // `sum`, `min` and `max` of zero rows are NULL
+    for i in 3..6 {
+        assert_eq!(d.columns()[i].type_info().name(), "INTEGER");
+        assert_eq!(d.nullable(i), Some(true));
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_describes_left_join() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let d = conn
+        .describe(
+            "SELECT a.id, a.name, b.text FROM accounts a LEFT JOIN tweet b ON b.owner_id = a.id",
+        )
+        .await?;
+
+    assert_eq!(d.columns()[0].type_info().name(), "INTEGER");
+    assert_eq!(d.nullable(0), Some(false));
+
+    assert_eq!(d.columns()[1].type_info().name(), "TEXT");
+    assert_eq!(d.nullable(1), Some(false));
+
+    // `text` is NOT NULL but the join may not find a tweet
+    assert_eq!(d.columns()[2].type_info().name(), "TEXT");
+    assert_eq!(d.nullable(2), Some(true));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_describes_primary_key() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMP TABLE _temp_rowid ( id INTEGER PRIMARY KEY, name TEXT );
+CREATE TEMP TABLE _temp_without_rowid ( key TEXT PRIMARY KEY, value TEXT ) WITHOUT ROWID;
+            "#,
+    )
+    .await?;
+
+    // an `INTEGER PRIMARY KEY` is the rowid
+    let d = conn.describe("SELECT id, name FROM _temp_rowid").await?;
+
+    assert_eq!(d.nullable(0), Some(false));
+    assert_eq!(d.nullable(1), Some(true));
+
+    // the primary key of a table WITHOUT ROWID is stored first
+    let d = conn
+        .describe("SELECT value, key FROM _temp_without_rowid")
+        .await?;
+
+    assert_eq!(d.nullable(0), Some(true));
+    assert_eq!(d.nullable(1), Some(false));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_describes_functions_and_aggregates() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMP TABLE _temp_agg ( k TEXT NOT NULL, v INT )")
+        .await?;
+
+    let d = conn
+        .describe("SELECT k, COUNT(v), MAX(v), length(k), COALESCE(v, 0) FROM _temp_agg GROUP BY k ORDER BY k")
+        .await?;
+
+    assert_eq!(d.columns()[0].type_info().name(), "TEXT");
+    assert_eq!(d.nullable(0), Some(false));
+
+    assert_eq!(d.columns()[1].type_info().name(), "INTEGER");
+    assert_eq!(d.nullable(1), Some(false));
+
+    assert_eq!(d.columns()[2].type_info().name(), "INTEGER");
+    assert_eq!(d.nullable(2), Some(true));
+
+    assert_eq!(d.columns()[3].type_info().name(), "INTEGER");
+    assert_eq!(d.nullable(3), Some(false));
+
+    // `COALESCE` is not nullable if its last argument is not
+    assert_eq!(d.nullable(4), Some(false));
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_describes_bad_statement() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
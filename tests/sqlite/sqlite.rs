@@ -1,8 +1,9 @@
 use futures::TryStreamExt;
-use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::error::DatabaseError;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteError, SqlitePoolOptions};
 use sqlx::{
-    query, sqlite::Sqlite, sqlite::SqliteRow, Column, Connection, Done, Executor, Row,
-    SqliteConnection, SqlitePool, Statement, TypeInfo,
+    query, sqlite::Sqlite, sqlite::SqliteRow, Column, ConnectOptions, Connection, Done, Executor,
+    Row, SqliteConnection, SqlitePool, Statement, TypeInfo,
 };
 use sqlx_test::new;
 
@@ -211,6 +212,49 @@ async fn it_opens_in_memory() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_shares_named_in_memory_with_pool() -> anyhow::Result<()> {
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .min_connections(2)
+        .max_connections(2)
+        .test_before_acquire(false)
+        .connect_with(SqliteConnectOptions::new().in_memory_named("it_shares_named_in_memory"))
+        .await?;
+
+    let mut conn1 = pool.acquire().await?;
+    let mut conn2 = pool.acquire().await?;
+
+    conn1
+        .execute("CREATE TABLE shared (id INTEGER PRIMARY KEY); INSERT INTO shared VALUES (10)")
+        .await?;
+
+    let id: i64 = sqlx::query_scalar("SELECT id FROM shared")
+        .fetch_one(&mut conn2)
+        .await?;
+
+    assert_eq!(id, 10);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_to_load_missing_extension() -> anyhow::Result<()> {
+    let res = SqliteConnectOptions::new()
+        .extension("./not-an-extension", None)
+        .connect()
+        .await;
+
+    let err = res.unwrap_err();
+    let err = err
+        .as_database_error()
+        .unwrap()
+        .downcast_ref::<SqliteError>();
+
+    assert!(err.message().contains("not-an-extension"));
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_opens_temp_on_disk() -> anyhow::Result<()> {
     // If the filename is an empty string, then a private, temporary on-disk database will
@@ -1,7 +1,9 @@
+extern crate time_ as time;
+
 use sqlx::sqlite::{Sqlite, SqliteRow};
 use sqlx_core::row::Row;
 use sqlx_test::new;
-use sqlx_test::test_type;
+use sqlx_test::{test_decode_type, test_type};
 
 test_type!(null<Option<i32>>(Sqlite,
     "NULL" == None::<i32>
@@ -9,6 +11,12 @@ test_type!(null<Option<i32>>(Sqlite,
 
 test_type!(bool(Sqlite, "FALSE" == false, "TRUE" == true));
 
+test_decode_type!(bool_text<bool>(Sqlite,
+    "'true'" == true,
+    "'F'" == false,
+    "'1'" == true
+));
+
 test_type!(i32(Sqlite, "94101" == 94101_i32));
 
 test_type!(i64(Sqlite, "9358295312" == 9358295312_i64));
@@ -106,3 +114,107 @@ mod chrono {
         "datetime('2016-11-08T03:50:23-05:00')" == FixedOffset::west(5 * 3600).ymd(2016, 11, 08).and_hms(3, 50, 23)
     ));
 }
+
+#[cfg(feature = "time")]
+mod time_tests {
+    use super::*;
+    use sqlx::types::time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+    use time::{date, time};
+
+    test_type!(time_date<Date>(Sqlite,
+        "date('2001-01-05')" == date!(2001 - 1 - 5)
+    ));
+
+    test_type!(time_time<Time>(Sqlite,
+        "time('05:10:20')" == time!(5:10:20),
+        "'05:10:20.1151'" == time!(5:10:20.115100)
+    ));
+
+    test_type!(time_date_time<PrimitiveDateTime>(Sqlite,
+        "datetime('2019-01-02 05:10:20')" == date!(2019 - 1 - 2).with_time(time!(5:10:20)),
+        "strftime('%Y-%m-%d %H:%M:%f', '2019-01-02 05:10:20.115')"
+            == date!(2019 - 1 - 2).with_time(time!(5:10:20.115))
+    ));
+
+    test_type!(time_offset_date_time<OffsetDateTime>(Sqlite,
+        "datetime('2016-11-08T03:50:23-05:00')"
+            == date!(2016 - 11 - 08)
+                .with_time(time!(3:50:23))
+                .assume_offset(UtcOffset::west_seconds(5 * 3600))
+    ));
+
+    test_decode_type!(time_offset_date_time_text<OffsetDateTime>(Sqlite,
+        "'2016-11-08T03:50:23.5-05:00'"
+            == date!(2016 - 11 - 08)
+                .with_time(time!(3:50:23.5))
+                .assume_offset(UtcOffset::west_seconds(5 * 3600)),
+        "'2016-11-08 08:50Z'" == date!(2016 - 11 - 08).with_time(time!(8:50)).assume_utc(),
+        "1478595023" == date!(2016 - 11 - 08).with_time(time!(8:50:23)).assume_utc()
+    ));
+
+    // the offset is ignored, as for `chrono::NaiveDateTime`
+    test_decode_type!(time_date_time_text<PrimitiveDateTime>(Sqlite,
+        "'2016-11-08T03:50:23-05:00'" == date!(2016 - 11 - 08).with_time(time!(3:50:23))
+    ));
+
+    #[sqlx_macros::test]
+    async fn it_fails_to_decode_out_of_range_datetime() -> anyhow::Result<()> {
+        let mut conn = new::<Sqlite>().await?;
+
+        for value in &["9223372036854775807", "-9223372036854775808", "1e300"] {
+            let result: Result<OffsetDateTime, _> =
+                sqlx::query_scalar(&format!("SELECT {}", value))
+                    .fetch_one(&mut conn)
+                    .await;
+
+            assert!(result.is_err(), "{}", value);
+
+            let result: Result<PrimitiveDateTime, _> =
+                sqlx::query_scalar(&format!("SELECT {}", value))
+                    .fetch_one(&mut conn)
+                    .await;
+
+            assert!(result.is_err(), "{}", value);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "uuid")]
+test_type!(uuid<sqlx::types::Uuid>(Sqlite,
+    "x'b731678f636f4135bc6f19440c13bd19'"
+        == sqlx::types::Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19").unwrap(),
+    "x'00000000000000000000000000000000'"
+        == sqlx::types::Uuid::nil()
+));
+
+#[cfg(feature = "uuid")]
+test_decode_type!(uuid_text<sqlx::types::Uuid>(Sqlite,
+    "'b731678f-636f-4135-bc6f-19440c13bd19'"
+        == sqlx::types::Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19").unwrap()
+));
+
+#[cfg(feature = "uuid")]
+test_type!(uuid_hyphenated<sqlx::types::uuid::adapter::Hyphenated>(Sqlite,
+    "'b731678f-636f-4135-bc6f-19440c13bd19'"
+        == sqlx::types::Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19").unwrap().to_hyphenated()
+));
+
+#[cfg(feature = "decimal")]
+test_type!(decimal<sqlx::types::Decimal>(Sqlite,
+    "'0.1'" == "0.1".parse::<sqlx::types::Decimal>().unwrap(),
+    "'12345.6789'" == "12345.6789".parse::<sqlx::types::Decimal>().unwrap()
+));
+
+#[cfg(feature = "decimal")]
+test_decode_type!(decimal_numeric<sqlx::types::Decimal>(Sqlite,
+    "12345" == "12345".parse::<sqlx::types::Decimal>().unwrap(),
+    "1.5" == "1.5".parse::<sqlx::types::Decimal>().unwrap()
+));
+
+#[cfg(feature = "bigdecimal")]
+test_type!(bigdecimal<sqlx::types::BigDecimal>(Sqlite,
+    "'0.1'" == "0.1".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "'12345.6789'" == "12345.6789".parse::<sqlx::types::BigDecimal>().unwrap()
+));